@@ -0,0 +1,47 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Fuzz target parsing `Interval`s from strings.
+//!
+//! Parsed intervals must survive a round trip through `Display`, and the
+//! `Selection` built from them must be in canonical form and agree with the
+//! `Interval`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+#![no_main]
+
+// External library imports.
+use libfuzzer_sys::fuzz_target;
+use normalize_interval::arbitrary::fuzz::assert_canonical;
+use normalize_interval::Interval;
+use normalize_interval::Selection;
+
+
+fuzz_target!(|text: &str| {
+    let interval: Interval<i32> = match text.parse() {
+        Ok(interval) => interval,
+        Err(_)       => return,
+    };
+    assert_eq!(interval.to_string().parse(), Ok(interval),
+        "round trip of {:?}", text);
+
+    let selection = Selection::from(interval);
+    assert_canonical(&selection);
+    let intervals: Vec<_> = selection.interval_iter().collect();
+    if interval.is_empty() {
+        assert!(intervals.is_empty(), "{:?} is not empty", selection);
+    } else {
+        assert_eq!(intervals, [interval], "selection of {:?}", text);
+    }
+
+    let complement = selection.complement();
+    assert_canonical(&complement);
+    let expected: Selection<i32> = interval.complement().collect();
+    assert_eq!(complement, expected, "complement of {:?}", text);
+});
@@ -0,0 +1,106 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Fuzz target applying operation scripts to a `Selection<u8>`.
+//!
+//! After each operation the `Selection` must contain exactly the points of a
+//! reference set, its tines must be in canonical form, and when the operands
+//! are single intervals it must agree with the `Interval` operations.
+//!
+////////////////////////////////////////////////////////////////////////////////
+#![no_main]
+
+// External library imports.
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use normalize_interval::arbitrary::fuzz::assert_canonical;
+use normalize_interval::Interval;
+use normalize_interval::Selection;
+
+
+/// An operation applied to the `Selection` and the reference set.
+#[derive(Debug, Arbitrary)]
+enum Op {
+    UnionInPlace(Interval<u8>),
+    MinusInPlace(Interval<u8>),
+    IntersectInPlace(Interval<u8>),
+    Complement,
+    Union(Selection<u8>),
+    Minus(Selection<u8>),
+    Intersect(Selection<u8>),
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut selection = Selection::new();
+    let mut model = [false; 256];
+
+    for op in &ops {
+        // The single interval of the `Selection`, if it has exactly one.
+        let single = match selection.interval_iter().collect::<Vec<_>>()[..] {
+            [interval] => Some(interval),
+            _          => None,
+        };
+        let expected: Option<Vec<Interval<u8>>> = match op {
+            Op::UnionInPlace(interval) => {
+                selection.union_in_place(*interval);
+                update(&mut model, |p, a| a || interval.contains(&p));
+                single.map(|s| s.union(interval).collect())
+            },
+            Op::MinusInPlace(interval) => {
+                selection.minus_in_place(*interval);
+                update(&mut model, |p, a| a && !interval.contains(&p));
+                single.map(|s| s.minus(interval).collect())
+            },
+            Op::IntersectInPlace(interval) => {
+                selection.intersect_in_place(*interval);
+                update(&mut model, |p, a| a && interval.contains(&p));
+                single.map(|s| vec![s.intersect(interval)])
+            },
+            Op::Complement => {
+                selection = selection.complement();
+                update(&mut model, |_, a| !a);
+                single.map(|s| s.complement().collect())
+            },
+            Op::Union(other) => {
+                selection = selection.union(other);
+                update(&mut model, |p, a| a || other.contains(&p));
+                None
+            },
+            Op::Minus(other) => {
+                selection = selection.minus(other);
+                update(&mut model, |p, a| a && !other.contains(&p));
+                None
+            },
+            Op::Intersect(other) => {
+                selection = selection.intersect(other);
+                update(&mut model, |p, a| a && other.contains(&p));
+                None
+            },
+        };
+
+        assert_canonical(&selection);
+        for p in 0..=255u8 {
+            assert_eq!(selection.contains(&p), model[usize::from(p)],
+                "point {} after {:?}", p, op);
+            if let Some(expected) = &expected {
+                assert_eq!(model[usize::from(p)],
+                    expected.iter().any(|i| i.contains(&p)),
+                    "point {} disagrees with Interval after {:?}", p, op);
+            }
+        }
+    }
+});
+
+/// Replaces each entry of the reference set using the given function of the
+/// point and its current entry.
+fn update<F>(model: &mut [bool; 256], f: F) where F: Fn(u8, bool) -> bool {
+    for (p, entry) in (0..=255u8).zip(model.iter_mut()) {
+        *entry = f(p, *entry);
+    }
+}
@@ -0,0 +1,365 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a range allocator which tracks free space with a `Selection`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FitPolicy
+////////////////////////////////////////////////////////////////////////////////
+/// The strategy used by a [`RangeAllocator`] to choose among the free ranges
+/// large enough for an allocation.
+///
+/// [`RangeAllocator`]: struct.RangeAllocator.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FitPolicy {
+    /// Allocate from the lowest free range which fits.
+    #[default]
+    FirstFit,
+    /// Allocate from the smallest free range which fits, preferring lower
+    /// ranges among those of equal size.
+    BestFit,
+    /// Allocate from the first free range which fits at or after the end of
+    /// the previous allocation, wrapping around to the start of the space.
+    NextFit,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RangeAllocator
+////////////////////////////////////////////////////////////////////////////////
+/// An allocator of contiguous ranges of `u64` offsets within a managed space,
+/// such as the blocks of a storage file.
+///
+/// The free space is held in a [`Selection`], so freed ranges are coalesced
+/// with their free neighbors automatically.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::allocator::AllocError;
+/// # use normalize_interval::allocator::RangeAllocator;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut alloc = RangeAllocator::new(Interval::closed(0, 99));
+///
+/// let a = alloc.allocate(10)?;
+/// let b = alloc.allocate(20)?;
+/// assert_eq!(a, Interval::closed(0, 9));
+/// assert_eq!(b, Interval::closed(10, 29));
+///
+/// alloc.free(a)?;
+/// assert_eq!(alloc.free(a), Err(AllocError::DoubleFree));
+/// assert_eq!(alloc.allocate_aligned(4, 8)?, Interval::closed(0, 3));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeAllocator {
+    /// The range of offsets managed by the allocator.
+    space: Interval<u64>,
+    /// The unallocated offsets.
+    free: Selection<u64>,
+    /// The allocation policy.
+    policy: FitPolicy,
+    /// The offset at which the next search begins under `FitPolicy::NextFit`.
+    cursor: u64,
+}
+
+impl RangeAllocator {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `RangeAllocator` managing the given space, which is
+    /// initially free, using the `FirstFit` policy.
+    pub fn new(space: Interval<u64>) -> Self {
+        RangeAllocator::with_policy(space, FitPolicy::default())
+    }
+
+    /// Constructs a new `RangeAllocator` managing the given space, which is
+    /// initially free, using the given `FitPolicy`.
+    pub fn with_policy(space: Interval<u64>, policy: FitPolicy) -> Self {
+        RangeAllocator {
+            space,
+            free: Selection::from(space),
+            policy,
+            cursor: space.infimum().unwrap_or(0),
+        }
+    }
+
+    // Accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the range of offsets managed by the `RangeAllocator`.
+    pub fn space(&self) -> Interval<u64> {
+        self.space
+    }
+
+    /// Returns the free space of the `RangeAllocator`.
+    pub fn free_space(&self) -> &Selection<u64> {
+        &self.free
+    }
+
+    /// Returns the `FitPolicy` of the `RangeAllocator`.
+    pub fn policy(&self) -> FitPolicy {
+        self.policy
+    }
+
+    /// Sets the `FitPolicy` used for subsequent allocations.
+    pub fn set_policy(&mut self, policy: FitPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns `true` if the given range is entirely free.
+    pub fn is_free(&self, range: Interval<u64>) -> bool {
+        match (range.infimum(), range.supremum()) {
+            (Some(l), Some(r)) => self.free
+                .interval_containing(&l)
+                .is_some_and(|free| free.contains(&r)),
+            _ => true,
+        }
+    }
+
+    /// Returns statistics describing the fragmentation of the free space.
+    pub fn stats(&self) -> FragmentationStats {
+        let mut stats = FragmentationStats::default();
+        for interval in self.free.interval_iter() {
+            let len = interval_len(interval);
+            stats.free += len;
+            stats.largest = stats.largest.max(len);
+            stats.fragments += 1;
+        }
+        stats
+    }
+
+    // Allocation
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Allocates a range of the given length according to the
+    /// `RangeAllocator`'s `FitPolicy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError::ZeroLength`] if `len` is zero, or
+    /// [`AllocError::OutOfSpace`] if no free range is large enough.
+    ///
+    /// [`AllocError::ZeroLength`]: enum.AllocError.html#variant.ZeroLength
+    /// [`AllocError::OutOfSpace`]: enum.AllocError.html#variant.OutOfSpace
+    pub fn allocate(&mut self, len: u64) -> Result<Interval<u64>, AllocError> {
+        self.allocate_aligned(len, 1)
+    }
+
+    /// Allocates a range of the given length whose start is a multiple of
+    /// `align`, according to the `RangeAllocator`'s `FitPolicy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError::ZeroLength`] if `len` is zero,
+    /// [`AllocError::ZeroAlignment`] if `align` is zero, or
+    /// [`AllocError::OutOfSpace`] if no free range is large enough.
+    ///
+    /// [`AllocError::ZeroLength`]: enum.AllocError.html#variant.ZeroLength
+    /// [`AllocError::ZeroAlignment`]: enum.AllocError.html#variant.ZeroAlignment
+    /// [`AllocError::OutOfSpace`]: enum.AllocError.html#variant.OutOfSpace
+    pub fn allocate_aligned(&mut self, len: u64, align: u64)
+        -> Result<Interval<u64>, AllocError>
+    {
+        if len == 0 { return Err(AllocError::ZeroLength); }
+        if align == 0 { return Err(AllocError::ZeroAlignment); }
+
+        let start = match self.policy {
+            FitPolicy::FirstFit => self.free
+                .interval_iter()
+                .find_map(|free| fit(free, len, align)),
+
+            FitPolicy::BestFit => self.free
+                .interval_iter()
+                .filter_map(|free| fit(free, len, align)
+                    .map(|start| (interval_len(free), start)))
+                .min_by_key(|(size, _)| *size)
+                .map(|(_, start)| start),
+
+            FitPolicy::NextFit => {
+                let cursor = self.cursor;
+                let after = Interval::unbounded_from(cursor);
+                self.free
+                    .interval_iter()
+                    .filter_map(|free| fit(free.intersect(&after), len, align))
+                    .chain(self.free
+                        .interval_iter()
+                        .filter_map(|free| fit(free, len, align)))
+                    .next()
+            },
+        };
+
+        let start = start.ok_or(AllocError::OutOfSpace)?;
+        let range = Interval::closed(start, start + (len - 1));
+        self.free.minus_in_place(range);
+        self.cursor = (start + (len - 1)).checked_add(1)
+            .unwrap_or_else(|| self.space.infimum().unwrap_or(0));
+        Ok(range)
+    }
+
+    /// Allocates the given range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError::OutOfRange`] if the range extends outside of the
+    /// managed space, or [`AllocError::Unavailable`] if any part of it is
+    /// already allocated.
+    ///
+    /// [`AllocError::OutOfRange`]: enum.AllocError.html#variant.OutOfRange
+    /// [`AllocError::Unavailable`]: enum.AllocError.html#variant.Unavailable
+    pub fn allocate_at(&mut self, range: Interval<u64>)
+        -> Result<(), AllocError>
+    {
+        if !self.in_space(range) {
+            return Err(AllocError::OutOfRange);
+        }
+        if !self.is_free(range) {
+            return Err(AllocError::Unavailable);
+        }
+        self.free.minus_in_place(range);
+        Ok(())
+    }
+
+    /// Frees the given range, coalescing it with any adjacent free ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError::OutOfRange`] if the range extends outside of the
+    /// managed space, or [`AllocError::DoubleFree`] if any part of it is
+    /// already free. The `RangeAllocator` is unchanged if an error occurs.
+    ///
+    /// [`AllocError::OutOfRange`]: enum.AllocError.html#variant.OutOfRange
+    /// [`AllocError::DoubleFree`]: enum.AllocError.html#variant.DoubleFree
+    pub fn free(&mut self, range: Interval<u64>) -> Result<(), AllocError> {
+        if !self.in_space(range) {
+            return Err(AllocError::OutOfRange);
+        }
+        if let (Some(l), Some(r)) = (range.infimum(), range.supremum()) {
+            if self.free.next_point_at_or_after(&l).is_some_and(|p| p <= r) {
+                return Err(AllocError::DoubleFree);
+            }
+        }
+        self.free.union_in_place(range);
+        Ok(())
+    }
+
+    /// Returns `true` if the given range lies within the managed space.
+    fn in_space(&self, range: Interval<u64>) -> bool {
+        match (range.infimum(), range.supremum()) {
+            (Some(l), Some(r)) => self.space.contains(&l)
+                && self.space.contains(&r),
+            _ => true,
+        }
+    }
+}
+
+/// Returns the first start offset of a range of the given length and
+/// alignment within the given free interval.
+fn fit(free: Interval<u64>, len: u64, align: u64) -> Option<u64> {
+    let (l, r) = free.closed_bounds()?;
+    let start = match l % align {
+        0   => l,
+        rem => l.checked_add(align - rem)?,
+    };
+    let end = start.checked_add(len - 1)?;
+    if end <= r { Some(start) } else { None }
+}
+
+/// Returns the number of offsets in the given interval.
+fn interval_len(interval: Interval<u64>) -> u128 {
+    match (interval.infimum(), interval.supremum()) {
+        (Some(l), Some(r)) => u128::from(r - l) + 1,
+        _                  => 0,
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FragmentationStats
+////////////////////////////////////////////////////////////////////////////////
+/// Statistics describing the free space of a [`RangeAllocator`].
+///
+/// [`RangeAllocator`]: struct.RangeAllocator.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FragmentationStats {
+    /// The total number of free offsets.
+    pub free: u128,
+    /// The length of the largest free range.
+    pub largest: u128,
+    /// The number of disjoint free ranges.
+    pub fragments: usize,
+}
+
+impl FragmentationStats {
+    /// Returns the fraction of free space outside of the largest free range,
+    /// from `0.0` when the free space is contiguous (or empty) towards `1.0`
+    /// as it is divided into many small ranges.
+    pub fn fragmentation(&self) -> f64 {
+        if self.free == 0 { return 0.0; }
+        1.0 - (self.largest as f64 / self.free as f64)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// AllocError
+////////////////////////////////////////////////////////////////////////////////
+/// The error returned by the operations of a [`RangeAllocator`].
+///
+/// [`RangeAllocator`]: struct.RangeAllocator.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllocError {
+    /// An allocation of zero length was requested.
+    ZeroLength,
+    /// An allocation with an alignment of zero was requested.
+    ZeroAlignment,
+    /// No free range is large enough for the allocation.
+    OutOfSpace,
+    /// The requested range is partially or entirely allocated.
+    Unavailable,
+    /// The range extends outside of the space managed by the allocator.
+    OutOfRange,
+    /// The range is partially or entirely free already.
+    DoubleFree,
+}
+
+impl std::fmt::Display for AllocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            AllocError::ZeroLength    =>
+                write!(f, "allocation length is zero"),
+            AllocError::ZeroAlignment =>
+                write!(f, "allocation alignment is zero"),
+            AllocError::OutOfSpace    =>
+                write!(f, "no free range is large enough for the allocation"),
+            AllocError::Unavailable   =>
+                write!(f, "range is already allocated"),
+            AllocError::OutOfRange    =>
+                write!(f, "range is outside of the allocator's space"),
+            AllocError::DoubleFree    =>
+                write!(f, "range is already free"),
+        }
+    }
+}
+
+impl std::error::Error for AllocError {}
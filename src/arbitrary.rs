@@ -0,0 +1,37 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides property testing and fuzzing generators for [`Bound`],
+//! [`Interval`], and [`Selection`].
+//!
+//! Each supported library is enabled by a cargo feature of the same name:
+//!
+//! + `proptest`: provides strategies and `Arbitrary` implementations.
+//! + `quickcheck`: provides `quickcheck::Arbitrary` implementations.
+//! + `arbitrary`: provides the `fuzz` module, with `arbitrary::Arbitrary`
+//!   implementations and invariant checks for fuzz targets.
+//!
+//! Generated intervals are drawn from every interval shape, including open,
+//! half-open, and unbounded shapes, before being normalized. Shrinking moves
+//! towards selections with fewer intervals, and intervals with fewer points
+//! and smaller bounds.
+//!
+//! [`Bound`]: ../bound/enum.Bound.html
+//! [`Interval`]: ../interval/struct.Interval.html
+//! [`Selection`]: ../selection/struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Module declarations.
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
@@ -0,0 +1,115 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! `Arbitrary` implementations for the `arbitrary` crate, and structural
+//! invariant checks for use in fuzz targets.
+//!
+//! The fuzz targets in the `fuzz` directory of the repository are run with
+//! `cargo fuzz run <target>`.
+//!
+//! ```rust
+//! # use normalize_interval::arbitrary::fuzz::assert_canonical;
+//! # use normalize_interval::Selection;
+//! use arbitrary::Arbitrary;
+//! use arbitrary::Unstructured;
+//!
+//! let data = [3, 0, 7, 12, 1, 40, 41, 9, 250];
+//! let mut u = Unstructured::new(&data);
+//! let sel = Selection::<u8>::arbitrary(&mut u).unwrap();
+//! assert_canonical(&sel.complement());
+//! ```
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use ::arbitrary::Arbitrary;
+use ::arbitrary::Result;
+use ::arbitrary::Unstructured;
+
+// Standard library imports.
+use std::fmt::Debug;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Arbitrary implementations
+////////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T> Arbitrary<'a> for Bound<T> where T: Arbitrary<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => Bound::Include(T::arbitrary(u)?),
+            1 => Bound::Exclude(T::arbitrary(u)?),
+            _ => Bound::Infinite,
+        })
+    }
+}
+
+// Every interval shape is generated before normalization.
+impl<'a, T> Arbitrary<'a> for Interval<T>
+    where T: Arbitrary<'a> + Finite + Ord + Clone,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use RawInterval::*;
+        let shape = u.int_in_range(0..=10u8)?;
+        let (a, b) = (T::arbitrary(u)?, T::arbitrary(u)?);
+        let (l, r) = if a <= b { (a, b) } else { (b, a) };
+        Ok(Interval::from(match shape {
+            0 => Empty,
+            1 => Point(l),
+            2 => Closed(l, r),
+            3 => LeftOpen(l, r),
+            4 => RightOpen(l, r),
+            5 => Open(l, r),
+            6 => To(l),
+            7 => From(l),
+            8 => UpTo(l),
+            9 => UpFrom(l),
+            _ => Full,
+        }))
+    }
+}
+
+impl<'a, T> Arbitrary<'a> for Selection<T>
+    where T: Arbitrary<'a> + Finite + Ord + Clone,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter::<Interval<T>>()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter::<Interval<T>>()?.collect()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Invariant checks
+////////////////////////////////////////////////////////////////////////////////
+
+/// Panics if the `Selection` is not in canonical form, as reported by
+/// [`Selection::check_invariants`].
+///
+/// `Selection`s which contain the same points are equal exactly when both are
+/// in canonical form.
+///
+/// [`Selection::check_invariants`]: ../../selection/struct.Selection.html#method.check_invariants
+pub fn assert_canonical<T>(selection: &Selection<T>)
+    where T: Finite + Ord + Clone + Debug,
+{
+    if let Err(error) = selection.check_invariants() {
+        panic!("non-canonical selection: {} in {:?}", error, selection);
+    }
+}
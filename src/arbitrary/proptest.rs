@@ -0,0 +1,155 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Strategies and `Arbitrary` implementations for the `proptest` crate.
+//!
+//! The `Arbitrary` implementations draw points from `any::<T>()`. To make
+//! overlapping intervals more likely, use the strategy functions with a
+//! narrower point strategy:
+//!
+//! ```rust
+//! # use normalize_interval::arbitrary::proptest::selection;
+//! # use normalize_interval::Selection;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn complement_covers(sel in selection(-20i32..20, 0..6)) {
+//!         let complement: Selection<i32> = sel.complement();
+//!         prop_assert!(sel.union(&complement).is_full());
+//!     }
+//! }
+//! # fn main() { complement_covers(); }
+//! ```
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use ::proptest::arbitrary::any;
+use ::proptest::arbitrary::Arbitrary;
+use ::proptest::collection::SizeRange;
+use ::proptest::collection;
+use ::proptest::prop_oneof;
+use ::proptest::strategy::BoxedStrategy;
+use ::proptest::strategy::Just;
+use ::proptest::strategy::Strategy;
+
+// Standard library imports.
+use std::fmt::Debug;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Strategies
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a strategy for `Bound`s with points drawn from the given strategy.
+///
+/// Shrinks towards `Include` bounds.
+pub fn bound<T, S>(points: S) -> BoxedStrategy<Bound<T>>
+    where
+        T: Clone + Debug + 'static,
+        S: Strategy<Value=T> + Clone + 'static,
+{
+    prop_oneof![
+        points.clone().prop_map(Bound::Include),
+        points.prop_map(Bound::Exclude),
+        Just(Bound::Infinite),
+    ].boxed()
+}
+
+/// Returns a strategy for `Interval`s with bounds drawn from the given
+/// strategy.
+///
+/// Every interval shape is generated before normalization. Shrinks towards
+/// empty and point intervals, then towards bounded intervals.
+pub fn interval<T, S>(points: S) -> BoxedStrategy<Interval<T>>
+    where
+        T: Finite + Ord + Clone + Debug + 'static,
+        S: Strategy<Value=T> + Clone + 'static,
+{
+    use RawInterval::*;
+    // The shape is drawn separately from the bounds so that it shrinks
+    // towards the simpler shapes independently of them.
+    (0..11u8, points.clone(), points)
+        .prop_map(|(shape, a, b)| {
+            let (l, r) = if a <= b { (a, b) } else { (b, a) };
+            Interval::from(match shape {
+                0 => Empty,
+                1 => Point(l),
+                2 => Closed(l, r),
+                3 => LeftOpen(l, r),
+                4 => RightOpen(l, r),
+                5 => Open(l, r),
+                6 => To(l),
+                7 => From(l),
+                8 => UpTo(l),
+                9 => UpFrom(l),
+                _ => Full,
+            })
+        })
+        .boxed()
+}
+
+/// Returns a strategy for `Selection`s formed from a number of `Interval`s in
+/// the given range, with bounds drawn from the given strategy.
+///
+/// Shrinks towards fewer and smaller intervals.
+pub fn selection<T, S, R>(points: S, intervals: R)
+    -> BoxedStrategy<Selection<T>>
+    where
+        T: Finite + Ord + Clone + Debug + 'static,
+        S: Strategy<Value=T> + Clone + 'static,
+        R: Into<SizeRange>,
+{
+    collection::vec(interval(points), intervals)
+        .prop_map(|intervals| intervals.into_iter().collect())
+        .boxed()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Arbitrary implementations
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> Arbitrary for Bound<T> where T: Arbitrary + Clone + 'static {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        bound(any::<T>().boxed())
+    }
+}
+
+impl<T> Arbitrary for Interval<T>
+    where T: Arbitrary + Finite + Ord + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        interval(any::<T>().boxed())
+    }
+}
+
+impl<T> Arbitrary for Selection<T>
+    where T: Arbitrary + Finite + Ord + Clone + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        selection(any::<T>().boxed(), 0..8)
+    }
+}
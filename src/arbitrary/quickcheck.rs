@@ -0,0 +1,145 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! `Arbitrary` implementations for the `quickcheck` crate.
+//!
+//! ```rust
+//! # use normalize_interval::Selection;
+//! use quickcheck::QuickCheck;
+//!
+//! fn complement_covers(sel: Selection<i8>) -> bool {
+//!     sel.union(&sel.complement()).is_full()
+//! }
+//!
+//! QuickCheck::new()
+//!     .quickcheck(complement_covers as fn(Selection<i8>) -> bool);
+//! ```
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use ::quickcheck::empty_shrinker;
+use ::quickcheck::Arbitrary;
+use ::quickcheck::Gen;
+
+// Standard library imports.
+use std::iter;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bound
+////////////////////////////////////////////////////////////////////////////////
+// Shrinks towards `Include` bounds with smaller points.
+impl<T> Arbitrary for Bound<T> where T: Arbitrary {
+    fn arbitrary(g: &mut Gen) -> Self {
+        match u8::arbitrary(g) % 3 {
+            0 => Bound::Include(T::arbitrary(g)),
+            1 => Bound::Exclude(T::arbitrary(g)),
+            _ => Bound::Infinite,
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item=Self>> {
+        match self {
+            Bound::Include(p) => Box::new(p.shrink().map(Bound::Include)),
+            Bound::Exclude(p) => Box::new(iter::once(Bound::Include(p.clone()))
+                .chain(p.shrink().map(Bound::Exclude))),
+            Bound::Infinite   => empty_shrinker(),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval
+////////////////////////////////////////////////////////////////////////////////
+// Every interval shape is generated before normalization. Each shrink either
+// has fewer points, or is a point interval with a smaller point.
+impl<T> Arbitrary for Interval<T>
+    where T: Arbitrary + Finite + Ord,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        use RawInterval::*;
+        let (a, b) = (T::arbitrary(g), T::arbitrary(g));
+        let (l, r) = if a <= b { (a, b) } else { (b, a) };
+        Interval::from(match u8::arbitrary(g) % 11 {
+            0 => Empty,
+            1 => Point(l),
+            2 => Closed(l, r),
+            3 => LeftOpen(l, r),
+            4 => RightOpen(l, r),
+            5 => Open(l, r),
+            6 => To(l),
+            7 => From(l),
+            8 => UpTo(l),
+            9 => UpFrom(l),
+            _ => Full,
+        })
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item=Self>> {
+        let (l, r) = match self.infimum().zip(self.supremum()) {
+            Some(bounds) => bounds,
+            None         => return empty_shrinker(),
+        };
+        let empty = iter::once(Interval::empty());
+        if l == r {
+            return Box::new(empty.chain(l.shrink().map(Interval::point)));
+        }
+
+        // Shrink each bound towards zero while it stays within the interval.
+        let (lower, upper) = (l.clone(), r.clone());
+        let lowers = l.shrink()
+            .filter(move |p| lower < *p && *p <= upper)
+            .map({
+                let r = r.clone();
+                move |p| Interval::closed(p, r.clone())
+            });
+        let (lower, upper) = (l.clone(), r.clone());
+        let uppers = r.shrink()
+            .filter(move |p| lower <= *p && *p < upper)
+            .map({
+                let l = l.clone();
+                move |p| Interval::closed(l.clone(), p)
+            });
+
+        Box::new(empty
+            .chain(iter::once(Interval::point(l)))
+            .chain(iter::once(Interval::point(r)))
+            .chain(lowers)
+            .chain(uppers))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection
+////////////////////////////////////////////////////////////////////////////////
+// Shrinks towards fewer and smaller intervals.
+impl<T> Arbitrary for Selection<T>
+    where T: Arbitrary + Finite + Ord,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::<Interval<T>>::arbitrary(g).into_iter().collect()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item=Self>> {
+        let intervals: Vec<Interval<T>> = self.interval_iter().collect();
+        Box::new(intervals
+            .shrink()
+            .map(|intervals| intervals.into_iter().collect()))
+    }
+}
@@ -0,0 +1,527 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a dense bitmap selection for small `Finite` domains.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::normalize::FiniteIndex;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+/// The default size, in bits, of the domain of a [`BitSelection`]. Types whose
+/// domains fit within it implement [`SmallDomain`] and may be constructed with
+/// [`BitSelection::new`]; other types require an explicit budget given to
+/// [`BitSelection::with_budget`].
+///
+/// [`BitSelection`]: struct.BitSelection.html
+/// [`SmallDomain`]: trait.SmallDomain.html
+/// [`BitSelection::new`]: struct.BitSelection.html#method.new
+/// [`BitSelection::with_budget`]: struct.BitSelection.html#method.with_budget
+pub const DEFAULT_BIT_BUDGET: u64 = 1 << 16;
+
+/// The number of bits in each word of a `BitSelection`.
+const WORD_BITS: u64 = 64;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SmallDomain
+////////////////////////////////////////////////////////////////////////////////
+/// A [`FiniteIndex`] type whose domain fits within the
+/// [`DEFAULT_BIT_BUDGET`], so that a [`BitSelection`] of it may be constructed
+/// without checking a budget.
+///
+/// Wider types such as `u32` should use a [`Selection`] or
+/// [`RoaringSelection`] instead, or give a larger budget to
+/// [`BitSelection::with_budget`].
+///
+/// [`FiniteIndex`]: ../normalize/trait.FiniteIndex.html
+/// [`DEFAULT_BIT_BUDGET`]: constant.DEFAULT_BIT_BUDGET.html
+/// [`BitSelection`]: struct.BitSelection.html
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`RoaringSelection`]: ../roaring_selection/struct.RoaringSelection.html
+/// [`BitSelection::with_budget`]: struct.BitSelection.html#method.with_budget
+pub trait SmallDomain: FiniteIndex {}
+
+impl SmallDomain for u8 {}
+impl SmallDomain for i8 {}
+impl SmallDomain for u16 {}
+impl SmallDomain for i16 {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BitSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of points of the type `T`, stored as a
+/// bitmap with one bit for every point in the domain.
+///
+/// For small domains such as `u8` or `u16`, a `BitSelection` is smaller and
+/// faster than a [`Selection`]; set operations work on whole words at a time.
+/// Because the bitmap covers the entire domain, its size is bounded by a bit
+/// budget, which defaults to [`DEFAULT_BIT_BUDGET`] for [`SmallDomain`] types.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`DEFAULT_BIT_BUDGET`]: constant.DEFAULT_BIT_BUDGET.html
+/// [`SmallDomain`]: trait.SmallDomain.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::bit_selection::BitSelection;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut bits: BitSelection<u8> = BitSelection::new();
+/// bits.union_in_place(Interval::closed(10, 20));
+/// bits.union_in_place(Interval::closed(200, 255));
+///
+/// assert_eq!(bits.cardinality(), 11 + 56);
+/// assert_eq!(bits.complement().cardinality(), 256 - 67);
+///
+/// let sel = Selection::from(bits.clone());
+/// assert_eq!(BitSelection::from(sel), bits);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSelection<T> {
+    /// The bits of the selection, least significant first.
+    words: Vec<u64>,
+    /// The type of the selected points.
+    marker: PhantomData<T>,
+}
+
+impl<T> BitSelection<T> where T: SmallDomain {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `BitSelection`.
+    pub fn new() -> Self {
+        BitSelection::empty(Self::domain_size())
+    }
+
+    /// Constructs a new `BitSelection` containing every point.
+    pub fn full() -> Self {
+        BitSelection::new().complement()
+    }
+}
+
+impl<T> BitSelection<T> where T: FiniteIndex {
+    /// Returns the number of points in the domain of `T`.
+    ///
+    /// This only overflows for 64-bit types, whose domains exceed any budget
+    /// and so never have a `BitSelection` to query.
+    pub(in crate) fn domain_size() -> u64 {
+        T::MAXIMUM.finite_index() + 1
+    }
+
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `BitSelection` if the domain of `T` fits within
+    /// the given number of bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BitBudgetError`] if the domain of `T` is larger than the
+    /// budget.
+    ///
+    /// [`BitBudgetError`]: struct.BitBudgetError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use normalize_interval::bit_selection::BitSelection;
+    /// assert!(BitSelection::<u16>::with_budget(1 << 16).is_ok());
+    /// assert!(BitSelection::<u16>::with_budget(1 << 8).is_err());
+    /// assert!(BitSelection::<u32>::with_budget(1 << 16).is_err());
+    /// ```
+    pub fn with_budget(budget: u64) -> Result<Self, BitBudgetError> {
+        let required = u128::from(T::MAXIMUM.finite_index()) + 1;
+        if required > u128::from(budget) {
+            return Err(BitBudgetError { required, budget });
+        }
+        Ok(BitSelection::empty(Self::domain_size()))
+    }
+
+    /// Constructs a new empty `BitSelection` with room for the given number of
+    /// points.
+    fn empty(domain_size: u64) -> Self {
+        let len = domain_size.div_ceil(WORD_BITS) as usize;
+        BitSelection { words: vec![0; len], marker: PhantomData }
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `BitSelection` contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    /// Returns `true` if the `BitSelection` contains every point.
+    pub fn is_full(&self) -> bool {
+        self.cardinality() == Self::domain_size()
+    }
+
+    /// Returns the number of points in the `BitSelection`.
+    pub fn cardinality(&self) -> u64 {
+        self.words.iter().map(|w| u64::from(w.count_ones())).sum()
+    }
+
+    /// Returns `true` if the `BitSelection` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        let index = point.finite_index();
+        self.words[(index / WORD_BITS) as usize] & (1 << (index % WORD_BITS))
+            != 0
+    }
+
+    /// Returns the least point in the `BitSelection`, or `None` if it is
+    /// empty.
+    pub fn infimum(&self) -> Option<T> {
+        self.next_set(0).map(T::from_finite_index)
+    }
+
+    /// Returns the greatest point in the `BitSelection`, or `None` if it is
+    /// empty.
+    pub fn supremum(&self) -> Option<T> {
+        self.words
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, w)| **w != 0)
+            .map(|(i, w)| {
+                let bit = WORD_BITS - 1 - u64::from(w.leading_zeros());
+                T::from_finite_index(i as u64 * WORD_BITS + bit)
+            })
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `BitSelection` containing all points not in the
+    /// `BitSelection`.
+    pub fn complement(&self) -> Self {
+        let mut complement = self.clone();
+        for w in &mut complement.words {
+            *w = !*w;
+        }
+        complement.clear_padding();
+        complement
+    }
+
+    /// Returns a `BitSelection` containing all points in both
+    /// `BitSelection`s.
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.zip_words(other, |a, b| a & b)
+    }
+
+    /// Returns a `BitSelection` containing all points in either
+    /// `BitSelection`.
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_words(other, |a, b| a | b)
+    }
+
+    /// Returns a `BitSelection` containing all points in the `BitSelection`
+    /// which are not in the given `BitSelection`.
+    pub fn minus(&self, other: &Self) -> Self {
+        self.zip_words(other, |a, b| a & !b)
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the `BitSelection` to only those points contained in the given
+    /// `Interval`.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        match interval.index_bounds() {
+            Some((lower, upper)) => {
+                if lower > 0 {
+                    self.fill_range(0, lower - 1, false);
+                }
+                if upper + 1 < Self::domain_size() {
+                    self.fill_range(upper + 1, Self::domain_size() - 1, false);
+                }
+            },
+            None => for w in &mut self.words { *w = 0; },
+        }
+    }
+
+    /// Adds all of the points in the given `Interval` to the `BitSelection`.
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        if let Some((lower, upper)) = interval.index_bounds() {
+            self.fill_range(lower, upper, true);
+        }
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `BitSelection`.
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        if let Some((lower, upper)) = interval.index_bounds() {
+            self.fill_range(lower, upper, false);
+        }
+    }
+
+    // Iterators
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the `Interval`s of the `BitSelection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter { bits: self, index: Some(0) }
+    }
+
+    /// Returns an iterator over each of the points in the `BitSelection`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { bits: self, index: Some(0) }
+    }
+
+    // Bit manipulation
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `BitSelection` built by combining the words of both
+    /// `BitSelection`s with the given function.
+    fn zip_words<F>(&self, other: &Self, f: F) -> Self
+        where F: Fn(u64, u64) -> u64
+    {
+        BitSelection {
+            words: self.words
+                .iter()
+                .zip(other.words.iter())
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets or clears every bit in the given inclusive range.
+    fn fill_range(&mut self, lower: u64, upper: u64, value: bool) {
+        let (first, last) = ((lower / WORD_BITS) as usize,
+            (upper / WORD_BITS) as usize);
+        for (i, w) in self.words[first..=last].iter_mut().enumerate() {
+            let i = i + first;
+            let mut mask = !0u64;
+            if i == first { mask &= !0 << (lower % WORD_BITS); }
+            if i == last { mask &= !0 >> (WORD_BITS - 1 - upper % WORD_BITS); }
+            if value { *w |= mask; } else { *w &= !mask; }
+        }
+    }
+
+    /// Clears any bits in the last word which lie beyond the domain.
+    fn clear_padding(&mut self) {
+        let used = Self::domain_size() % WORD_BITS;
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+
+    /// Returns the index of the first bit with the given value at or after
+    /// the given index.
+    fn next_bit(&self, from: u64, value: bool) -> Option<u64> {
+        let mut i = (from / WORD_BITS) as usize;
+        let flip = if value { 0 } else { !0 };
+        let mut word = (*self.words.get(i)? ^ flip)
+            & (!0 << (from % WORD_BITS));
+        loop {
+            if word != 0 {
+                let index = i as u64 * WORD_BITS
+                    + u64::from(word.trailing_zeros());
+                return Some(index).filter(|idx| *idx < Self::domain_size());
+            }
+            i += 1;
+            word = *self.words.get(i)? ^ flip;
+        }
+    }
+
+    /// Returns the index of the first set bit at or after the given index.
+    fn next_set(&self, from: u64) -> Option<u64> {
+        self.next_bit(from, true)
+    }
+}
+
+impl<T> Default for BitSelection<T> where T: SmallDomain {
+    fn default() -> Self {
+        BitSelection::new()
+    }
+}
+
+impl<T> Extend<Interval<T>> for BitSelection<T> where T: FiniteIndex {
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        for interval in iter.into_iter() {
+            self.union_in_place(interval);
+        }
+    }
+}
+
+impl<T> Extend<T> for BitSelection<T> where T: FiniteIndex {
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=T> {
+        for point in iter.into_iter() {
+            self.union_in_place(Interval::point(point));
+        }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for BitSelection<T> where T: SmallDomain {
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        let mut bits = BitSelection::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+impl<T> FromIterator<T> for BitSelection<T> where T: SmallDomain {
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
+        let mut bits = BitSelection::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+impl<T> From<Interval<T>> for BitSelection<T> where T: SmallDomain {
+    fn from(interval: Interval<T>) -> Self {
+        let mut bits = BitSelection::new();
+        bits.union_in_place(interval);
+        bits
+    }
+}
+
+impl<T> From<Selection<T>> for BitSelection<T> where T: SmallDomain {
+    fn from(selection: Selection<T>) -> Self {
+        selection.interval_iter().collect()
+    }
+}
+
+impl<T> From<BitSelection<T>> for Selection<T> where T: FiniteIndex {
+    fn from(bits: BitSelection<T>) -> Self {
+        bits.interval_iter().collect()
+    }
+}
+
+impl<T> IntervalSet<T> for BitSelection<T> where T: SmallDomain {
+    type Output = BitSelection<T>;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        BitSelection::interval_iter(self)
+    }
+
+    fn infimum(&self) -> Option<T> {
+        BitSelection::infimum(self)
+    }
+
+    fn supremum(&self) -> Option<T> {
+        BitSelection::supremum(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        BitSelection::is_empty(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        BitSelection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self::Output {
+        BitSelection::complement(self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BitBudgetError
+////////////////////////////////////////////////////////////////////////////////
+/// The error returned when a type's domain does not fit within the bit budget
+/// of a [`BitSelection`].
+///
+/// [`BitSelection`]: struct.BitSelection.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitBudgetError {
+    /// The number of bits required to represent the domain.
+    pub required: u128,
+    /// The number of bits allowed.
+    pub budget: u64,
+}
+
+impl std::fmt::Display for BitBudgetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "domain of {} bits exceeds the bit budget of {}",
+            self.required, self.budget)
+    }
+}
+
+impl std::error::Error for BitBudgetError {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `BitSelection`.
+#[derive(Debug, Clone)]
+pub struct IntervalIter<'t, T> {
+    bits: &'t BitSelection<T>,
+    index: Option<u64>,
+}
+
+impl<'t, T> Iterator for IntervalIter<'t, T> where T: FiniteIndex {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let lower = self.bits.next_set(self.index?);
+        let lower = match lower {
+            Some(lower) => lower,
+            None        => { self.index = None; return None; },
+        };
+        let end = self.bits.next_bit(lower, false);
+        let upper = end.map_or(BitSelection::<T>::domain_size() - 1,
+            |end| end - 1);
+        self.index = end;
+        Some(Interval::closed(
+            T::from_finite_index(lower),
+            T::from_finite_index(upper)))
+    }
+}
+
+impl<'t, T> FusedIterator for IntervalIter<'t, T> where T: FiniteIndex {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points of a `BitSelection`.
+#[derive(Debug, Clone)]
+pub struct Iter<'t, T> {
+    bits: &'t BitSelection<T>,
+    index: Option<u64>,
+}
+
+impl<'t, T> Iterator for Iter<'t, T> where T: FiniteIndex {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.bits.next_set(self.index?);
+        self.index = index.and_then(|i| i.checked_add(1));
+        index.map(T::from_finite_index)
+    }
+}
+
+impl<'t, T> FusedIterator for Iter<'t, T> where T: FiniteIndex {}
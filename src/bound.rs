@@ -532,3 +532,27 @@ impl<T> From<T> for Bound<T> {
         Include(t)
     }
 }
+
+// `Bound`-from-`std::ops::Bound` conversion.
+impl<T> From<std::ops::Bound<T>> for Bound<T> {
+    #[inline]
+    fn from(bound: std::ops::Bound<T>) -> Self {
+        match bound {
+            std::ops::Bound::Included(t) => Include(t),
+            std::ops::Bound::Excluded(t) => Exclude(t),
+            std::ops::Bound::Unbounded   => Infinite,
+        }
+    }
+}
+
+// `std::ops::Bound`-from-`Bound` conversion.
+impl<T> From<Bound<T>> for std::ops::Bound<T> {
+    #[inline]
+    fn from(bound: Bound<T>) -> Self {
+        match bound {
+            Include(t) => std::ops::Bound::Included(t),
+            Exclude(t) => std::ops::Bound::Excluded(t),
+            Infinite   => std::ops::Bound::Unbounded,
+        }
+    }
+}
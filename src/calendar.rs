@@ -0,0 +1,389 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a business-day calendar for selecting working days.
+//!
+//! Implementations of [`CalendarDate`] are provided for the date types of the
+//! `chrono` and `time` crates when their respective features are enabled.
+//!
+//! [`CalendarDate`]: trait.CalendarDate.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::FiniteIndex;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Weekday
+////////////////////////////////////////////////////////////////////////////////
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the number of days since Monday, in the range `0..7`.
+    pub fn days_from_monday(self) -> usize {
+        self as usize
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CalendarDate
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Finite`] date type with a granularity of one day, whose index counts
+/// the days from its `MINIMUM`.
+///
+/// [`Finite`]: ../normalize/trait.Finite.html
+pub trait CalendarDate: FiniteIndex {
+    /// Returns the day of the week of the date.
+    fn weekday(&self) -> Weekday;
+
+    /// Returns the year, month (starting from 1), and day of the month
+    /// (starting from 1) of the date.
+    fn year_month_day(&self) -> (i32, u32, u32);
+
+    /// Constructs a date from the given year, month, and day of the month, or
+    /// `None` if the date is invalid or out of range.
+    fn from_year_month_day(year: i32, month: u32, day: u32) -> Option<Self>;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BusinessCalendar
+////////////////////////////////////////////////////////////////////////////////
+/// A calendar of business days, determined by a set of working weekdays and a
+/// [`Selection`] of holidays.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar<D> where D: CalendarDate {
+    /// Whether each weekday, starting from Monday, is a working day.
+    working_days: [bool; 7],
+    /// The non-working days which fall on working weekdays.
+    holidays: Selection<D>,
+}
+
+impl<D> BusinessCalendar<D> where D: CalendarDate {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `BusinessCalendar` with Monday through Friday as
+    /// working days and no holidays.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::calendar::BusinessCalendar;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "chrono")] {
+    /// # use chrono::NaiveDate;
+    /// # //-------------------------------------------------------------------
+    /// let calendar: BusinessCalendar<NaiveDate> = BusinessCalendar::new();
+    ///
+    /// // 2021-01-02 is a Saturday.
+    /// let saturday = NaiveDate::from_ymd_opt(2021, 1, 2).unwrap();
+    /// assert_eq!(calendar.is_business_day(&saturday), false);
+    /// # //-------------------------------------------------------------------
+    /// # }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        use Weekday::*;
+        BusinessCalendar::with_working_days(
+            vec![Monday, Tuesday, Wednesday, Thursday, Friday])
+    }
+
+    /// Constructs a new `BusinessCalendar` with the given working days and no
+    /// holidays.
+    pub fn with_working_days<I>(working_days: I) -> Self
+        where I: IntoIterator<Item=Weekday>
+    {
+        let mut days = [false; 7];
+        for weekday in working_days {
+            days[weekday.days_from_monday()] = true;
+        }
+        BusinessCalendar {
+            working_days: days,
+            holidays: Selection::new(),
+        }
+    }
+
+    // Holidays
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Marks the given date as a holiday.
+    pub fn add_holiday(&mut self, date: D) {
+        self.holidays.union_in_place(Interval::point(date));
+    }
+
+    /// Marks all of the dates in the given `Interval` as holidays.
+    pub fn add_holidays(&mut self, dates: Interval<D>) {
+        self.holidays.union_in_place(dates);
+    }
+
+    /// Returns the `Selection` of holidays.
+    pub fn holidays(&self) -> &Selection<D> {
+        &self.holidays
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the given weekday is a working day.
+    pub fn is_working_day(&self, weekday: Weekday) -> bool {
+        self.working_days[weekday.days_from_monday()]
+    }
+
+    /// Returns `true` if the given date falls on a working day and is not a
+    /// holiday.
+    pub fn is_business_day(&self, date: &D) -> bool {
+        self.is_working_day(date.weekday()) && !self.holidays.contains(date)
+    }
+
+    /// Returns the `Selection` of business days within the given `Interval`.
+    ///
+    /// Each date in the `Interval` is visited, so unbounded `Interval`s should
+    /// be avoided.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::calendar::BusinessCalendar;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "chrono")] {
+    /// # use chrono::NaiveDate;
+    /// # //-------------------------------------------------------------------
+    /// let mut calendar: BusinessCalendar<NaiveDate> = BusinessCalendar::new();
+    /// calendar.add_holiday(NaiveDate::from_ymd_opt(2021, 9, 6).unwrap());
+    ///
+    /// let q3 = Interval::<NaiveDate>::month(2021, 7).unwrap()
+    ///     .enclose(&Interval::<NaiveDate>::month(2021, 9).unwrap());
+    ///
+    /// assert_eq!(calendar.business_days(q3).iter().count(), 65);
+    /// # //-------------------------------------------------------------------
+    /// # }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn business_days(&self, dates: Interval<D>) -> Selection<D> {
+        let mut selection = Selection::new();
+        let mut run: Option<(D, D)> = None;
+
+        // Collect runs of working days into intervals.
+        for date in dates.iter() {
+            if self.is_working_day(date.weekday()) {
+                run = Some(match run.take() {
+                    Some((start, _)) => (start, date),
+                    None             => (date.clone(), date),
+                });
+            } else if let Some((start, end)) = run.take() {
+                selection.union_in_place(Interval::closed(start, end));
+            }
+        }
+        if let Some((start, end)) = run {
+            selection.union_in_place(Interval::closed(start, end));
+        }
+
+        selection.minus(&self.holidays)
+    }
+
+    /// Returns the number of business days from the `start` date up to, but
+    /// not including, the `end` date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::calendar::BusinessCalendar;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "chrono")] {
+    /// # use chrono::NaiveDate;
+    /// # //-------------------------------------------------------------------
+    /// let calendar: BusinessCalendar<NaiveDate> = BusinessCalendar::new();
+    /// let start = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2021, 1, 11).unwrap();
+    ///
+    /// assert_eq!(calendar.business_days_between(&start, &end), 6);
+    /// # //-------------------------------------------------------------------
+    /// # }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn business_days_between(&self, start: &D, end: &D) -> usize {
+        if start >= end { return 0; }
+        let dates = Interval::right_open(start.clone(), end.clone());
+        let working = self.working_days_in(&dates);
+        let holidays: u64 = self.holidays
+            .range(dates)
+            .interval_iter()
+            .map(|holidays| self.working_days_in(&holidays))
+            .sum();
+        (working - holidays) as usize
+    }
+
+    /// Returns the first business day after the given date, or `None` if
+    /// there is no such date.
+    pub fn next_business_day_after(&self, date: &D) -> Option<D> {
+        self.add_business_days(date, 1)
+    }
+
+    /// Returns the last business day before the given date, or `None` if
+    /// there is no such date.
+    pub fn prev_business_day_before(&self, date: &D) -> Option<D> {
+        self.add_business_days(date, -1)
+    }
+
+    /// Returns the date which is the given number of business days after the
+    /// given date, or before it if the count is negative. Returns `None` if
+    /// there is no such date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::calendar::BusinessCalendar;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "chrono")] {
+    /// # use chrono::NaiveDate;
+    /// # //-------------------------------------------------------------------
+    /// let calendar: BusinessCalendar<NaiveDate> = BusinessCalendar::new();
+    /// let friday = NaiveDate::from_ymd_opt(2021, 1, 8).unwrap();
+    ///
+    /// assert_eq!(calendar.add_business_days(&friday, 1),
+    ///     NaiveDate::from_ymd_opt(2021, 1, 11));
+    /// assert_eq!(calendar.add_business_days(&friday, -5),
+    ///     NaiveDate::from_ymd_opt(2021, 1, 1));
+    /// # //-------------------------------------------------------------------
+    /// # }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn add_business_days(&self, date: &D, count: i64) -> Option<D> {
+        let mut current = date.clone();
+        let mut remaining = count.unsigned_abs();
+        if remaining == 0 { return Some(current); }
+
+        // Skip over each run of holidays, counting the working days between
+        // them, until the run containing the result is found.
+        let holiday_set = &self.holidays;
+        loop {
+            let holidays = if count > 0 {
+                current.succ()
+                    .and_then(|next| holiday_set.next_point_at_or_after(&next))
+                    .and_then(|next| holiday_set.interval_containing(&next))
+            } else {
+                current.pred()
+                    .and_then(|prev| holiday_set.prev_point_at_or_before(&prev))
+                    .and_then(|prev| holiday_set.interval_containing(&prev))
+            };
+            let (between, end) = match holidays {
+                Some(holidays) if count > 0 => (
+                    Interval::open(current.clone(), holidays.infimum()?),
+                    holidays.supremum()?),
+                Some(holidays) => (
+                    Interval::open(holidays.supremum()?, current.clone()),
+                    holidays.infimum()?),
+                None => return self.nth_working_day_from(current.finite_index(),
+                    remaining, count > 0),
+            };
+
+            let working = self.working_days_in(&between);
+            if working >= remaining {
+                return self.nth_working_day_from(current.finite_index(),
+                    remaining, count > 0);
+            }
+            remaining -= working;
+            current = end;
+        }
+    }
+
+    // Working day arithmetic
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the number of working days in the given `Interval`, ignoring
+    /// holidays.
+    fn working_days_in(&self, dates: &Interval<D>) -> u64 {
+        let (first, days) = match (dates.infimum(), dates.point_count()) {
+            (Some(first), days) if days > 0 => (first, days as u64),
+            _ => return 0,
+        };
+        let weekday = first.weekday().days_from_monday();
+        let per_week = self.working_days.iter().filter(|&&w| w).count();
+        let rest = (0..(days % 7) as usize)
+            .filter(|day| self.working_days[(weekday + day) % 7])
+            .count();
+        (days / 7) * per_week as u64 + rest as u64
+    }
+
+    /// Returns the `n`th working day after the date with the given index, or
+    /// before it if `forward` is `false`, ignoring holidays. Returns `None` if
+    /// there is no such date.
+    fn nth_working_day_from(&self, index: u64, n: u64, forward: bool)
+        -> Option<D>
+    {
+        let per_week = self.working_days.iter().filter(|&&w| w).count() as u64;
+        if per_week == 0 { return None; }
+
+        // Every week contains the same number of working days, so whole weeks
+        // can be skipped before stepping through the last one.
+        let weeks = (n - 1) / per_week;
+        let mut rest = n - weeks * per_week;
+        let mut index = step_index::<D>(index, weeks.checked_mul(7)?, forward)?;
+        loop {
+            index = step_index::<D>(index, 1, forward)?;
+            let date = D::from_finite_index(index);
+            if self.is_working_day(date.weekday()) {
+                rest -= 1;
+                if rest == 0 { return Some(date); }
+            }
+        }
+    }
+}
+
+/// Returns the index the given number of days after the given index, or
+/// before it if `forward` is `false`, or `None` if it is out of range.
+fn step_index<D>(index: u64, days: u64, forward: bool) -> Option<u64>
+    where D: CalendarDate
+{
+    if forward {
+        index.checked_add(days)
+            .filter(|next| *next <= D::MAXIMUM.finite_index())
+    } else {
+        index.checked_sub(days)
+    }
+}
+
+impl<D> Default for BusinessCalendar<D> where D: CalendarDate {
+    fn default() -> Self {
+        BusinessCalendar::new()
+    }
+}
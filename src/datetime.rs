@@ -0,0 +1,30 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides [`Finite`] implementations for date and time types.
+//!
+//! Each supported date and time library is enabled by a cargo feature of the
+//! same name:
+//!
+//! + `chrono`: implements [`Finite`] for `chrono::NaiveDate`.
+//! + `time`: implements [`Finite`] for `time::Date`.
+//!
+//! Date types are normalized with a granularity of one day. Timestamps have no
+//! natural granularity, so each library module provides a pair of timestamp
+//! wrappers which step by one second and one nanosecond respectively.
+//!
+//! [`Finite`]: ../normalize/trait.Finite.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Module declarations.
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "time")]
+pub mod time;
@@ -0,0 +1,286 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! [`Finite`] support for the `chrono` crate.
+//!
+//! [`Finite`]: ../../normalize/trait.Finite.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::calendar::CalendarDate;
+use crate::calendar;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::FiniteIndex;
+
+// External library imports.
+use ::chrono::Datelike;
+use ::chrono::NaiveDate;
+use ::chrono::NaiveDateTime;
+use ::chrono::NaiveTime;
+use ::chrono::TimeDelta;
+use ::chrono::Timelike;
+use ::chrono::Weekday;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NaiveDate
+////////////////////////////////////////////////////////////////////////////////
+// Dates are normalized with a granularity of one day.
+impl Finite for NaiveDate {
+    const MINIMUM: NaiveDate = NaiveDate::MIN;
+    const MAXIMUM: NaiveDate = NaiveDate::MAX;
+
+    fn pred(&self) -> Option<Self> {
+        self.pred_opt()
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.succ_opt()
+    }
+}
+
+// Dates are indexed by the number of days from `NaiveDate::MIN`.
+impl FiniteIndex for NaiveDate {
+    // The range of `NaiveDate` spans fewer than 2^28 days.
+    const INDEX_BITS: u32 = 28;
+
+    fn finite_index(&self) -> u64 {
+        (self.num_days_from_ce() - NaiveDate::MIN.num_days_from_ce()) as u64
+    }
+
+    fn from_finite_index(index: u64) -> Self {
+        let min = NaiveDate::MIN.num_days_from_ce();
+        i32::try_from(index).ok()
+            .and_then(|days| days.checked_add(min))
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .expect("index of a date in range")
+    }
+}
+
+impl CalendarDate for NaiveDate {
+    fn weekday(&self) -> calendar::Weekday {
+        Datelike::weekday(self).into()
+    }
+
+    fn year_month_day(&self) -> (i32, u32, u32) {
+        (self.year(), self.month(), self.day())
+    }
+
+    fn from_year_month_day(year: i32, month: u32, day: u32) -> Option<Self> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+}
+
+impl From<Weekday> for calendar::Weekday {
+    fn from(weekday: Weekday) -> Self {
+        use calendar::Weekday::*;
+        match weekday {
+            Weekday::Mon => Monday,
+            Weekday::Tue => Tuesday,
+            Weekday::Wed => Wednesday,
+            Weekday::Thu => Thursday,
+            Weekday::Fri => Friday,
+            Weekday::Sat => Saturday,
+            Weekday::Sun => Sunday,
+        }
+    }
+}
+
+// Calendar constructors.
+impl Interval<NaiveDate> {
+    /// Constructs a closed `Interval` covering every day of the given calendar
+    /// month, or `None` if the month is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use chrono::NaiveDate;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let feb = Interval::<NaiveDate>::month(2020, 2).unwrap();
+    ///
+    /// assert_eq!(feb.infimum(), NaiveDate::from_ymd_opt(2020, 2, 1));
+    /// assert_eq!(feb.supremum(), NaiveDate::from_ymd_opt(2020, 2, 29));
+    /// assert_eq!(Interval::<NaiveDate>::month(2020, 13), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn month(year: i32, month: u32) -> Option<Self> {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let last = (28..=31)
+            .rev()
+            .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))?;
+        Some(Interval::closed(first, last))
+    }
+
+    /// Constructs a closed `Interval` covering the Monday through Sunday of the
+    /// given ISO 8601 week, or `None` if the week is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use chrono::NaiveDate;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let week = Interval::<NaiveDate>::iso_week(2020, 1).unwrap();
+    ///
+    /// assert_eq!(week.infimum(), NaiveDate::from_ymd_opt(2019, 12, 30));
+    /// assert_eq!(week.supremum(), NaiveDate::from_ymd_opt(2020, 1, 5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iso_week(year: i32, week: u32) -> Option<Self> {
+        let first = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)?;
+        let last = NaiveDate::from_isoywd_opt(year, week, Weekday::Sun)?;
+        Some(Interval::closed(first, last))
+    }
+
+    /// Constructs a closed `Interval` covering every day of the given calendar
+    /// year, or `None` if the year is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use chrono::NaiveDate;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let year = Interval::<NaiveDate>::year(2021).unwrap();
+    ///
+    /// assert_eq!(year.iter().count(), 365);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn year(year: i32) -> Option<Self> {
+        let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+        let last = NaiveDate::from_ymd_opt(year, 12, 31)?;
+        Some(Interval::closed(first, last))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SecondTimestamp
+////////////////////////////////////////////////////////////////////////////////
+/// A `NaiveDateTime` which is normalized with a granularity of one second.
+///
+/// Any fractional second is truncated on construction, including the
+/// representation of a leap second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SecondTimestamp(NaiveDateTime);
+
+impl SecondTimestamp {
+    /// Constructs a new `SecondTimestamp`, truncating any fractional second.
+    pub fn new(datetime: NaiveDateTime) -> Self {
+        SecondTimestamp(datetime
+            .with_nanosecond(0)
+            .expect("zero nanoseconds is a valid time"))
+    }
+
+    /// Returns the wrapped `NaiveDateTime`.
+    pub fn datetime(&self) -> NaiveDateTime {
+        self.0
+    }
+}
+
+impl Finite for SecondTimestamp {
+    const MINIMUM: SecondTimestamp = SecondTimestamp(NaiveDateTime::MIN);
+    const MAXIMUM: SecondTimestamp = SecondTimestamp(
+        NaiveDateTime::new(NaiveDate::MAX, LAST_SECOND));
+
+    fn pred(&self) -> Option<Self> {
+        self.0.checked_sub_signed(TimeDelta::seconds(1)).map(SecondTimestamp)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.0.checked_add_signed(TimeDelta::seconds(1)).map(SecondTimestamp)
+    }
+}
+
+impl From<NaiveDateTime> for SecondTimestamp {
+    fn from(datetime: NaiveDateTime) -> Self {
+        SecondTimestamp::new(datetime)
+    }
+}
+
+impl From<SecondTimestamp> for NaiveDateTime {
+    fn from(timestamp: SecondTimestamp) -> Self {
+        timestamp.0
+    }
+}
+
+/// The last whole second of a day.
+const LAST_SECOND: NaiveTime = match NaiveTime::from_hms_opt(23, 59, 59) {
+    Some(time) => time,
+    None       => panic!("invalid time"),
+};
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NanoTimestamp
+////////////////////////////////////////////////////////////////////////////////
+/// A `NaiveDateTime` which is normalized with a granularity of one nanosecond.
+///
+/// Stepping through a leap second will leave it; a leap second is never
+/// produced by [`pred`] or [`succ`].
+///
+/// [`pred`]: ../../normalize/trait.Finite.html#tymethod.pred
+/// [`succ`]: ../../normalize/trait.Finite.html#tymethod.succ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NanoTimestamp(NaiveDateTime);
+
+impl NanoTimestamp {
+    /// Constructs a new `NanoTimestamp`.
+    pub fn new(datetime: NaiveDateTime) -> Self {
+        NanoTimestamp(datetime)
+    }
+
+    /// Returns the wrapped `NaiveDateTime`.
+    pub fn datetime(&self) -> NaiveDateTime {
+        self.0
+    }
+}
+
+impl Finite for NanoTimestamp {
+    const MINIMUM: NanoTimestamp = NanoTimestamp(NaiveDateTime::MIN);
+    const MAXIMUM: NanoTimestamp = NanoTimestamp(NaiveDateTime::MAX);
+
+    fn pred(&self) -> Option<Self> {
+        self.0.checked_sub_signed(TimeDelta::nanoseconds(1)).map(NanoTimestamp)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.0.checked_add_signed(TimeDelta::nanoseconds(1)).map(NanoTimestamp)
+    }
+}
+
+impl From<NaiveDateTime> for NanoTimestamp {
+    fn from(datetime: NaiveDateTime) -> Self {
+        NanoTimestamp::new(datetime)
+    }
+}
+
+impl From<NanoTimestamp> for NaiveDateTime {
+    fn from(timestamp: NanoTimestamp) -> Self {
+        timestamp.0
+    }
+}
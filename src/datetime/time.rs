@@ -0,0 +1,285 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! [`Finite`] support for the `time` crate.
+//!
+//! [`Finite`]: ../../normalize/trait.Finite.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::calendar::CalendarDate;
+use crate::calendar;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::FiniteIndex;
+
+// External library imports.
+use ::time::Date;
+use ::time::Duration;
+use ::time::Month;
+use ::time::PrimitiveDateTime;
+use ::time::Time;
+use ::time::Weekday;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Date
+////////////////////////////////////////////////////////////////////////////////
+// Dates are normalized with a granularity of one day.
+impl Finite for Date {
+    const MINIMUM: Date = Date::MIN;
+    const MAXIMUM: Date = Date::MAX;
+
+    fn pred(&self) -> Option<Self> {
+        self.previous_day()
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.next_day()
+    }
+}
+
+// Dates are indexed by the number of days from `Date::MIN`.
+impl FiniteIndex for Date {
+    const INDEX_BITS: u32 = u32::BITS - ((Date::MAX.to_julian_day()
+        - Date::MIN.to_julian_day()) as u32).leading_zeros();
+
+    fn finite_index(&self) -> u64 {
+        (self.to_julian_day() - Date::MIN.to_julian_day()) as u64
+    }
+
+    fn from_finite_index(index: u64) -> Self {
+        i32::try_from(index).ok()
+            .and_then(|days| days.checked_add(Date::MIN.to_julian_day()))
+            .and_then(|day| Date::from_julian_day(day).ok())
+            .expect("index of a date in range")
+    }
+}
+
+impl CalendarDate for Date {
+    fn weekday(&self) -> calendar::Weekday {
+        Date::weekday(*self).into()
+    }
+
+    fn year_month_day(&self) -> (i32, u32, u32) {
+        (self.year(), u8::from(self.month()) as u32, self.day() as u32)
+    }
+
+    fn from_year_month_day(year: i32, month: u32, day: u32) -> Option<Self> {
+        let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+        Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+    }
+}
+
+impl From<Weekday> for calendar::Weekday {
+    fn from(weekday: Weekday) -> Self {
+        use calendar::Weekday::*;
+        match weekday {
+            Weekday::Monday    => Monday,
+            Weekday::Tuesday   => Tuesday,
+            Weekday::Wednesday => Wednesday,
+            Weekday::Thursday  => Thursday,
+            Weekday::Friday    => Friday,
+            Weekday::Saturday  => Saturday,
+            Weekday::Sunday    => Sunday,
+        }
+    }
+}
+
+// Calendar constructors.
+impl Interval<Date> {
+    /// Constructs a closed `Interval` covering every day of the given calendar
+    /// month, or `None` if the month is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use time::Date;
+    /// # use time::Month;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let feb = Interval::<Date>::month(2020, Month::February).unwrap();
+    ///
+    /// assert_eq!(feb.infimum(),
+    ///     Some(Date::from_calendar_date(2020, Month::February, 1)?));
+    /// assert_eq!(feb.supremum(),
+    ///     Some(Date::from_calendar_date(2020, Month::February, 29)?));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn month(year: i32, month: Month) -> Option<Self> {
+        let first = Date::from_calendar_date(year, month, 1).ok()?;
+        let last = Date::from_calendar_date(year, month, month.length(year))
+            .ok()?;
+        Some(Interval::closed(first, last))
+    }
+
+    /// Constructs a closed `Interval` covering the Monday through Sunday of the
+    /// given ISO 8601 week, or `None` if the week is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use time::Date;
+    /// # use time::Month;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let week = Interval::<Date>::iso_week(2020, 1).unwrap();
+    ///
+    /// assert_eq!(week.infimum(),
+    ///     Some(Date::from_calendar_date(2019, Month::December, 30)?));
+    /// assert_eq!(week.supremum(),
+    ///     Some(Date::from_calendar_date(2020, Month::January, 5)?));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iso_week(year: i32, week: u8) -> Option<Self> {
+        let first = Date::from_iso_week_date(year, week, Weekday::Monday)
+            .ok()?;
+        let last = Date::from_iso_week_date(year, week, Weekday::Sunday)
+            .ok()?;
+        Some(Interval::closed(first, last))
+    }
+
+    /// Constructs a closed `Interval` covering every day of the given calendar
+    /// year, or `None` if the year is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use time::Date;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let year = Interval::<Date>::year(2020).unwrap();
+    ///
+    /// assert_eq!(year.iter().count(), 366);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn year(year: i32) -> Option<Self> {
+        let first = Date::from_calendar_date(year, Month::January, 1).ok()?;
+        let last = Date::from_calendar_date(year, Month::December, 31).ok()?;
+        Some(Interval::closed(first, last))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SecondTimestamp
+////////////////////////////////////////////////////////////////////////////////
+/// A `PrimitiveDateTime` which is normalized with a granularity of one second.
+///
+/// Any fractional second is truncated on construction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SecondTimestamp(PrimitiveDateTime);
+
+impl SecondTimestamp {
+    /// Constructs a new `SecondTimestamp`, truncating any fractional second.
+    pub fn new(datetime: PrimitiveDateTime) -> Self {
+        SecondTimestamp(datetime
+            .replace_nanosecond(0)
+            .expect("zero nanoseconds is a valid time"))
+    }
+
+    /// Returns the wrapped `PrimitiveDateTime`.
+    pub fn datetime(&self) -> PrimitiveDateTime {
+        self.0
+    }
+}
+
+impl Finite for SecondTimestamp {
+    const MINIMUM: SecondTimestamp = SecondTimestamp(PrimitiveDateTime::MIN);
+    const MAXIMUM: SecondTimestamp = SecondTimestamp(
+        PrimitiveDateTime::new(Date::MAX, LAST_SECOND));
+
+    fn pred(&self) -> Option<Self> {
+        self.0.checked_sub(Duration::SECOND).map(SecondTimestamp)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.0.checked_add(Duration::SECOND).map(SecondTimestamp)
+    }
+}
+
+impl From<PrimitiveDateTime> for SecondTimestamp {
+    fn from(datetime: PrimitiveDateTime) -> Self {
+        SecondTimestamp::new(datetime)
+    }
+}
+
+impl From<SecondTimestamp> for PrimitiveDateTime {
+    fn from(timestamp: SecondTimestamp) -> Self {
+        timestamp.0
+    }
+}
+
+/// The last whole second of a day.
+const LAST_SECOND: Time = match Time::from_hms(23, 59, 59) {
+    Ok(time) => time,
+    Err(_)   => panic!("invalid time"),
+};
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NanoTimestamp
+////////////////////////////////////////////////////////////////////////////////
+/// A `PrimitiveDateTime` which is normalized with a granularity of one
+/// nanosecond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NanoTimestamp(PrimitiveDateTime);
+
+impl NanoTimestamp {
+    /// Constructs a new `NanoTimestamp`.
+    pub fn new(datetime: PrimitiveDateTime) -> Self {
+        NanoTimestamp(datetime)
+    }
+
+    /// Returns the wrapped `PrimitiveDateTime`.
+    pub fn datetime(&self) -> PrimitiveDateTime {
+        self.0
+    }
+}
+
+impl Finite for NanoTimestamp {
+    const MINIMUM: NanoTimestamp = NanoTimestamp(PrimitiveDateTime::MIN);
+    const MAXIMUM: NanoTimestamp = NanoTimestamp(PrimitiveDateTime::MAX);
+
+    fn pred(&self) -> Option<Self> {
+        self.0.checked_sub(Duration::NANOSECOND).map(NanoTimestamp)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.0.checked_add(Duration::NANOSECOND).map(NanoTimestamp)
+    }
+}
+
+impl From<PrimitiveDateTime> for NanoTimestamp {
+    fn from(datetime: PrimitiveDateTime) -> Self {
+        NanoTimestamp::new(datetime)
+    }
+}
+
+impl From<NanoTimestamp> for PrimitiveDateTime {
+    fn from(timestamp: NanoTimestamp) -> Self {
+        timestamp.0
+    }
+}
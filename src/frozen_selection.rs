@@ -0,0 +1,409 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides an immutable selection stored as a sorted vector of bounds.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+use crate::stream;
+
+// Standard library imports.
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FrozenSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of `Interval`s of the type `T`, stored
+/// as a contiguous sorted vector of bounds.
+///
+/// A `FrozenSelection` can not be modified in place, but it is more compact
+/// than a [`Selection`] and answers queries with a binary search. Set
+/// operations are linear merges which produce new `FrozenSelection`s.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::frozen_selection::FrozenSelection;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: Selection<i32> = Selection::new();
+/// sel.union_in_place(Interval::closed(0, 4));
+/// sel.union_in_place(Interval::closed(8, 12));
+///
+/// let frozen = sel.freeze();
+/// assert!(frozen.contains(&3));
+/// assert!(!frozen.contains(&6));
+/// assert_eq!(Selection::from(frozen), sel);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrozenSelection<T> {
+    /// The closed bounds of each interval, in ascending order. The intervals
+    /// are non-empty, disjoint, and non-adjacent.
+    bounds: Vec<(T, T)>,
+}
+
+impl<T> FrozenSelection<T> where T: Ord + Clone + Finite {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `FrozenSelection`.
+    pub fn new() -> Self {
+        FrozenSelection { bounds: Vec::new() }
+    }
+
+    /// Constructs a new `FrozenSelection` containing every point.
+    pub fn full() -> Self {
+        FrozenSelection { bounds: vec![(T::MINIMUM, T::MAXIMUM)] }
+    }
+
+    /// Appends the given closed bounds, merging them with the last interval if
+    /// they overlap or are adjacent. The bounds must not start before the last
+    /// interval.
+    fn push(&mut self, lower: T, upper: T) {
+        if let Some(last) = self.bounds.last_mut() {
+            let joined = last.1.succ().map_or(true, |next| lower <= next);
+            if joined {
+                if upper > last.1 { last.1 = upper; }
+                return;
+            }
+        }
+        self.bounds.push((lower, upper));
+    }
+
+    // Bound accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the greatest lower bound of the `FrozenSelection`, or `None` if
+    /// it is empty.
+    pub fn infimum(&self) -> Option<T> {
+        self.bounds.first().map(|(l, _)| l.clone())
+    }
+
+    /// Returns the least upper bound of the `FrozenSelection`, or `None` if it
+    /// is empty.
+    pub fn supremum(&self) -> Option<T> {
+        self.bounds.last().map(|(_, r)| r.clone())
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `FrozenSelection` contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// Returns the number of disjoint `Interval`s in the `FrozenSelection`.
+    pub fn interval_count(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Returns `true` if the `FrozenSelection` contains the given point.
+    ///
+    /// This performs a binary search over the intervals.
+    pub fn contains(&self, point: &T) -> bool {
+        let idx = self.bounds.partition_point(|(l, _)| l <= point);
+        idx > 0 && self.bounds[idx - 1].1 >= *point
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `FrozenSelection` containing all points not in the
+    /// `FrozenSelection`.
+    pub fn complement(&self) -> Self {
+        let mut complement = FrozenSelection::new();
+        let mut next = Some(T::MINIMUM);
+        for (l, r) in &self.bounds {
+            if let Some(start) = next.take() {
+                if start < *l {
+                    let end = l.pred().expect("predecessor of lower bound");
+                    complement.bounds.push((start, end));
+                }
+            }
+            next = r.succ();
+        }
+        if let Some(start) = next {
+            complement.bounds.push((start, T::MAXIMUM));
+        }
+        complement
+    }
+
+    /// Returns a `FrozenSelection` containing all points in both
+    /// `FrozenSelection`s.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut intersection = FrozenSelection::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.bounds.len() && j < other.bounds.len() {
+            let (al, ar) = &self.bounds[i];
+            let (bl, br) = &other.bounds[j];
+            let lower = std::cmp::max(al, bl);
+            let upper = std::cmp::min(ar, br);
+            if lower <= upper {
+                intersection.bounds.push((lower.clone(), upper.clone()));
+            }
+            if ar <= br { i += 1; } else { j += 1; }
+        }
+        intersection
+    }
+
+    /// Returns a `FrozenSelection` containing all points in either
+    /// `FrozenSelection`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = FrozenSelection::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.bounds.len() || j < other.bounds.len() {
+            let take_self = j >= other.bounds.len()
+                || (i < self.bounds.len()
+                    && self.bounds[i].0 <= other.bounds[j].0);
+            let (l, r) = if take_self {
+                i += 1;
+                self.bounds[i - 1].clone()
+            } else {
+                j += 1;
+                other.bounds[j - 1].clone()
+            };
+            union.push(l, r);
+        }
+        union
+    }
+
+    /// Returns a `FrozenSelection` containing all points in the
+    /// `FrozenSelection` which are not in the given `FrozenSelection`.
+    pub fn minus(&self, other: &Self) -> Self {
+        self.intersect(&other.complement())
+    }
+
+    // Iterators
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the `Interval`s of the `FrozenSelection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter(self.bounds.iter())
+    }
+
+    /// Returns an iterator over each of the points in the `FrozenSelection`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            intervals: self.interval_iter(),
+            current: Interval::empty().iter(),
+        }
+    }
+}
+
+impl<T> Selection<T> where T: Ord + Clone + Finite {
+    /// Returns a [`FrozenSelection`] containing the same points as the
+    /// `Selection`.
+    ///
+    /// [`FrozenSelection`]: ../frozen_selection/struct.FrozenSelection.html
+    pub fn freeze(&self) -> FrozenSelection<T> {
+        self.interval_iter().collect()
+    }
+}
+
+impl<T> Default for FrozenSelection<T> where T: Ord + Clone + Finite {
+    fn default() -> Self {
+        FrozenSelection::new()
+    }
+}
+
+impl<T> Extend<Interval<T>> for FrozenSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        let mut other: Vec<(T, T)> = iter.into_iter()
+            .filter_map(|i| i.closed_bounds())
+            .filter(|(l, r)| l <= r)
+            .collect();
+        other.sort();
+
+        // Merge the sorted bounds with the existing ones in a single pass.
+        let mut current = std::mem::take(&mut self.bounds)
+            .into_iter()
+            .peekable();
+        let mut other = other.into_iter().peekable();
+        loop {
+            let take_current = match (current.peek(), other.peek()) {
+                (Some(a), Some(b)) => a.0 <= b.0,
+                (Some(_), None)    => true,
+                (None, Some(_))    => false,
+                (None, None)       => break,
+            };
+            let (l, r) = if take_current {
+                current.next().expect("peeked bounds")
+            } else {
+                other.next().expect("peeked bounds")
+            };
+            self.push(l, r);
+        }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for FrozenSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        let mut bounds: Vec<(T, T)> = iter.into_iter()
+            .filter_map(|i| i.closed_bounds())
+            .filter(|(l, r)| l <= r)
+            .collect();
+        bounds.sort();
+
+        let mut frozen = FrozenSelection::new();
+        for (l, r) in bounds {
+            frozen.push(l, r);
+        }
+        frozen
+    }
+}
+
+impl<T> From<Selection<T>> for FrozenSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(selection: Selection<T>) -> Self {
+        selection.freeze()
+    }
+}
+
+impl<T> From<FrozenSelection<T>> for Selection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(frozen: FrozenSelection<T>) -> Self {
+        frozen.bounds
+            .into_iter()
+            .map(|(l, r)| Interval::closed(l, r))
+            .collect()
+    }
+}
+
+impl<T> IntervalSet<T> for FrozenSelection<T> where T: Ord + Clone + Finite {
+    type Output = FrozenSelection<T>;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        FrozenSelection::interval_iter(self)
+    }
+
+    fn lower_bound(&self) -> Option<Bound<T>> {
+        self.infimum().map(Bound::Include)
+    }
+
+    fn upper_bound(&self) -> Option<Bound<T>> {
+        self.supremum().map(Bound::Include)
+    }
+
+    fn infimum(&self) -> Option<T> {
+        FrozenSelection::infimum(self)
+    }
+
+    fn supremum(&self) -> Option<T> {
+        FrozenSelection::supremum(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        FrozenSelection::is_empty(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        FrozenSelection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self::Output {
+        FrozenSelection::complement(self)
+    }
+
+    fn intersect<S>(&self, other: &S) -> Self::Output where S: IntervalSet<T> {
+        stream::intersect(self.interval_iter(), other.interval_iter())
+            .collect()
+    }
+
+    fn union<S>(&self, other: &S) -> Self::Output where S: IntervalSet<T> {
+        stream::union(self.interval_iter(), other.interval_iter()).collect()
+    }
+
+    fn minus<S>(&self, other: &S) -> Self::Output where S: IntervalSet<T> {
+        stream::minus(self.interval_iter(), other.interval_iter()).collect()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `FrozenSelection`.
+#[derive(Debug, Clone)]
+pub struct IntervalIter<'t, T>(std::slice::Iter<'t, (T, T)>);
+
+impl<'t, T> Iterator for IntervalIter<'t, T> where T: Ord + Clone + Finite {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(l, r)| Interval::closed(l.clone(), r.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'t, T> DoubleEndedIterator for IntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(l, r)| Interval::closed(l.clone(), r.clone()))
+    }
+}
+
+impl<'t, T> ExactSizeIterator for IntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{}
+
+impl<'t, T> FusedIterator for IntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points of a `FrozenSelection`.
+#[derive(Debug)]
+pub struct Iter<'t, T> where T: Ord + Clone + Finite {
+    intervals: IntervalIter<'t, T>,
+    current: crate::interval::Iter<T>,
+}
+
+impl<'t, T> Iterator for Iter<'t, T> where T: Ord + Clone + Finite {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(next) = self.current.next() {
+            return Some(next);
+        }
+        self.current = self.intervals.next()?.iter();
+        self.current.next()
+    }
+}
+
+impl<'t, T> FusedIterator for Iter<'t, T> where T: Ord + Clone + Finite {}
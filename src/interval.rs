@@ -14,18 +14,23 @@
 // Local imports.
 use crate::bound::Bound;
 use crate::normalize::Finite;
+use crate::normalize::FiniteIndex;
 use crate::normalize::Normalize;
 use crate::raw_interval::RawInterval;
 
 // Standard library imports.
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::iter::FusedIterator;
 use std::ops::Range;
+use std::ops::RangeBounds;
 use std::ops::RangeFrom;
 use std::ops::RangeFull;
-// use std::ops::RangeInclusive; // TODO: Add when RangeInclusive accessors stabilize.
+use std::ops::RangeInclusive;
 use std::ops::RangeTo;
 use std::ops::RangeToInclusive;
 use std::ops::Sub;
+use std::str::FromStr;
 
 
 
@@ -90,6 +95,9 @@ impl<T> Interval<T>
     /// ```
     ///
     /// If the bounds are out of order, and empty `Interval` will be returned.
+    /// The checked [`try_new`] constructor returns an error instead.
+    ///
+    /// [`try_new`]: #method.try_new
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -110,6 +118,91 @@ impl<T> Interval<T>
     }
     
     
+    /// Constructs a new `Interval` from the bounds of the given range.
+    ///
+    /// This accepts any [`RangeBounds`] type, including the standard range
+    /// types and `(std::ops::Bound<T>, std::ops::Bound<T>)` tuples.
+    ///
+    /// [`RangeBounds`]: https://doc.rust-lang.org/std/ops/trait.RangeBounds.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::ops::Bound::*;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> =
+    ///     Interval::from_bounds((Excluded(3), Unbounded));
+    ///
+    /// assert_eq!(interval, Interval::unbounded_from(4));
+    /// assert_eq!(Interval::from_bounds(3..7), Interval::closed(3, 6));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_bounds<R>(range: R) -> Self where R: RangeBounds<T> {
+        Interval::new(
+            range.start_bound().cloned().into(),
+            range.end_bound().cloned().into())
+    }
+
+    /// Returns the bounds of the `Interval` as a pair of
+    /// [`std::ops::Bound`]s, which can be used with the standard range APIs.
+    ///
+    /// [`std::ops::Bound`]: https://doc.rust-lang.org/std/ops/enum.Bound.html
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryFromIntervalError::Empty`] if the `Interval` is empty,
+    /// because an empty range can not be represented by its bounds. The
+    /// `RangeBounds` implementation of `Interval` panics in this case
+    /// instead.
+    ///
+    /// [`TryFromIntervalError::Empty`]: enum.TryFromIntervalError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::collections::BTreeMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let map: BTreeMap<i32, char> = (0..5).zip("abcde".chars()).collect();
+    ///
+    /// let interval = Interval::open(0, 3);
+    /// let bounds = interval.to_range_bounds()?;
+    /// assert_eq!(map.range(bounds).map(|(_, c)| *c).collect::<String>(),
+    ///     "bc");
+    /// assert!(Interval::<i32>::empty().to_range_bounds().is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_range_bounds(&self)
+        -> Result<(std::ops::Bound<&T>, std::ops::Bound<&T>),
+            TryFromIntervalError>
+    {
+        use RawInterval::*;
+        use std::ops::Bound::*;
+        Ok(match self.0 {
+            Empty                   => return Err(TryFromIntervalError::Empty),
+            Point(ref p)            => (Included(p), Included(p)),
+            Open(ref l, ref r)      => (Excluded(l), Excluded(r)),
+            LeftOpen(ref l, ref r)  => (Excluded(l), Included(r)),
+            RightOpen(ref l, ref r) => (Included(l), Excluded(r)),
+            Closed(ref l, ref r)    => (Included(l), Included(r)),
+            UpTo(ref p)             => (Unbounded, Excluded(p)),
+            UpFrom(ref p)           => (Excluded(p), Unbounded),
+            To(ref p)               => (Unbounded, Included(p)),
+            From(ref p)             => (Included(p), Unbounded),
+            Full                    => (Unbounded, Unbounded),
+        })
+    }
+
+
     /// Constructs an empty `Interval`.
     ///
     /// # Example
@@ -184,6 +277,9 @@ impl<T> Interval<T>
     /// ```
     ///
     /// If the bounds are out of order, and empty `Interval` will be returned.
+    /// The checked [`try_open`] constructor returns an error instead.
+    ///
+    /// [`try_open`]: #method.try_open
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -237,6 +333,9 @@ impl<T> Interval<T>
     /// ```
     ///
     /// If the bounds are out of order, and empty `Interval` will be returned.
+    /// The checked [`try_left_open`] constructor returns an error instead.
+    ///
+    /// [`try_left_open`]: #method.try_left_open
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -252,7 +351,8 @@ impl<T> Interval<T>
     /// # }
     /// ```
     /// 
-    /// If the bounds are identical, a point `Interval` will be returned.
+    /// If the bounds are identical, a point `Interval` will be returned,
+    /// while [`try_left_open`] reports a degenerate interval.
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -306,6 +406,9 @@ impl<T> Interval<T>
     /// ```
     ///
     /// If the bounds are out of order, and empty `Interval` will be returned.
+    /// The checked [`try_right_open`] constructor returns an error instead.
+    ///
+    /// [`try_right_open`]: #method.try_right_open
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -321,7 +424,8 @@ impl<T> Interval<T>
     /// # }
     /// ```
     /// 
-    /// If the bounds are identical, a point `Interval` will be returned.
+    /// If the bounds are identical, a point `Interval` will be returned,
+    /// while [`try_right_open`] reports a degenerate interval.
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -357,6 +461,9 @@ impl<T> Interval<T>
     /// ```
     ///
     /// If the bounds are out of order, and empty `Interval` will be returned.
+    /// The checked [`try_closed`] constructor returns an error instead.
+    ///
+    /// [`try_closed`]: #method.try_closed
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -426,6 +533,9 @@ impl<T> Interval<T>
     /// ```
     ///
     /// If the bounds are out of order, and empty `Interval` will be returned.
+    /// The checked [`try_left_closed`] constructor returns an error instead.
+    ///
+    /// [`try_left_closed`]: #method.try_left_closed
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -441,7 +551,8 @@ impl<T> Interval<T>
     /// # }
     /// ```
     /// 
-    /// If the bounds are identical, a point `Interval` will be returned.
+    /// If the bounds are identical, a point `Interval` will be returned,
+    /// while [`try_left_closed`] reports a degenerate interval.
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -495,6 +606,9 @@ impl<T> Interval<T>
     /// ```
     ///
     /// If the bounds are out of order, and empty `Interval` will be returned.
+    /// The checked [`try_right_closed`] constructor returns an error instead.
+    ///
+    /// [`try_right_closed`]: #method.try_right_closed
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -510,7 +624,8 @@ impl<T> Interval<T>
     /// # }
     /// ```
     /// 
-    /// If the bounds are identical, a point `Interval` will be returned.
+    /// If the bounds are identical, a point `Interval` will be returned,
+    /// while [`try_right_closed`] reports a degenerate interval.
     ///
     /// ```rust
     /// # use std::error::Error;
@@ -724,6 +839,228 @@ impl<T> Interval<T>
         Interval(RawInterval::Full.normalized())
     }
     
+    ////////////////////////////////////////////////////////////////////////////
+    // Checked constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `Interval` from the given [`Bound`]s, or returns an
+    /// [`IntervalError`] if the bounds are out of order or exclude every
+    /// point.
+    ///
+    /// Unlike [`new`], which returns an empty `Interval` in these cases, this
+    /// is useful for validating bounds from untrusted data.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    /// [`IntervalError`]: enum.IntervalError.html
+    /// [`new`]: #method.new
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::try_new(Include(3), Exclude(7)),
+    ///     Ok(Interval::closed(3, 6)));
+    /// assert_eq!(Interval::try_new(Include(7), Exclude(3)),
+    ///     Err(IntervalError::InvertedBounds));
+    /// assert_eq!(Interval::try_new(Include(3), Exclude(3)),
+    ///     Err(IntervalError::DegenerateOpen));
+    ///
+    /// // Normalization may also exclude every point.
+    /// assert_eq!(Interval::try_new(Exclude(3), Exclude(4)),
+    ///     Err(IntervalError::DegenerateOpen));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_new(left: Bound<T>, right: Bound<T>)
+        -> Result<Self, IntervalError>
+    {
+        use Bound::*;
+        let open = left.is_exclusive() || right.is_exclusive();
+        match (&left, &right) {
+            (Include(l), Include(r)) |
+            (Include(l), Exclude(r)) |
+            (Exclude(l), Include(r)) |
+            (Exclude(l), Exclude(r)) => match T::cmp(l, r) {
+                Ordering::Greater => return Err(IntervalError::InvertedBounds),
+                Ordering::Equal if open
+                    => return Err(IntervalError::DegenerateOpen),
+                _ => (),
+            },
+            _ => (),
+        }
+
+        // Normalization may leave no points between the bounds.
+        let interval = Interval::new(left, right);
+        match (interval.infimum(), interval.supremum()) {
+            (Some(l), Some(r)) if l > r => Err(IntervalError::DegenerateOpen),
+            _ if interval.is_empty()    => Err(IntervalError::DegenerateOpen),
+            _                           => Ok(interval),
+        }
+    }
+
+    /// Constructs a new bounded open `Interval` from the given points, or
+    /// returns an [`IntervalError`] if the bounds are out of order or exclude
+    /// every point.
+    ///
+    /// [`IntervalError`]: enum.IntervalError.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::try_open(3, 7), Ok(Interval::closed(4, 6)));
+    /// assert_eq!(Interval::try_open(3, 3),
+    ///     Err(IntervalError::DegenerateOpen));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_open(left: T, right: T) -> Result<Self, IntervalError> {
+        Interval::try_new(Bound::Exclude(left), Bound::Exclude(right))
+    }
+
+    /// Constructs a new bounded left-open `Interval` from the given points, or
+    /// returns an [`IntervalError`] if the bounds are out of order or exclude
+    /// every point.
+    ///
+    /// [`IntervalError`]: enum.IntervalError.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::try_left_open(3, 7), Ok(Interval::closed(4, 7)));
+    /// assert_eq!(Interval::try_left_open(7, 3),
+    ///     Err(IntervalError::InvertedBounds));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_left_open(left: T, right: T) -> Result<Self, IntervalError> {
+        Interval::try_new(Bound::Exclude(left), Bound::Include(right))
+    }
+
+    /// Constructs a new bounded right-open `Interval` from the given points, or
+    /// returns an [`IntervalError`] if the bounds are out of order or exclude
+    /// every point.
+    ///
+    /// [`IntervalError`]: enum.IntervalError.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::try_right_open(3, 7), Ok(Interval::closed(3, 6)));
+    /// assert_eq!(Interval::try_right_open(3, 3),
+    ///     Err(IntervalError::DegenerateOpen));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_right_open(left: T, right: T) -> Result<Self, IntervalError> {
+        Interval::try_new(Bound::Include(left), Bound::Exclude(right))
+    }
+
+    /// Constructs a new bounded closed `Interval` from the given points, or
+    /// returns an [`IntervalError`] if the bounds are out of order or exclude
+    /// every point.
+    ///
+    /// [`IntervalError`]: enum.IntervalError.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::try_closed(3, 3), Ok(Interval::point(3)));
+    /// assert_eq!(Interval::try_closed(7, 3),
+    ///     Err(IntervalError::InvertedBounds));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_closed(left: T, right: T) -> Result<Self, IntervalError> {
+        Interval::try_new(Bound::Include(left), Bound::Include(right))
+    }
+
+    /// Constructs a new bounded left-closed `Interval` from the given points,
+    /// or returns an [`IntervalError`] if the bounds are out of order or
+    /// exclude every point.
+    ///
+    /// [`IntervalError`]: enum.IntervalError.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::try_left_closed(3, 7), Ok(Interval::closed(3, 6)));
+    /// assert_eq!(Interval::try_left_closed(3, 3),
+    ///     Err(IntervalError::DegenerateOpen));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_left_closed(left: T, right: T) -> Result<Self, IntervalError> {
+        Self::try_right_open(left, right)
+    }
+
+    /// Constructs a new bounded right-closed `Interval` from the given points,
+    /// or returns an [`IntervalError`] if the bounds are out of order or
+    /// exclude every point.
+    ///
+    /// [`IntervalError`]: enum.IntervalError.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Interval::try_right_closed(3, 7),
+    ///     Ok(Interval::closed(4, 7)));
+    /// assert_eq!(Interval::try_right_closed(3, 3),
+    ///     Err(IntervalError::DegenerateOpen));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_right_closed(left: T, right: T) -> Result<Self, IntervalError> {
+        Self::try_left_open(left, right)
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Conversion methods
     ////////////////////////////////////////////////////////////////////////////
@@ -949,6 +1286,27 @@ impl<T> Interval<T>
         }
     }
 
+    /// Returns the least and greatest points of the `Interval`, or `None` if
+    /// it is empty.
+    pub(in crate) fn closed_bounds(&self) -> Option<(T, T)> where T: Finite {
+        self.infimum().zip(self.supremum())
+    }
+
+    /// Returns the indices of the least and greatest points of the
+    /// `Interval`, or `None` if it is empty.
+    pub(in crate) fn index_bounds(&self) -> Option<(u64, u64)>
+        where T: FiniteIndex
+    {
+        self.closed_bounds()
+            .map(|(l, u)| (l.finite_index(), u.finite_index()))
+    }
+
+    /// Returns the number of points in the `Interval`, computed from its
+    /// bounds.
+    pub(in crate) fn point_count(&self) -> u128 where T: FiniteIndex {
+        self.index_bounds().map_or(0, |(l, u)| u128::from(u - l) + 1)
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Query operations
     ////////////////////////////////////////////////////////////////////////////
@@ -1685,6 +2043,47 @@ impl<T> Interval<T>
     pub fn closure(&self) -> Self {
         self.0.closure().normalized().into()
     }
+
+    /// Splits the `Interval` at the given point, returning the portions of
+    /// the `Interval` before and after it. The [`SplitSide`] determines which
+    /// portion includes the point.
+    ///
+    /// [`SplitSide`]: enum.SplitSide.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::interval::SplitSide;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(-3, 7);
+    /// assert_eq!(interval.split_at(&2, SplitSide::Left),
+    ///     (Interval::closed(-3, 2), Interval::closed(3, 7)));
+    /// assert_eq!(interval.split_at(&2, SplitSide::Right),
+    ///     (Interval::closed(-3, 1), Interval::closed(2, 7)));
+    /// assert_eq!(interval.split_at(&9, SplitSide::Right),
+    ///     (interval, Interval::empty()));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_at(&self, point: &T, side: SplitSide) -> (Self, Self) {
+        let p = point.clone();
+        let (before, after) = match side {
+            SplitSide::Left  => {
+                (RawInterval::To(p.clone()), RawInterval::UpFrom(p))
+            },
+            SplitSide::Right => {
+                (RawInterval::UpTo(p.clone()), RawInterval::From(p))
+            },
+        };
+        (
+            self.0.intersect(&before).normalized().into(),
+            self.0.intersect(&after).normalized().into(),
+        )
+    }
 }
 
 
@@ -1727,18 +2126,18 @@ impl<T> From<Range<T>> for Interval<T>
     }
 }
 
-// // TODO: Fix RangeInclusive once the accessors become available.
-// impl<T> From<RangeInclusive<T>> for Interval<T>
-//     where
-//         T: Ord + Clone,
-//         RawInterval<T>: Normalize,
-// {
-//     fn from(r: RangeInclusive<T>) -> Self {
-//         Interval(RawInterval::closed(
-//             r.next().unwrap(),
-//             r.next_back().unwrap()).normalized())
-//     }
-// }
+impl<T> From<RangeInclusive<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeInclusive<T>) -> Self {
+        // An exhausted range is empty regardless of its endpoints.
+        if r.is_empty() { return Interval::empty(); }
+        let (start, end) = r.into_inner();
+        Interval(RawInterval::closed(start, end).normalized())
+    }
+}
 
 impl<T> From<RangeFrom<T>> for Interval<T>
     where
@@ -1780,6 +2179,297 @@ impl<T> From<RangeFull> for Interval<T>
     }
 }
 
+/// Allows an `Interval` to be passed directly to the standard range APIs, such
+/// as `BTreeMap::range` and `Vec::drain`.
+///
+/// # Panics
+///
+/// `std::ops::Bound` can not represent an empty range, so `start_bound` and
+/// `end_bound` panic if the `Interval` is empty. Use
+/// [`Interval::to_range_bounds`] to handle empty intervals without panicking.
+///
+/// [`Interval::to_range_bounds`]: struct.Interval.html#method.to_range_bounds
+impl<T> RangeBounds<T> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn start_bound(&self) -> std::ops::Bound<&T> {
+        self.to_range_bounds().expect("empty interval has no start bound").0
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&T> {
+        self.to_range_bounds().expect("empty interval has no end bound").1
+    }
+}
+
+impl<T> TryFrom<Interval<T>> for Range<T>
+    where T: Ord + Clone + Finite,
+{
+    type Error = TryFromIntervalError;
+
+    fn try_from(interval: Interval<T>) -> Result<Self, Self::Error> {
+        use Bound::*;
+        let (lower, upper) = interval.finite_bounds()?;
+        let start = match lower {
+            Include(p) => p,
+            Exclude(p) => p.succ().ok_or(TryFromIntervalError::Unbounded)?,
+            Infinite   => return Err(TryFromIntervalError::Unbounded),
+        };
+        let end = match upper {
+            Include(p) => p.succ().ok_or(TryFromIntervalError::Unbounded)?,
+            Exclude(p) => p,
+            Infinite   => return Err(TryFromIntervalError::Unbounded),
+        };
+        Ok(start..end)
+    }
+}
+
+impl<T> TryFrom<Interval<T>> for RangeInclusive<T>
+    where T: Ord + Clone + Finite,
+{
+    type Error = TryFromIntervalError;
+
+    fn try_from(interval: Interval<T>) -> Result<Self, Self::Error> {
+        use Bound::*;
+        let (lower, upper) = interval.finite_bounds()?;
+        let start = match lower {
+            Include(p) => p,
+            Exclude(p) => p.succ().ok_or(TryFromIntervalError::Unbounded)?,
+            Infinite   => return Err(TryFromIntervalError::Unbounded),
+        };
+        let end = match upper {
+            Include(p) => p,
+            Exclude(p) => p.pred().ok_or(TryFromIntervalError::Unbounded)?,
+            Infinite   => return Err(TryFromIntervalError::Unbounded),
+        };
+        Ok(start..=end)
+    }
+}
+
+impl<T> Interval<T> where T: Ord + Clone + Finite {
+    /// Returns the lower and upper bounds of the `Interval` for conversion into
+    /// a range type.
+    fn finite_bounds(&self)
+        -> Result<(Bound<T>, Bound<T>), TryFromIntervalError>
+    {
+        match (self.0.lower_bound(), self.0.upper_bound()) {
+            (Some(lower), Some(upper)) => Ok((lower, upper)),
+            _                          => Err(TryFromIntervalError::Empty),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// TryFromIntervalError
+////////////////////////////////////////////////////////////////////////////////
+/// The error returned when an [`Interval`] can not be converted into a range
+/// type.
+///
+/// [`Interval`]: struct.Interval.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TryFromIntervalError {
+    /// The `Interval` is empty.
+    Empty,
+    /// The `Interval` has a bound which is not representable by the range.
+    ///
+    /// Unbounded `Interval`s of [`Finite`] types are normalized to the
+    /// extreme values of the type, so this occurs when converting an
+    /// `Interval` containing the maximum value into a `Range`, which has an
+    /// exclusive end.
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    Unbounded,
+}
+
+impl std::fmt::Display for TryFromIntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TryFromIntervalError::Empty     => write!(f, "interval is empty"),
+            TryFromIntervalError::Unbounded =>
+                write!(f, "interval bound is not representable by the range"),
+        }
+    }
+}
+
+impl std::error::Error for TryFromIntervalError {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalError
+////////////////////////////////////////////////////////////////////////////////
+/// The error returned by the checked `Interval` constructors, such as
+/// [`Interval::try_new`].
+///
+/// [`Interval::try_new`]: struct.Interval.html#method.try_new
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalError {
+    /// The left bound point is greater than the right bound point.
+    InvertedBounds,
+    /// The bounds exclude every point, such as an open interval with equal
+    /// bound points.
+    DegenerateOpen,
+}
+
+impl std::fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            IntervalError::InvertedBounds =>
+                write!(f, "interval bounds are out of order"),
+            IntervalError::DegenerateOpen =>
+                write!(f, "interval bounds exclude every point"),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Display and parsing
+////////////////////////////////////////////////////////////////////////////////
+// Display using interval notation.
+impl<T> std::fmt::Display for Interval<T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// Parses the interval notation produced by `Display`.
+impl<T> FromStr for Interval<T>
+    where
+        T: Ord + Clone + FromStr,
+        RawInterval<T>: Normalize,
+{
+    type Err = ParseIntervalError;
+
+    /// Parses an `Interval` from interval notation, such as `[3, 7)`,
+    /// `(-∞, 5]`, a single point, or `Ø` for the empty interval.
+    ///
+    /// The bounds are checked as by [`try_new`].
+    ///
+    /// [`try_new`]: struct.Interval.html#method.try_new
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::interval::IntervalError;
+    /// # use normalize_interval::interval::ParseIntervalError;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!("[3, 7)".parse(), Ok(Interval::closed(3, 6)));
+    /// assert_eq!("(-∞, 5]".parse(), Ok(Interval::unbounded_to(5)));
+    /// assert_eq!("4".parse(), Ok(Interval::point(4)));
+    /// assert_eq!("Ø".parse(), Ok(Interval::<i32>::empty()));
+    ///
+    /// assert_eq!("[7, 3]".parse::<Interval<i32>>(),
+    ///     Err(ParseIntervalError::Interval(IntervalError::InvertedBounds)));
+    /// assert_eq!("[3, 7".parse::<Interval<i32>>(),
+    ///     Err(ParseIntervalError::Syntax));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "Ø" { return Ok(Interval::empty()); }
+
+        let (left_open, rest) = match s.chars().next() {
+            Some('(') => (true, &s[1..]),
+            Some('[') => (false, &s[1..]),
+            _         => return s.parse()
+                .map(Interval::point)
+                .map_err(|_| ParseIntervalError::Point),
+        };
+        let (rest, right_open) = match rest.chars().next_back() {
+            Some(')') => (&rest[..rest.len() - 1], true),
+            Some(']') => (&rest[..rest.len() - 1], false),
+            _         => return Err(ParseIntervalError::Syntax),
+        };
+        let (left, right) = rest
+            .split_once(',')
+            .ok_or(ParseIntervalError::Syntax)?;
+
+        Interval::try_new(
+                parse_bound(left, left_open, "-∞")?,
+                parse_bound(right, right_open, "∞")?)
+            .map_err(ParseIntervalError::Interval)
+    }
+}
+
+/// Parses a `Bound` of an interval, given whether its bracket is open and the
+/// notation for its infinite bound.
+fn parse_bound<T>(s: &str, open: bool, infinity: &str)
+    -> Result<Bound<T>, ParseIntervalError>
+    where T: FromStr
+{
+    let s = s.trim();
+    match (s == infinity, open) {
+        (true, true)  => Ok(Bound::Infinite),
+        (true, false) => Err(ParseIntervalError::Syntax),
+        (false, _)    => {
+            let point = s.parse().map_err(|_| ParseIntervalError::Point)?;
+            Ok(if open { Bound::Exclude(point) } else { Bound::Include(point) })
+        },
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ParseIntervalError
+////////////////////////////////////////////////////////////////////////////////
+/// The error returned when parsing an [`Interval`] from a string.
+///
+/// [`Interval`]: struct.Interval.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseIntervalError {
+    /// The string is not in interval notation.
+    Syntax,
+    /// A bound point could not be parsed.
+    Point,
+    /// The bounds do not form a valid `Interval`.
+    Interval(IntervalError),
+}
+
+impl std::fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ParseIntervalError::Syntax      =>
+                write!(f, "invalid interval notation"),
+            ParseIntervalError::Point       =>
+                write!(f, "invalid interval bound point"),
+            ParseIntervalError::Interval(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseIntervalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseIntervalError::Interval(e) => Some(e),
+            _                               => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SplitSide
+////////////////////////////////////////////////////////////////////////////////
+/// The side of a split which receives the point it is split at, as in
+/// [`Interval::split_at`].
+///
+/// [`Interval::split_at`]: struct.Interval.html#method.split_at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SplitSide {
+    /// The split point is included in the lower portion.
+    Left,
+    /// The split point is included in the upper portion.
+    Right,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Default
 ////////////////////////////////////////////////////////////////////////////////
@@ -1833,6 +2523,39 @@ impl<T> Interval<T> where T: Ord + Clone + Finite {
             inner: self.clone(),
         }
     }
+
+    /// Returns an `Iterator` over consecutive `Interval`s of the given number
+    /// of points which cover the `Interval`. The last `Interval` may contain
+    /// fewer points. Only defined for `FiniteIndex` `Interval`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(0, 9);
+    /// assert_eq!(interval.chunks(4).collect::<Vec<_>>(), [
+    ///     Interval::closed(0, 3),
+    ///     Interval::closed(4, 7),
+    ///     Interval::closed(8, 9),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn chunks(&self, size: usize) -> Chunks<T> where T: FiniteIndex {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks {
+            rest: self.infimum().zip(self.supremum()),
+            size,
+        }
+    }
 }
 
 
@@ -1900,3 +2623,47 @@ impl<T> FusedIterator for Iter<T>
     where
         T: Ord + Clone + Finite
 {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Chunks
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over consecutive fixed-size `Interval`s covering an
+/// `Interval`.
+#[derive(Debug, Clone)]
+pub struct Chunks<T> {
+    /// The closed bounds of the portion of the `Interval` not yet iterated.
+    rest: Option<(T, T)>,
+    /// The number of points in each chunk.
+    size: usize,
+}
+
+impl<T> Chunks<T> where T: FiniteIndex {
+    /// Returns the next `Interval` of up to `n` points, along with the number
+    /// of points in it. The number of points must be non-zero.
+    pub(in crate) fn take_points(&mut self, n: u128)
+        -> Option<(Interval<T>, u128)>
+    {
+        debug_assert!(n != 0, "chunk size must be non-zero");
+        let (l, r) = self.rest.take()?;
+        let (lower, upper) = (l.finite_index(), r.finite_index());
+        let count = n.min(u128::from(upper - lower) + 1);
+        // The count is at most the width of the remaining bounds.
+        let end = lower + (count - 1) as u64;
+        if end < upper {
+            self.rest = Some((T::from_finite_index(end + 1), r));
+        }
+        Some((Interval::closed(l, T::from_finite_index(end)), count))
+    }
+}
+
+impl<T> Iterator for Chunks<T> where T: FiniteIndex {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = self.size as u128;
+        self.take_points(size).map(|(interval, _)| interval)
+    }
+}
+
+impl<T> FusedIterator for Chunks<T> where T: FiniteIndex {}
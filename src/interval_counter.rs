@@ -0,0 +1,251 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a multiset of intervals which counts the coverage of each point.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::iter::Peekable;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalCounter<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A multiset of `Interval`s of the type `T`, which tracks how many of its
+/// intervals cover each point.
+///
+/// Like the `TineTree` underlying a [`Selection`], an `IntervalCounter` is
+/// stored as a number line with markers on it. Each marker records the depth
+/// of coverage from its point up to the next marker, so the coverage forms
+/// piecewise-constant segments.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::interval_counter::IntervalCounter;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut counter = IntervalCounter::new();
+/// counter.add(Interval::closed(0, 10), 1);
+/// counter.add(Interval::closed(5, 15), 2);
+///
+/// assert_eq!(counter.depth(&3), 1);
+/// assert_eq!(counter.depth(&7), 3);
+/// assert_eq!(counter.segments().collect::<Vec<_>>(), [
+///     (Interval::closed(0, 4), 1),
+///     (Interval::closed(5, 10), 3),
+///     (Interval::closed(11, 15), 2),
+/// ]);
+///
+/// let deep = counter.at_least(2);
+/// assert_eq!(deep.interval_iter().next(), Some(Interval::closed(5, 15)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalCounter<T> {
+    /// The depth of coverage starting at each marker and continuing up to the
+    /// next marker. Points before the first marker are uncovered, and
+    /// adjacent markers have different depths.
+    tines: BTreeMap<T, usize>,
+}
+
+impl<T> IntervalCounter<T> where T: Ord + Clone + Finite {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `IntervalCounter`.
+    pub fn new() -> Self {
+        IntervalCounter { tines: BTreeMap::new() }
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `IntervalCounter` covers no points.
+    pub fn is_empty(&self) -> bool {
+        self.tines.is_empty()
+    }
+
+    /// Returns the number of intervals covering the given point.
+    pub fn depth(&self, point: &T) -> usize {
+        self.tines
+            .range(..=point)
+            .next_back()
+            .map_or(0, |(_, depth)| *depth)
+    }
+
+    /// Returns the greatest depth of coverage of any point.
+    pub fn max_depth(&self) -> usize {
+        self.tines.values().copied().max().unwrap_or(0)
+    }
+
+    /// Returns an iterator over the covered segments of the
+    /// `IntervalCounter`, paired with their depth of coverage. Each segment
+    /// is a maximal `Interval` of constant, nonzero depth.
+    pub fn segments(&self) -> Segments<'_, T> {
+        Segments { tines: self.tines.iter().peekable() }
+    }
+
+    /// Returns a `Selection` of the points covered by at least the given
+    /// number of intervals.
+    pub fn at_least(&self, depth: usize) -> Selection<T> {
+        if depth == 0 { return Selection::full(); }
+        self.segments()
+            .filter(|(_, d)| *d >= depth)
+            .map(|(interval, _)| interval)
+            .collect()
+    }
+
+    // Update operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Adds the given `Interval` to the `IntervalCounter` with the given
+    /// multiplicity.
+    pub fn add(&mut self, interval: Interval<T>, multiplicity: usize) {
+        if multiplicity == 0 { return; }
+        let (l, r) = match interval.closed_bounds() {
+            Some(bounds) => bounds,
+            None         => return,
+        };
+        self.update(l, r, |depth| *depth += multiplicity);
+    }
+
+    /// Removes the given `Interval` from the `IntervalCounter` with the given
+    /// multiplicity.
+    ///
+    /// Returns `false` and leaves the `IntervalCounter` unchanged if any point
+    /// of the `Interval` is covered by fewer than `multiplicity` intervals.
+    pub fn remove(&mut self, interval: Interval<T>, multiplicity: usize)
+        -> bool
+    {
+        let (l, r) = match interval.closed_bounds() {
+            Some(bounds) => bounds,
+            None         => return true,
+        };
+        let covered = self.depth(&l) >= multiplicity && self.tines
+            .range(&l..=&r)
+            .all(|(_, depth)| *depth >= multiplicity);
+        if !covered { return false; }
+
+        if multiplicity > 0 {
+            self.update(l, r, |depth| *depth -= multiplicity);
+        }
+        true
+    }
+
+    /// Removes every interval from the `IntervalCounter`.
+    pub fn clear(&mut self) {
+        self.tines.clear();
+    }
+
+    /// Applies the given function to the depth of every point in the closed
+    /// range from `l` to `r`.
+    fn update<F>(&mut self, l: T, r: T, f: F) where F: Fn(&mut usize) {
+        let end = r.succ();
+        self.split_at(&l);
+        if let Some(end) = &end { self.split_at(end); }
+
+        for (_, depth) in self.tines.range_mut(&l..=&r) {
+            f(depth);
+        }
+
+        self.coalesce_at(&l);
+        if let Some(end) = &end { self.coalesce_at(end); }
+    }
+
+    /// Ensures a marker exists at the given point.
+    fn split_at(&mut self, point: &T) {
+        if !self.tines.contains_key(point) {
+            let depth = self.depth(point);
+            let _ = self.tines.insert(point.clone(), depth);
+        }
+    }
+
+    /// Removes the marker at the given point if it does not change the depth.
+    fn coalesce_at(&mut self, point: &T) {
+        let prev = self.tines
+            .range(..point)
+            .next_back()
+            .map_or(0, |(_, depth)| *depth);
+        if self.tines.get(point) == Some(&prev) {
+            let _ = self.tines.remove(point);
+        }
+    }
+}
+
+impl<T> Default for IntervalCounter<T> where T: Ord + Clone + Finite {
+    fn default() -> Self {
+        IntervalCounter::new()
+    }
+}
+
+impl<T> Extend<Interval<T>> for IntervalCounter<T>
+    where T: Ord + Clone + Finite,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        for interval in iter.into_iter() {
+            self.add(interval, 1);
+        }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for IntervalCounter<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        let mut counter = IntervalCounter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Segments
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the covered segments of an `IntervalCounter` and their
+/// depths.
+#[derive(Debug)]
+pub struct Segments<'t, T> {
+    tines: Peekable<btree_map::Iter<'t, T, usize>>,
+}
+
+impl<'t, T> Iterator for Segments<'t, T> where T: Ord + Clone + Finite {
+    type Item = (Interval<T>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (start, depth) = self.tines.next()?;
+            if *depth == 0 { continue; }
+            let end = match self.tines.peek() {
+                Some((next, _)) => next.pred().expect("predecessor of marker"),
+                None            => T::MAXIMUM,
+            };
+            return Some((Interval::closed(start.clone(), end), *depth));
+        }
+    }
+}
+
+impl<'t, T> FusedIterator for Segments<'t, T> where T: Ord + Clone + Finite {}
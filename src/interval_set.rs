@@ -0,0 +1,229 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a common trait for interval set representations.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+use crate::stream;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalSet
+////////////////////////////////////////////////////////////////////////////////
+/// A set of points represented by a sequence of disjoint [`Interval`]s.
+///
+/// Implementors need only provide [`interval_iter`]. Set operations produce
+/// the associated [`Output`] type, which is built using its [`Default`] and
+/// [`Extend`] implementations; extending an `Output` must union the given
+/// `Interval`s into it. The default set operations merge the sorted
+/// `Interval`s of their operands lazily and extend the `Output` once with the
+/// result. Every other method has a default implementation in terms of these,
+/// which implementors may override with more efficient versions.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+/// [`interval_iter`]: #tymethod.interval_iter
+/// [`Output`]: #associatedtype.Output
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+/// [`Extend`]: https://doc.rust-lang.org/std/iter/trait.Extend.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::IntervalSet;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// fn total_size<S>(set: &S) -> usize where S: IntervalSet<i32> {
+///     set.interval_iter().map(|i| i.iter().count()).sum()
+/// }
+///
+/// let mut sel: Selection<i32> = Selection::new();
+/// sel.union_in_place(Interval::closed(0, 4));
+/// sel.union_in_place(Interval::closed(8, 9));
+///
+/// assert_eq!(total_size(&sel), 7);
+/// assert_eq!(total_size(&Interval::closed(3, 5)), 3);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub trait IntervalSet<T> where T: Ord + Clone + Finite {
+    /// The set type produced by set operations.
+    type Output: IntervalSet<T> + Default + Extend<Interval<T>>;
+
+    // Required methods
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the non-empty, disjoint, and non-adjacent
+    /// `Interval`s of the set, in ascending order.
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_;
+
+    // Bound accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the lower [`Bound`] of the set, or `None` if the set is empty.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    fn lower_bound(&self) -> Option<Bound<T>> {
+        self.interval_iter().next().and_then(|i| i.lower_bound())
+    }
+
+    /// Returns the upper [`Bound`] of the set, or `None` if the set is empty.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    fn upper_bound(&self) -> Option<Bound<T>> {
+        self.interval_iter().last().and_then(|i| i.upper_bound())
+    }
+
+    /// Returns the greatest lower bound of the set, or `None` if the set is
+    /// empty.
+    fn infimum(&self) -> Option<T> {
+        self.interval_iter().next().and_then(|i| i.infimum())
+    }
+
+    /// Returns the least upper bound of the set, or `None` if the set is
+    /// empty.
+    fn supremum(&self) -> Option<T> {
+        self.interval_iter().last().and_then(|i| i.supremum())
+    }
+
+    /// Returns the smallest `Interval` containing every point in the set.
+    fn enclose(&self) -> Interval<T> {
+        match (self.lower_bound(), self.upper_bound()) {
+            (Some(lower), Some(upper)) => Interval::new(lower, upper),
+            _                          => Interval::empty(),
+        }
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the set contains no points.
+    fn is_empty(&self) -> bool {
+        self.interval_iter().next().is_none()
+    }
+
+    /// Returns `true` if the set contains the given point.
+    fn contains(&self, point: &T) -> bool {
+        self.interval_iter()
+            .take_while(|i| i.infimum().is_some_and(|l| l <= *point))
+            .any(|i| i.contains(point))
+    }
+
+    /// Returns `true` if the set shares any points with the given set.
+    fn intersects<S>(&self, other: &S) -> bool where S: IntervalSet<T> {
+        stream::intersect(self.interval_iter(), other.interval_iter())
+            .next()
+            .is_some()
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the set of points not contained in the set.
+    fn complement(&self) -> Self::Output {
+        let mut output = Self::Output::default();
+        output.extend(stream::minus(Some(Interval::full()),
+            self.interval_iter()));
+        output
+    }
+
+    /// Returns the set of points contained in both sets.
+    fn intersect<S>(&self, other: &S) -> Self::Output where S: IntervalSet<T> {
+        let mut output = Self::Output::default();
+        output.extend(stream::intersect(self.interval_iter(),
+            other.interval_iter()));
+        output
+    }
+
+    /// Returns the set of points contained in either set.
+    fn union<S>(&self, other: &S) -> Self::Output where S: IntervalSet<T> {
+        let mut output = Self::Output::default();
+        output.extend(stream::union(self.interval_iter(),
+            other.interval_iter()));
+        output
+    }
+
+    /// Returns the set of points contained in the set but not in the given
+    /// set.
+    fn minus<S>(&self, other: &S) -> Self::Output where S: IntervalSet<T> {
+        let mut output = Self::Output::default();
+        output.extend(stream::minus(self.interval_iter(),
+            other.interval_iter()));
+        output
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection
+////////////////////////////////////////////////////////////////////////////////
+impl<T> IntervalSet<T> for Selection<T> where T: Ord + Clone + Finite {
+    type Output = Selection<T>;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        Selection::interval_iter(self)
+    }
+
+    fn upper_bound(&self) -> Option<Bound<T>> {
+        Selection::interval_iter(self)
+            .next_back()
+            .and_then(|i| i.upper_bound())
+    }
+
+    fn supremum(&self) -> Option<T> {
+        Selection::interval_iter(self)
+            .next_back()
+            .and_then(|i| i.supremum())
+    }
+
+    fn is_empty(&self) -> bool {
+        Selection::is_empty(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        Selection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self::Output {
+        Selection::complement(self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval
+////////////////////////////////////////////////////////////////////////////////
+impl<T> IntervalSet<T> for Interval<T> where T: Ord + Clone + Finite {
+    type Output = Selection<T>;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        self.clone().into_non_empty().into_iter()
+    }
+
+    fn enclose(&self) -> Interval<T> {
+        self.clone()
+    }
+
+    fn is_empty(&self) -> bool {
+        Interval::is_empty(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        Interval::contains(self, point)
+    }
+}
@@ -53,12 +53,30 @@ pub(in crate) mod utility {
 mod test;
 
 // Public modules.
+pub mod allocator;
+pub mod arbitrary;
+pub mod bit_selection;
 pub mod bound;
+pub mod calendar;
+pub mod datetime;
+pub mod frozen_selection;
 pub mod interval;
+pub mod interval_counter;
+pub mod interval_set;
 pub mod normalize;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod persistent_selection;
+pub mod recurrence;
+pub mod roaring_selection;
 pub mod selection;
+pub mod set_expr;
+pub mod small_selection;
+pub mod stream;
+pub mod sweep;
 
 // Exports.
 pub use crate::bound::Bound;
 pub use crate::interval::Interval;
+pub use crate::interval_set::IntervalSet;
 pub use crate::selection::Selection;
@@ -38,6 +38,28 @@ pub trait Finite: Sized {
 }
 
 
+////////////////////////////////////////////////////////////////////////////////
+// FiniteIndex
+////////////////////////////////////////////////////////////////////////////////
+/// A [`Finite`] type whose points can be numbered densely and in order from
+/// zero, so that they can be stored, counted, or divided by index.
+///
+/// [`Finite`]: trait.Finite.html
+pub trait FiniteIndex: Finite + Ord + Clone {
+    /// The number of bits needed to index every point of the type.
+    const INDEX_BITS: u32;
+
+    /// Returns the index of the point, counting from `MINIMUM`.
+    fn finite_index(&self) -> u64;
+
+    /// Returns the point with the given index.
+    ///
+    /// The index must be at most the index of `MAXIMUM`, which is less than
+    /// 2<sup>`INDEX_BITS`</sup>.
+    fn from_finite_index(index: u64) -> Self;
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 // Normalize
 ////////////////////////////////////////////////////////////////////////////////
@@ -75,24 +97,26 @@ pub trait Normalize {
 // }
 
 /// Specialization for [`Finite`] intervals.
-impl<T> Normalize for RawInterval<T> where T: Finite {
+impl<T> Normalize for RawInterval<T> where T: Ord + Clone + Finite {
     fn normalize(&mut self) {
         use RawInterval::*;
         *self = match std::mem::replace(self, Empty) {
             Empty           => Empty,
             Point(p)        => Point(p),
             Open(l, r)      => match (l.succ(), r.pred()) {
-                (Some(l), Some(r)) => Closed(l, r),
+                (Some(l), Some(r)) => Self::closed(l, r),
                 _                  => Empty,
             },
-            LeftOpen(l, r)  => l.succ().map_or(Empty, |l| Closed(l, r)),
-            RightOpen(l, r) => r.pred().map_or(Empty, |r| Closed(l, r)),
-            Closed(l, r)    => Closed(l, r),
-            UpTo(r)         => r.pred().map_or(Empty, |r| Closed(T::MINIMUM, r)),
-            UpFrom(l)       => l.succ().map_or(Empty, |l| Closed(l, T::MAXIMUM)),
-            To(p)           => Closed(T::MINIMUM, p),
-            From(p)         => Closed(p, T::MAXIMUM),
-            Full            => Closed(T::MINIMUM, T::MAXIMUM),
+            LeftOpen(l, r)  => l.succ().map_or(Empty, |l| Self::closed(l, r)),
+            RightOpen(l, r) => r.pred().map_or(Empty, |r| Self::closed(l, r)),
+            Closed(l, r)    => Self::closed(l, r),
+            UpTo(r)         => r.pred()
+                .map_or(Empty, |r| Self::closed(T::MINIMUM, r)),
+            UpFrom(l)       => l.succ()
+                .map_or(Empty, |l| Self::closed(l, T::MAXIMUM)),
+            To(p)           => Self::closed(T::MINIMUM, p),
+            From(p)         => Self::closed(p, T::MAXIMUM),
+            Full            => Self::closed(T::MINIMUM, T::MAXIMUM),
         }
     }
 
@@ -160,5 +184,33 @@ std_integer_finite_impl![
     i8, i16, i32, i64, i128, isize
 ];
 
+// Implements `FiniteIndex` for a builtin integer type, given the unsigned type
+// of the same width. Signed points are offset so that `MINIMUM` has index
+// zero. The casts are trivial for the unsigned types.
+macro_rules! std_integer_finite_index_impl {
+    // For each given type...
+    ($($t:ident : $u:ident),*) => {
+        $(#[allow(trivial_numeric_casts)]
+        impl FiniteIndex for $t {
+            const INDEX_BITS: u32 = <$t>::BITS;
+
+            fn finite_index(&self) -> u64 {
+                (*self as $u).wrapping_sub(<$t>::MIN as $u) as u64
+            }
+
+            fn from_finite_index(index: u64) -> Self {
+                (index as $u).wrapping_add(<$t>::MIN as $u) as $t
+            }
+        })*
+    };
+}
+
+// Provide implementations of FiniteIndex for builtin integer types of at most
+// 64 bits.
+std_integer_finite_index_impl![
+    u8: u8, u16: u16, u32: u32, u64: u64, usize: usize,
+    i8: u8, i16: u16, i32: u32, i64: u64, isize: usize
+];
+
 
 // TODO: Use nextUp and nextDown IEEE 754 functions to normalize float values?
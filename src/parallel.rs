@@ -0,0 +1,294 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides [`rayon`] parallel iterators over the points and intervals of a
+//! [`Selection`]. Enabled by the `rayon` cargo feature.
+//!
+//! Work is divided by point count rather than by interval count, so a
+//! `Selection` made of one large interval and many small ones is still split
+//! evenly between threads.
+//!
+//! [`rayon`]: https://docs.rs/rayon
+//! [`Selection`]: ../selection/struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::FiniteIndex;
+use crate::selection::Selection;
+
+// External library imports.
+use rayon::iter::FromParallelIterator;
+use rayon::iter::IntoParallelIterator;
+use rayon::iter::ParallelIterator;
+use rayon::iter::plumbing::bridge_unindexed;
+use rayon::iter::plumbing::Folder;
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::plumbing::UnindexedProducer;
+
+// Standard library imports.
+use std::marker::PhantomData;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection parallel iterators
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Selection<T> where T: FiniteIndex + Send {
+    /// Returns a parallel iterator over each of the points in the
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// use rayon::iter::ParallelIterator;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u64> = Selection::from(Interval::closed(0, 999));
+    /// sel.union_in_place(Interval::closed(5000, 5999));
+    ///
+    /// assert_eq!(sel.par_iter().filter(|p| p % 2 == 0).count(), 1000);
+    /// assert_eq!(sel.par_iter().max(), Some(5999));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn par_iter(&self) -> ParIter<T> {
+        let runs = runs(self);
+        let total = runs.last().map_or(0, Run::end_rank);
+        ParIter { runs, total, marker: PhantomData }
+    }
+
+    /// Returns a parallel iterator over each of the `Interval`s in the
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// use rayon::iter::ParallelIterator;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u64> = Selection::from(Interval::closed(0, 9));
+    /// sel.union_in_place(Interval::closed(20, 29));
+    ///
+    /// let intervals: Vec<_> = sel.par_interval_iter().collect();
+    /// assert_eq!(intervals,
+    ///     [Interval::closed(0, 9), Interval::closed(20, 29)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn par_interval_iter(&self) -> ParIntervalIter<T> {
+        ParIntervalIter { runs: runs(self), marker: PhantomData }
+    }
+}
+
+impl<T> FromParallelIterator<Interval<T>> for Selection<T>
+    where T: Ord + Clone + Finite + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+        where I: IntoParallelIterator<Item=Interval<T>>,
+    {
+        par_iter
+            .into_par_iter()
+            .fold(Selection::new, |mut selection, interval| {
+                selection.union_in_place(interval);
+                selection
+            })
+            .reduce(Selection::new, |mut selection, other| {
+                for interval in other.into_interval_iter() {
+                    selection.union_in_place(interval);
+                }
+                selection
+            })
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Run
+////////////////////////////////////////////////////////////////////////////////
+/// The point indices of a single interval of a `Selection`.
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    /// The index of the least point of the interval.
+    lower: u64,
+    /// The index of the greatest point of the interval.
+    upper: u64,
+    /// The number of points in the `Selection` before the interval.
+    offset: u128,
+}
+
+impl Run {
+    /// Returns the number of points in the `Selection` up to and including the
+    /// interval.
+    fn end_rank(&self) -> u128 {
+        self.offset + u128::from(self.upper - self.lower) + 1
+    }
+
+    /// Returns the interval as an `Interval`.
+    fn interval<T>(&self) -> Interval<T> where T: FiniteIndex + Send {
+        Interval::closed(
+            T::from_finite_index(self.lower),
+            T::from_finite_index(self.upper))
+    }
+}
+
+/// Returns the `Run`s of the given `Selection`.
+fn runs<T>(selection: &Selection<T>) -> Vec<Run> where T: FiniteIndex + Send {
+    let mut offset = 0;
+    selection
+        .interval_iter()
+        .filter_map(|interval| {
+            let (lower, upper) = interval.index_bounds()?;
+            let run = Run { lower, upper, offset };
+            offset = run.end_rank();
+            Some(run)
+        })
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ParIter
+////////////////////////////////////////////////////////////////////////////////
+/// A `ParallelIterator` over the points of a `Selection`.
+#[derive(Debug)]
+pub struct ParIter<T> {
+    runs: Vec<Run>,
+    total: u128,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ParallelIterator for ParIter<T> where T: FiniteIndex + Send {
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>,
+    {
+        let producer = PointProducer {
+            runs: &self.runs,
+            start: 0,
+            end: self.total,
+            marker: PhantomData,
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+/// An `UnindexedProducer` of the points with ranks in `start..end`.
+struct PointProducer<'r, T> {
+    runs: &'r [Run],
+    start: u128,
+    end: u128,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'r, T> UnindexedProducer for PointProducer<'r, T>
+    where T: FiniteIndex + Send,
+{
+    type Item = T;
+
+    fn split(self) -> (Self, Option<Self>) {
+        if self.end - self.start < 2 {
+            return (self, None);
+        }
+        let mid = self.start + (self.end - self.start) / 2;
+        let upper = PointProducer {
+            runs: self.runs,
+            start: mid,
+            end: self.end,
+            marker: PhantomData,
+        };
+        (PointProducer { end: mid, ..self }, Some(upper))
+    }
+
+    fn fold_with<F>(self, mut folder: F) -> F where F: Folder<Self::Item> {
+        let first = self.runs.partition_point(|run| {
+            run.end_rank() <= self.start
+        });
+        for run in &self.runs[first..] {
+            if run.offset >= self.end || folder.full() { break; }
+
+            let skip = self.start.saturating_sub(run.offset);
+            let take = self.end.min(run.end_rank()) - run.offset;
+            // Both are bounded by the run length, so the casts are lossless.
+            let lower = run.lower + skip as u64;
+            let upper = run.lower + (take - 1) as u64;
+            folder = folder.consume_iter(
+                (lower..=upper).map(T::from_finite_index));
+        }
+        folder
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ParIntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// A `ParallelIterator` over the `Interval`s of a `Selection`.
+#[derive(Debug)]
+pub struct ParIntervalIter<T> {
+    runs: Vec<Run>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ParallelIterator for ParIntervalIter<T> where T: FiniteIndex + Send {
+    type Item = Interval<T>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>,
+    {
+        let producer = IntervalProducer {
+            runs: &self.runs,
+            marker: PhantomData,
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+/// An `UnindexedProducer` of whole intervals, which splits between the
+/// intervals nearest the median point.
+struct IntervalProducer<'r, T> {
+    runs: &'r [Run],
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'r, T> UnindexedProducer for IntervalProducer<'r, T>
+    where T: FiniteIndex + Send,
+{
+    type Item = Interval<T>;
+
+    fn split(self) -> (Self, Option<Self>) {
+        let (first, last) = match self.runs {
+            [first, .., last] => (first, last),
+            _                 => return (self, None),
+        };
+        let median = first.offset + (last.end_rank() - first.offset) / 2;
+        let mid = self.runs
+            .partition_point(|run| run.end_rank() <= median)
+            .clamp(1, self.runs.len() - 1);
+        let (lower, upper) = self.runs.split_at(mid);
+        (
+            IntervalProducer { runs: lower, marker: PhantomData },
+            Some(IntervalProducer { runs: upper, marker: PhantomData }),
+        )
+    }
+
+    fn fold_with<F>(self, folder: F) -> F where F: Folder<Self::Item> {
+        folder.consume_iter(self.runs.iter().map(Run::interval))
+    }
+}
@@ -0,0 +1,589 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a persistent selection whose versions share structure.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::sync::Arc;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Tree
+////////////////////////////////////////////////////////////////////////////////
+/// A persistent AVL tree of closed interval bounds. Nodes are never modified
+/// once built; updates copy the path to the changed node and share the rest.
+type Tree<T> = Option<Arc<Node<T>>>;
+
+/// A node of a persistent interval tree.
+#[derive(Debug)]
+struct Node<T> {
+    /// The closed bounds of the node's interval.
+    bounds: (T, T),
+    /// The intervals before the node's interval.
+    left: Tree<T>,
+    /// The intervals after the node's interval.
+    right: Tree<T>,
+    /// The height of the subtree rooted at the node.
+    height: usize,
+}
+
+/// Returns the height of the tree.
+fn height<T>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |n| n.height)
+}
+
+/// Returns a new node with the given children.
+fn create<T>(left: Tree<T>, bounds: (T, T), right: Tree<T>) -> Tree<T> {
+    let height = height(&left).max(height(&right)) + 1;
+    Some(Arc::new(Node { bounds, left, right, height }))
+}
+
+/// Returns a new node with the given children, rotating to restore balance if
+/// their heights differ by at most three.
+fn balance<T>(left: Tree<T>, bounds: (T, T), right: Tree<T>) -> Tree<T>
+    where T: Clone
+{
+    let (hl, hr) = (height(&left), height(&right));
+    if hl > hr + 2 {
+        let l = left.expect("nonempty left subtree");
+        if height(&l.left) >= height(&l.right) {
+            create(
+                l.left.clone(),
+                l.bounds.clone(),
+                create(l.right.clone(), bounds, right))
+        } else {
+            let lr = l.right.as_ref().expect("nonempty left-right subtree");
+            create(
+                create(l.left.clone(), l.bounds.clone(), lr.left.clone()),
+                lr.bounds.clone(),
+                create(lr.right.clone(), bounds, right))
+        }
+    } else if hr > hl + 2 {
+        let r = right.expect("nonempty right subtree");
+        if height(&r.right) >= height(&r.left) {
+            create(
+                create(left, bounds, r.left.clone()),
+                r.bounds.clone(),
+                r.right.clone())
+        } else {
+            let rl = r.left.as_ref().expect("nonempty right-left subtree");
+            create(
+                create(left, bounds, rl.left.clone()),
+                rl.bounds.clone(),
+                create(rl.right.clone(), r.bounds.clone(), r.right.clone()))
+        }
+    } else {
+        create(left, bounds, right)
+    }
+}
+
+/// Returns the tree with the given bounds added before all others.
+fn add_min<T>(bounds: (T, T), tree: &Tree<T>) -> Tree<T> where T: Clone {
+    match tree {
+        None    => create(None, bounds, None),
+        Some(n) => balance(
+            add_min(bounds, &n.left),
+            n.bounds.clone(),
+            n.right.clone()),
+    }
+}
+
+/// Returns the tree with the given bounds added after all others.
+fn add_max<T>(bounds: (T, T), tree: &Tree<T>) -> Tree<T> where T: Clone {
+    match tree {
+        None    => create(None, bounds, None),
+        Some(n) => balance(
+            n.left.clone(),
+            n.bounds.clone(),
+            add_max(bounds, &n.right)),
+    }
+}
+
+/// Joins two trees and the bounds between them into a balanced tree.
+fn join<T>(left: Tree<T>, bounds: (T, T), right: Tree<T>) -> Tree<T>
+    where T: Clone
+{
+    match (&left, &right) {
+        (None, _) => add_min(bounds, &right),
+        (_, None) => add_max(bounds, &left),
+        (Some(l), Some(r)) => {
+            if l.height > r.height + 2 {
+                balance(
+                    l.left.clone(),
+                    l.bounds.clone(),
+                    join(l.right.clone(), bounds, right))
+            } else if r.height > l.height + 2 {
+                balance(
+                    join(left, bounds, r.left.clone()),
+                    r.bounds.clone(),
+                    r.right.clone())
+            } else {
+                create(left, bounds, right)
+            }
+        },
+    }
+}
+
+/// Returns the first bounds of the tree and the tree without them.
+fn pop_min<T>(tree: &Tree<T>) -> Option<((T, T), Tree<T>)> where T: Clone {
+    let n = tree.as_ref()?;
+    Some(match pop_min(&n.left) {
+        None => (n.bounds.clone(), n.right.clone()),
+        Some((min, rest)) =>
+            (min, balance(rest, n.bounds.clone(), n.right.clone())),
+    })
+}
+
+/// Returns the last bounds of the tree and the tree without them.
+fn pop_max<T>(tree: &Tree<T>) -> Option<((T, T), Tree<T>)> where T: Clone {
+    let n = tree.as_ref()?;
+    Some(match pop_max(&n.right) {
+        None => (n.bounds.clone(), n.left.clone()),
+        Some((max, rest)) =>
+            (max, balance(n.left.clone(), n.bounds.clone(), rest)),
+    })
+}
+
+/// Returns the first bounds of the tree.
+fn first<T>(tree: &Tree<T>) -> Option<&(T, T)> {
+    let mut n = tree.as_ref()?;
+    while let Some(left) = &n.left { n = left; }
+    Some(&n.bounds)
+}
+
+/// Returns the last bounds of the tree.
+fn last<T>(tree: &Tree<T>) -> Option<&(T, T)> {
+    let mut n = tree.as_ref()?;
+    while let Some(right) = &n.right { n = right; }
+    Some(&n.bounds)
+}
+
+/// Joins two trees whose intervals are ordered.
+fn concat<T>(left: Tree<T>, right: &Tree<T>) -> Tree<T> where T: Clone {
+    match pop_min(right) {
+        None              => left,
+        Some((min, rest)) => join(left, min, rest),
+    }
+}
+
+/// Splits the tree into the intervals satisfying the given predicate and those
+/// after them. The predicate must hold for a prefix of the intervals.
+fn split<T, F>(tree: &Tree<T>, before: &F) -> (Tree<T>, Tree<T>)
+    where
+        T: Clone,
+        F: Fn(&(T, T)) -> bool,
+{
+    match tree {
+        None => (None, None),
+        Some(n) if before(&n.bounds) => {
+            let (l, r) = split(&n.right, before);
+            (join(n.left.clone(), n.bounds.clone(), l), r)
+        },
+        Some(n) => {
+            let (l, r) = split(&n.left, before);
+            (l, join(r, n.bounds.clone(), n.right.clone()))
+        },
+    }
+}
+
+/// Builds a balanced tree from sorted, disjoint, and non-adjacent bounds.
+fn build<T>(bounds: &[(T, T)]) -> Tree<T> where T: Clone {
+    if bounds.is_empty() { return None; }
+    let mid = bounds.len() / 2;
+    create(
+        build(&bounds[..mid]),
+        bounds[mid].clone(),
+        build(&bounds[mid + 1..]))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// PersistentSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of `Interval`s of the type `T`, stored
+/// in a persistent balanced tree.
+///
+/// Cloning a `PersistentSelection` is O(1), and each in-place update takes
+/// O(log n) time and copies only the path to the changed intervals, so
+/// earlier versions remain valid and share most of their structure. This
+/// makes it suitable for keeping undo histories or historical versions of a
+/// selection.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::persistent_selection::PersistentSelection;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut history = vec![PersistentSelection::new()];
+///
+/// let mut sel = PersistentSelection::new();
+/// sel.union_in_place(Interval::closed(0, 10));
+/// history.push(sel.clone());
+/// sel.minus_in_place(Interval::closed(3, 5));
+/// history.push(sel.clone());
+///
+/// assert!(history[0].is_empty());
+/// assert!(history[1].contains(&4));
+/// assert!(!history[2].contains(&4));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PersistentSelection<T>(Tree<T>);
+
+impl<T> PersistentSelection<T> where T: Ord + Clone + Finite {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `PersistentSelection`.
+    pub fn new() -> Self {
+        PersistentSelection(None)
+    }
+
+    /// Constructs a new `PersistentSelection` containing every point.
+    pub fn full() -> Self {
+        PersistentSelection(create(None, (T::MINIMUM, T::MAXIMUM), None))
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `PersistentSelection` contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns `true` if the `PersistentSelection` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        let mut tree = &self.0;
+        while let Some(n) = tree {
+            if *point < n.bounds.0 {
+                tree = &n.left;
+            } else if *point > n.bounds.1 {
+                tree = &n.right;
+            } else {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Returns the greatest lower bound of the `PersistentSelection`, or
+    /// `None` if it is empty.
+    pub fn infimum(&self) -> Option<T> {
+        first(&self.0).map(|b| b.0.clone())
+    }
+
+    /// Returns the least upper bound of the `PersistentSelection`, or `None`
+    /// if it is empty.
+    pub fn supremum(&self) -> Option<T> {
+        last(&self.0).map(|b| b.1.clone())
+    }
+
+    /// Returns `true` if both `PersistentSelection`s share the same
+    /// underlying tree, so that they are known to be equal without comparing
+    /// their intervals.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None)       => true,
+            _                  => false,
+        }
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `PersistentSelection` containing all points not in the
+    /// `PersistentSelection`.
+    pub fn complement(&self) -> Self {
+        IntervalSet::complement(self)
+    }
+
+    /// Returns a `PersistentSelection` containing all points in both
+    /// `PersistentSelection`s.
+    pub fn intersect(&self, other: &Self) -> Self {
+        IntervalSet::intersect(self, other)
+    }
+
+    /// Returns a `PersistentSelection` containing all points in either
+    /// `PersistentSelection`.
+    ///
+    /// The result shares structure with the `PersistentSelection`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.interval_iter());
+        union
+    }
+
+    /// Returns a `PersistentSelection` containing all points in the
+    /// `PersistentSelection` which are not in the given
+    /// `PersistentSelection`.
+    ///
+    /// The result shares structure with the `PersistentSelection`.
+    pub fn minus(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for interval in other.interval_iter() {
+            difference.minus_in_place(interval);
+        }
+        difference
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the `PersistentSelection` to only those points contained in
+    /// the given `Interval`.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        let (l, r) = match interval.closed_bounds() {
+            Some(bounds) => bounds,
+            None         => { self.0 = None; return; },
+        };
+        let (_, rest) = split(&self.0, &|b: &(T, T)| b.1 < l);
+        let (mid, _) = split(&rest, &|b: &(T, T)| b.0 <= r);
+
+        self.0 = match pop_min(&mid) {
+            None => None,
+            Some(((fl, fr), rest)) => match pop_max(&rest) {
+                None => create(None, (fl.max(l), fr.min(r)), None),
+                Some(((ll, lr), inner)) => {
+                    let inner = add_min((fl.max(l), fr), &inner);
+                    add_max((ll, lr.min(r)), &inner)
+                },
+            },
+        };
+    }
+
+    /// Adds all of the points in the given `Interval` to the
+    /// `PersistentSelection`.
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        let (l, r) = match interval.closed_bounds() {
+            Some(bounds) => bounds,
+            None         => return,
+        };
+        // Split off the intervals which neither overlap nor are adjacent.
+        let (before, rest) = split(&self.0,
+            &|b: &(T, T)| b.1.succ().is_some_and(|s| s < l));
+        let (mid, after) = split(&rest,
+            &|b: &(T, T)| r.succ().map_or(true, |s| b.0 <= s));
+
+        let lower = first(&mid).map_or(l.clone(), |b| b.0.clone().min(l));
+        let upper = last(&mid).map_or(r.clone(), |b| b.1.clone().max(r));
+        self.0 = join(before, (lower, upper), after);
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `PersistentSelection`.
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        let (l, r) = match interval.closed_bounds() {
+            Some(bounds) => bounds,
+            None         => return,
+        };
+        let (mut before, rest) = split(&self.0, &|b: &(T, T)| b.1 < l);
+        let (mid, mut after) = split(&rest, &|b: &(T, T)| b.0 <= r);
+
+        if let Some((ml, _)) = first(&mid) {
+            if *ml < l {
+                let end = l.pred().expect("predecessor of lower bound");
+                before = add_max((ml.clone(), end), &before);
+            }
+        }
+        if let Some((_, mr)) = last(&mid) {
+            if *mr > r {
+                let start = r.succ().expect("successor of upper bound");
+                after = add_min((start, mr.clone()), &after);
+            }
+        }
+        self.0 = concat(before, &after);
+    }
+
+    // Iterators
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the `Interval`s of the `PersistentSelection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        let mut iter = IntervalIter { stack: Vec::new() };
+        iter.push_left(&self.0);
+        iter
+    }
+
+    /// Returns an iterator over each of the points in the
+    /// `PersistentSelection`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            intervals: self.interval_iter(),
+            current: Interval::empty().iter(),
+        }
+    }
+}
+
+impl<T> Default for PersistentSelection<T> where T: Ord + Clone + Finite {
+    fn default() -> Self {
+        PersistentSelection::new()
+    }
+}
+
+impl<T> PartialEq for PersistentSelection<T> where T: Ord + Clone + Finite {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || self.interval_iter()
+            .map(|i| (i.infimum(), i.supremum()))
+            .eq(other.interval_iter().map(|i| (i.infimum(), i.supremum())))
+    }
+}
+
+impl<T> Eq for PersistentSelection<T> where T: Ord + Clone + Finite {}
+
+impl<T> Extend<Interval<T>> for PersistentSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        for interval in iter.into_iter() {
+            self.union_in_place(interval);
+        }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for PersistentSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        let mut sel = PersistentSelection::new();
+        sel.extend(iter);
+        sel
+    }
+}
+
+impl<T> From<Interval<T>> for PersistentSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(interval: Interval<T>) -> Self {
+        PersistentSelection(interval.closed_bounds()
+            .and_then(|bounds| create(None, bounds, None)))
+    }
+}
+
+impl<T> From<Selection<T>> for PersistentSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(selection: Selection<T>) -> Self {
+        let bounds: Vec<(T, T)> = selection
+            .interval_iter()
+            .filter_map(|i| i.closed_bounds())
+            .collect();
+        PersistentSelection(build(&bounds))
+    }
+}
+
+impl<T> From<PersistentSelection<T>> for Selection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(persistent: PersistentSelection<T>) -> Self {
+        persistent.interval_iter().collect()
+    }
+}
+
+impl<T> IntervalSet<T> for PersistentSelection<T>
+    where T: Ord + Clone + Finite,
+{
+    type Output = PersistentSelection<T>;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        PersistentSelection::interval_iter(self)
+    }
+
+    fn infimum(&self) -> Option<T> {
+        PersistentSelection::infimum(self)
+    }
+
+    fn supremum(&self) -> Option<T> {
+        PersistentSelection::supremum(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        PersistentSelection::is_empty(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        PersistentSelection::contains(self, point)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `PersistentSelection`.
+#[derive(Debug)]
+pub struct IntervalIter<'t, T> {
+    /// The nodes whose intervals and right subtrees have yet to be visited.
+    stack: Vec<&'t Node<T>>,
+}
+
+impl<'t, T> IntervalIter<'t, T> {
+    /// Pushes the given tree's leftmost path onto the stack.
+    fn push_left(&mut self, mut tree: &'t Tree<T>) {
+        while let Some(n) = tree {
+            self.stack.push(n);
+            tree = &n.left;
+        }
+    }
+}
+
+impl<'t, T> Iterator for IntervalIter<'t, T> where T: Ord + Clone + Finite {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.stack.pop()?;
+        self.push_left(&n.right);
+        Some(Interval::closed(n.bounds.0.clone(), n.bounds.1.clone()))
+    }
+}
+
+impl<'t, T> FusedIterator for IntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points of a `PersistentSelection`.
+#[derive(Debug)]
+pub struct Iter<'t, T> where T: Ord + Clone + Finite {
+    intervals: IntervalIter<'t, T>,
+    current: crate::interval::Iter<T>,
+}
+
+impl<'t, T> Iterator for Iter<'t, T> where T: Ord + Clone + Finite {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(next) = self.current.next() {
+            return Some(next);
+        }
+        self.current = self.intervals.next()?.iter();
+        self.current.next()
+    }
+}
+
+impl<'t, T> FusedIterator for Iter<'t, T> where T: Ord + Clone + Finite {}
@@ -438,8 +438,8 @@ impl<T> std::fmt::Display for RawInterval<T> where T: std::fmt::Display {
             Closed(ref l, ref r)    => write!(f, "[{}, {}]", l, r),
             UpTo(ref p)             => write!(f, "(-∞, {})", p),
             UpFrom(ref p)           => write!(f, "({}, ∞)", p),
-            To(ref p)               => write!(f, "(-∞, {})", p),
-            From(ref p)             => write!(f, "({}, ∞)", p),
+            To(ref p)               => write!(f, "(-∞, {}]", p),
+            From(ref p)             => write!(f, "[{}, ∞)", p),
             Full                    => write!(f, "(-∞, ∞)"),
         }
     }
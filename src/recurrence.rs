@@ -0,0 +1,606 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides recurrence rules for generating repeating intervals.
+//!
+//! A [`Recurrence`] is modelled after the iCalendar `RRULE`, and produces a
+//! sequence of [`CalendarDate`] occurrences. Each occurrence may be mapped to
+//! an [`Interval`] window and collected into a [`Selection`].
+//!
+//! [`Recurrence`]: struct.Recurrence.html
+//! [`CalendarDate`]: ../calendar/trait.CalendarDate.html
+//! [`Interval`]: ../interval/struct.Interval.html
+//! [`Selection`]: ../selection/struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::calendar::CalendarDate;
+use crate::calendar::Weekday;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Frequency
+////////////////////////////////////////////////////////////////////////////////
+/// The period over which a [`Recurrence`] repeats.
+///
+/// [`Recurrence`]: struct.Recurrence.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Frequency {
+    /// Repeats every day.
+    Daily,
+    /// Repeats every week, starting on Monday.
+    Weekly,
+    /// Repeats every calendar month.
+    Monthly,
+    /// Repeats every calendar year.
+    Yearly,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ByDay
+////////////////////////////////////////////////////////////////////////////////
+/// A weekday rule restricting the occurrences of a [`Recurrence`].
+///
+/// [`Recurrence`]: struct.Recurrence.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ByDay {
+    /// Matches every instance of the weekday within the period.
+    Every(Weekday),
+    /// Matches the nth instance of the weekday within a monthly or yearly
+    /// period. Negative values count backward from the end of the period, so
+    /// `Nth(-1, Friday)` is the last Friday. The ordinal must be non-zero, and
+    /// at most 5 for monthly periods or 53 for yearly periods. For daily and
+    /// weekly periods, the ordinal is otherwise ignored.
+    Nth(i8, Weekday),
+}
+
+impl ByDay {
+    /// Returns the weekday matched by the rule.
+    pub fn weekday(&self) -> Weekday {
+        match *self {
+            ByDay::Every(weekday)  => weekday,
+            ByDay::Nth(_, weekday) => weekday,
+        }
+    }
+
+    /// Returns an error if the rule can never match within a period of the
+    /// given frequency.
+    fn validate(&self, frequency: Frequency) -> Result<(), ByDayError> {
+        let n = match *self {
+            ByDay::Every(_)  => return Ok(()),
+            ByDay::Nth(n, _) => n.unsigned_abs(),
+        };
+        let max = match frequency {
+            Frequency::Monthly => 5,
+            Frequency::Yearly  => 53,
+            _                  => u8::MAX,
+        };
+        if n == 0 {
+            Err(ByDayError::ZeroOrdinal)
+        } else if n > max {
+            Err(ByDayError::OrdinalOutOfRange)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns `true` if the rule matches a day with the given weekday, which
+    /// is the `forward`th instance of that weekday from the start of its
+    /// period and the `backward`th from the end.
+    fn matches(&self, weekday: Weekday, forward: usize, backward: usize)
+        -> bool
+    {
+        match *self {
+            ByDay::Every(w)  => w == weekday,
+            ByDay::Nth(n, w) => {
+                let ordinal = if n > 0 { forward } else { backward };
+                w == weekday && (n as i64).unsigned_abs() == ordinal as u64
+            },
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ByDayError
+////////////////////////////////////////////////////////////////////////////////
+/// The error returned by [`Recurrence::with_by_day`] for a weekday rule which
+/// can never match.
+///
+/// [`Recurrence::with_by_day`]: struct.Recurrence.html#method.with_by_day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByDayError {
+    /// The ordinal of an `Nth` rule is zero.
+    ZeroOrdinal,
+    /// The ordinal of an `Nth` rule exceeds the number of instances of a
+    /// weekday in a period.
+    OrdinalOutOfRange,
+}
+
+impl std::fmt::Display for ByDayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ByDayError::ZeroOrdinal =>
+                write!(f, "weekday ordinal is zero"),
+            ByDayError::OrdinalOutOfRange =>
+                write!(f, "weekday ordinal exceeds the recurrence period"),
+        }
+    }
+}
+
+impl std::error::Error for ByDayError {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Recurrence
+////////////////////////////////////////////////////////////////////////////////
+/// A rule generating a sequence of recurring dates, in the spirit of the
+/// iCalendar `RRULE`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::calendar::Weekday;
+/// # use normalize_interval::recurrence::ByDay;
+/// # use normalize_interval::recurrence::Frequency;
+/// # use normalize_interval::recurrence::Recurrence;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # #[cfg(feature = "chrono")] {
+/// # use chrono::NaiveDate;
+/// # //-------------------------------------------------------------------
+/// // The first Monday of each month.
+/// let start = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+/// let rule = Recurrence::new(Frequency::Monthly, start)
+///     .with_by_day(vec![ByDay::Nth(1, Weekday::Monday)])?
+///     .with_count(3);
+///
+/// assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+///     NaiveDate::from_ymd_opt(2021, 1, 4).unwrap(),
+///     NaiveDate::from_ymd_opt(2021, 2, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2021, 3, 1).unwrap(),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// # }
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Recurrence<D> where D: CalendarDate {
+    /// The first date of the recurrence. No earlier dates are produced.
+    start: D,
+    /// The period over which the recurrence repeats.
+    frequency: Frequency,
+    /// The number of periods between each repetition.
+    interval: u32,
+    /// The weekday rules restricting occurrences within a period.
+    by_day: Vec<ByDay>,
+    /// The maximum number of occurrences.
+    count: Option<usize>,
+    /// The last date which may be produced.
+    until: Option<D>,
+}
+
+impl<D> Recurrence<D> where D: CalendarDate {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `Recurrence` with the given frequency, starting from
+    /// the given date.
+    ///
+    /// Without further restriction, each occurrence falls on the same
+    /// weekday, day of the month, or day of the year as the start date,
+    /// according to the frequency. Periods which contain no such day (such as
+    /// the 31st of a short month) are skipped.
+    pub fn new(frequency: Frequency, start: D) -> Self {
+        Recurrence {
+            start,
+            frequency,
+            interval: 1,
+            by_day: Vec::new(),
+            count: None,
+            until: None,
+        }
+    }
+
+    /// Sets the number of periods between each repetition. An interval of zero
+    /// is treated as one.
+    pub fn with_interval(mut self, interval: u32) -> Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    /// Restricts occurrences to the days matching any of the given weekday
+    /// rules within each period.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ByDayError`] if any `Nth` rule has an ordinal which can
+    /// never match within a period of the recurrence's frequency.
+    ///
+    /// [`ByDayError`]: enum.ByDayError.html
+    pub fn with_by_day<I>(mut self, by_day: I) -> Result<Self, ByDayError>
+        where I: IntoIterator<Item=ByDay>
+    {
+        let by_day: Vec<ByDay> = by_day.into_iter().collect();
+        for rule in &by_day {
+            rule.validate(self.frequency)?;
+        }
+        self.by_day = by_day;
+        Ok(self)
+    }
+
+    /// Limits the recurrence to the given number of occurrences.
+    pub fn with_count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Limits the recurrence to occurrences on or before the given date.
+    pub fn with_until(mut self, until: D) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    // Accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the start date of the recurrence.
+    pub fn start(&self) -> &D {
+        &self.start
+    }
+
+    /// Returns the frequency of the recurrence.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    // Expansion
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the dates of the recurrence.
+    ///
+    /// The iterator is unbounded if neither a count nor an until date is
+    /// given, ending only when the range of the date type is exhausted.
+    pub fn occurrences(&self) -> Occurrences<'_, D> {
+        Occurrences {
+            recurrence: self,
+            anchor: Some(self.first_anchor()),
+            pending: VecDeque::new(),
+            produced: 0,
+        }
+    }
+
+    /// Returns an iterator over the `Interval`s produced by applying the given
+    /// window function to each date of the recurrence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::recurrence::Frequency;
+    /// # use normalize_interval::recurrence::Recurrence;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "chrono")] {
+    /// # use chrono::NaiveDate;
+    /// # //-------------------------------------------------------------------
+    /// let start = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+    /// let rule = Recurrence::new(Frequency::Weekly, start).with_count(2);
+    ///
+    /// // A three day window starting on each occurrence.
+    /// let mut windows = rule.windows(|date| {
+    ///     Interval::closed(date, date + chrono::Days::new(2))
+    /// });
+    ///
+    /// assert_eq!(windows.next(), Some(Interval::closed(
+    ///     NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2021, 1, 3).unwrap())));
+    /// assert_eq!(windows.next(), Some(Interval::closed(
+    ///     NaiveDate::from_ymd_opt(2021, 1, 8).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2021, 1, 10).unwrap())));
+    /// assert_eq!(windows.next(), None);
+    /// # //-------------------------------------------------------------------
+    /// # }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn windows<'r, T, F>(&'r self, window: F)
+        -> impl Iterator<Item=Interval<T>> + 'r
+        where
+            T: Ord + Clone + Finite + 'r,
+            F: Fn(D) -> Interval<T> + 'r,
+    {
+        self.occurrences().map(window)
+    }
+
+    /// Returns a `Selection` containing the windows of the recurrence, clipped
+    /// to the given bounding `Interval`.
+    ///
+    /// The windows are assumed to be ordered by their lower bounds, so that
+    /// expansion stops at the first period whose first date has a window
+    /// lying entirely after the bounding `Interval`. If the bounding
+    /// `Interval` has no upper bound, the recurrence should be limited by a
+    /// count or until date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::calendar::Weekday;
+    /// # use normalize_interval::recurrence::ByDay;
+    /// # use normalize_interval::recurrence::Frequency;
+    /// # use normalize_interval::recurrence::Recurrence;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # #[cfg(feature = "chrono")] {
+    /// # use chrono::NaiveDate;
+    /// # use normalize_interval::datetime::chrono::SecondTimestamp;
+    /// # //-------------------------------------------------------------------
+    /// // Every Tuesday from 02:00 up to 04:00.
+    /// let start = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+    /// let rule = Recurrence::new(Frequency::Weekly, start)
+    ///     .with_by_day(vec![ByDay::Every(Weekday::Tuesday)])?;
+    /// let at = |date: NaiveDate, hour| {
+    ///     SecondTimestamp::new(date.and_hms_opt(hour, 0, 0).unwrap())
+    /// };
+    ///
+    /// let january = Interval::<NaiveDate>::month(2021, 1).unwrap();
+    /// let bounds = Interval::right_open(
+    ///     at(january.infimum().unwrap(), 0),
+    ///     at(january.supremum().unwrap(), 0));
+    ///
+    /// let windows = rule.expand(bounds, |date| {
+    ///     Interval::right_open(at(date, 2), at(date, 4))
+    /// });
+    ///
+    /// assert_eq!(windows.interval_iter().count(), 4);
+    /// # //-------------------------------------------------------------------
+    /// # }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn expand<T, F>(&self, bounds: Interval<T>, window: F) -> Selection<T>
+        where
+            T: Ord + Clone + Finite,
+            F: Fn(D) -> Interval<T>,
+    {
+        let mut selection = Selection::new();
+        if bounds.is_empty() { return selection; }
+
+        let limit = bounds.supremum();
+        let past_limit = |date: &D| match (&limit, window(date.clone())
+            .infimum())
+        {
+            (Some(limit), Some(lower)) => lower > *limit,
+            _                          => false,
+        };
+
+        let mut occurrences = self.occurrences();
+        while let Some(date) = occurrences.next_before(past_limit) {
+            if past_limit(&date) { break; }
+            selection.union_in_place(window(date).intersect(&bounds));
+        }
+        selection
+    }
+
+    // Period calculations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the first date of the period containing the start date.
+    fn first_anchor(&self) -> D {
+        let (year, month, _) = self.start.year_month_day();
+        match self.frequency {
+            Frequency::Daily   => self.start.clone(),
+            Frequency::Weekly  => {
+                let mut monday = self.start.clone();
+                for _ in 0..self.start.weekday().days_from_monday() {
+                    monday = match monday.pred() {
+                        Some(date) => date,
+                        None       => break,
+                    };
+                }
+                monday
+            },
+            Frequency::Monthly => D::from_year_month_day(year, month, 1)
+                .unwrap_or_else(|| self.start.clone()),
+            Frequency::Yearly  => D::from_year_month_day(year, 1, 1)
+                .unwrap_or_else(|| self.start.clone()),
+        }
+    }
+
+    /// Returns the first date of the period following the one beginning with
+    /// the given anchor, or `None` if it is out of range.
+    fn next_anchor(&self, anchor: &D) -> Option<D> {
+        let (year, month, _) = anchor.year_month_day();
+        let interval = self.interval;
+        match self.frequency {
+            Frequency::Daily   => step_days(anchor, interval as u64),
+            Frequency::Weekly  => step_days(anchor, interval as u64 * 7),
+            Frequency::Monthly => {
+                let months = year as i64 * 12 + (month as i64 - 1)
+                    + interval as i64;
+                let year = i32::try_from(months.div_euclid(12)).ok()?;
+                let month = months.rem_euclid(12) as u32 + 1;
+                D::from_year_month_day(year, month, 1)
+            },
+            Frequency::Yearly  => {
+                let year = i32::try_from(year as i64 + interval as i64).ok()?;
+                D::from_year_month_day(year, 1, 1)
+            },
+        }
+    }
+
+    /// Returns the candidate dates in the period beginning with the given
+    /// anchor, in ascending order.
+    fn candidates(&self, anchor: &D) -> Vec<D> {
+        let (year, month, _) = anchor.year_month_day();
+        let (_, start_month, start_day) = self.start.year_month_day();
+        let start_weekday = self.start.weekday();
+
+        match self.frequency {
+            Frequency::Daily => {
+                let weekday = anchor.weekday();
+                if self.by_day.is_empty()
+                    || self.by_day.iter().any(|b| b.weekday() == weekday)
+                {
+                    vec![anchor.clone()]
+                } else {
+                    Vec::new()
+                }
+            },
+            Frequency::Weekly => period_days(anchor, 7, |_| true)
+                .into_iter()
+                .filter(|date| {
+                    let weekday = date.weekday();
+                    if self.by_day.is_empty() {
+                        weekday == start_weekday
+                    } else {
+                        self.by_day.iter().any(|b| b.weekday() == weekday)
+                    }
+                })
+                .collect(),
+            Frequency::Monthly if self.by_day.is_empty() => {
+                D::from_year_month_day(year, month, start_day)
+                    .into_iter()
+                    .collect()
+            },
+            Frequency::Yearly if self.by_day.is_empty() => {
+                D::from_year_month_day(year, start_month, start_day)
+                    .into_iter()
+                    .collect()
+            },
+            Frequency::Monthly => self.filter_by_day(period_days(anchor, 31,
+                |date| date.year_month_day().1 == month)),
+            Frequency::Yearly => self.filter_by_day(period_days(anchor, 366,
+                |date| date.year_month_day().0 == year)),
+        }
+    }
+
+    /// Returns the days of a monthly or yearly period which match any of the
+    /// weekday rules.
+    fn filter_by_day(&self, days: Vec<D>) -> Vec<D> {
+        let len = days.len();
+        days.into_iter()
+            .enumerate()
+            .filter(|(i, date)| {
+                let weekday = date.weekday();
+                let forward = i / 7 + 1;
+                let backward = (len - 1 - i) / 7 + 1;
+                self.by_day.iter()
+                    .any(|b| b.matches(weekday, forward, backward))
+            })
+            .map(|(_, date)| date)
+            .collect()
+    }
+}
+
+
+/// Returns the date the given number of days after the given date, or `None`
+/// if it is out of range.
+fn step_days<D>(date: &D, days: u64) -> Option<D> where D: CalendarDate {
+    let mut date = date.clone();
+    for _ in 0..days {
+        date = date.succ()?;
+    }
+    Some(date)
+}
+
+/// Returns up to `limit` consecutive dates starting from the given date for
+/// which the given predicate holds.
+fn period_days<D, P>(start: &D, limit: usize, in_period: P) -> Vec<D>
+    where
+        D: CalendarDate,
+        P: Fn(&D) -> bool,
+{
+    let mut days = Vec::new();
+    let mut next = Some(start.clone());
+    while let Some(date) = next {
+        if days.len() >= limit || !in_period(&date) { break; }
+        next = date.succ();
+        days.push(date);
+    }
+    days
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Occurrences
+////////////////////////////////////////////////////////////////////////////////
+/// An iterator over the dates of a [`Recurrence`].
+///
+/// [`Recurrence`]: struct.Recurrence.html
+#[derive(Debug, Clone)]
+pub struct Occurrences<'r, D> where D: CalendarDate {
+    /// The recurrence being expanded.
+    recurrence: &'r Recurrence<D>,
+    /// The first date of the next period to expand.
+    anchor: Option<D>,
+    /// The dates of the current period which have not yet been produced.
+    pending: VecDeque<D>,
+    /// The number of dates produced so far.
+    produced: usize,
+}
+
+impl<'r, D> Occurrences<'r, D> where D: CalendarDate {
+    /// Returns the next date of the recurrence, or `None` if the recurrence is
+    /// exhausted or the next date lies in a period whose first date is
+    /// rejected by the given predicate.
+    ///
+    /// Checking each period, rather than each date, ensures that a rule which
+    /// rarely matches stops once its periods pass a limit.
+    fn next_before<P>(&mut self, past_limit: P) -> Option<D>
+        where P: Fn(&D) -> bool
+    {
+        let recurrence = self.recurrence;
+        if recurrence.count.is_some_and(|count| self.produced >= count) {
+            return None;
+        }
+
+        while self.pending.is_empty() {
+            let anchor = self.anchor.take()?;
+            if recurrence.until.as_ref().is_some_and(|until| anchor > *until)
+                || past_limit(&anchor)
+            {
+                return None;
+            }
+            self.pending = recurrence.candidates(&anchor)
+                .into_iter()
+                .filter(|date| *date >= recurrence.start)
+                .collect();
+            self.anchor = recurrence.next_anchor(&anchor);
+        }
+
+        let date = self.pending.pop_front()?;
+        if recurrence.until.as_ref().is_some_and(|until| date > *until) {
+            self.pending.clear();
+            self.anchor = None;
+            return None;
+        }
+        self.produced += 1;
+        Some(date)
+    }
+}
+
+impl<'r, D> Iterator for Occurrences<'r, D> where D: CalendarDate {
+    type Item = D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_before(|_| false)
+    }
+}
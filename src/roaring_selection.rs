@@ -0,0 +1,1087 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a compressed selection for integer points, in the style of Roaring
+//! bitmaps.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::normalize::Finite;
+use crate::normalize::FiniteIndex;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::iter::Peekable;
+use std::marker::PhantomData;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constants
+////////////////////////////////////////////////////////////////////////////////
+/// The number of low bits of a point stored within a chunk's container.
+const CHUNK_BITS: u32 = 16;
+
+/// The number of words in a bitmap container.
+const BITMAP_WORDS: usize = 1 << (CHUNK_BITS - 6);
+
+/// The size in bytes of a bitmap container.
+const BITMAP_BYTES: usize = BITMAP_WORDS * 8;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Container
+////////////////////////////////////////////////////////////////////////////////
+/// The points of a single chunk, stored in whichever form is smallest.
+#[derive(Debug, Clone)]
+enum Container {
+    /// A sorted array of points, for sparse chunks.
+    Array(Vec<u16>),
+    /// A bitmap with one bit per point, for dense chunks.
+    Bitmap(Box<[u64; BITMAP_WORDS]>),
+    /// A sorted list of inclusive runs, for chunks with long runs.
+    Run(Vec<(u16, u16)>),
+}
+
+impl Container {
+    /// Returns the smallest container holding the given sorted, disjoint, and
+    /// non-adjacent runs, or `None` if there are no runs.
+    fn from_runs(runs: Vec<(u16, u16)>) -> Option<Self> {
+        if runs.is_empty() { return None; }
+        let count: usize = runs.iter()
+            .map(|(l, h)| usize::from(h - l) + 1)
+            .sum();
+        let run_bytes = 4 * runs.len();
+        let array_bytes = 2 * count;
+
+        Some(if run_bytes <= array_bytes.min(BITMAP_BYTES) {
+            Container::Run(runs)
+        } else if array_bytes <= BITMAP_BYTES {
+            Container::Array(runs.iter().flat_map(|(l, h)| *l..=*h).collect())
+        } else {
+            let mut words = Box::new([0u64; BITMAP_WORDS]);
+            for (l, h) in runs {
+                for p in l..=h {
+                    words[usize::from(p) / 64] |= 1 << (p % 64);
+                }
+            }
+            Container::Bitmap(words)
+        })
+    }
+
+    /// Returns `true` if the container contains the given point.
+    fn contains(&self, low: u16) -> bool {
+        match self {
+            Container::Array(points) => points.binary_search(&low).is_ok(),
+            Container::Bitmap(words) =>
+                words[usize::from(low) / 64] & (1 << (low % 64)) != 0,
+            Container::Run(runs) => runs_contain(runs, low),
+        }
+    }
+
+    /// Returns the number of points in the container.
+    fn cardinality(&self) -> u64 {
+        match self {
+            Container::Array(points) => points.len() as u64,
+            Container::Bitmap(words) => words.iter()
+                .map(|w| u64::from(w.count_ones()))
+                .sum(),
+            Container::Run(runs) => runs.iter()
+                .map(|(l, h)| u64::from(h - l) + 1)
+                .sum(),
+        }
+    }
+
+    /// Returns the runs of the container.
+    fn runs(&self) -> Vec<(u16, u16)> {
+        match self {
+            Container::Array(points) => {
+                let mut runs: Vec<(u16, u16)> = Vec::new();
+                for p in points {
+                    match runs.last_mut() {
+                        Some(last) if u32::from(last.1) + 1 == u32::from(*p)
+                            => last.1 = *p,
+                        _   => runs.push((*p, *p)),
+                    }
+                }
+                runs
+            },
+            Container::Bitmap(words) => bitmap_runs(words),
+            Container::Run(runs) => runs.clone(),
+        }
+    }
+
+    /// Returns the container of points in either container.
+    fn union(&self, other: &Self) -> Option<Self> {
+        if let (Container::Bitmap(a), Container::Bitmap(b)) = (self, other) {
+            return bitmap_zip(a, b, |x, y| x | y);
+        }
+        Container::from_runs(union_runs(&self.runs(), &other.runs()))
+    }
+
+    /// Returns the container of points in both containers.
+    fn intersect(&self, other: &Self) -> Option<Self> {
+        if let (Container::Bitmap(a), Container::Bitmap(b)) = (self, other) {
+            return bitmap_zip(a, b, |x, y| x & y);
+        }
+        Container::from_runs(intersect_runs(&self.runs(), &other.runs()))
+    }
+
+    /// Returns the container of points in the container but not the other.
+    fn minus(&self, other: &Self) -> Option<Self> {
+        if let (Container::Bitmap(a), Container::Bitmap(b)) = (self, other) {
+            return bitmap_zip(a, b, |x, y| x & !y);
+        }
+        let rest = complement_runs(&other.runs());
+        Container::from_runs(intersect_runs(&self.runs(), &rest))
+    }
+
+    /// Returns the container of points in the chunk but not the container.
+    fn complement(&self) -> Option<Self> {
+        Container::from_runs(complement_runs(&self.runs()))
+    }
+
+    /// Returns `true` if the container contains no points.
+    fn is_empty(&self) -> bool {
+        match self {
+            Container::Array(points) => points.is_empty(),
+            Container::Bitmap(words) => words.iter().all(|w| *w == 0),
+            Container::Run(runs)     => runs.is_empty(),
+        }
+    }
+
+    /// Returns `true` if the container contains every point of the chunk.
+    fn is_full(&self) -> bool {
+        match self {
+            Container::Array(_)      => false,
+            Container::Bitmap(words) => words.iter().all(|w| *w == !0),
+            Container::Run(runs)     => runs[..] == [(0, u16::MAX)],
+        }
+    }
+
+    /// Adds the points of the given run to the container.
+    fn insert_run(&mut self, l: u16, h: u16) {
+        let oversized = match self {
+            Container::Array(points) => {
+                let start = points.partition_point(|p| *p < l);
+                let end = points.partition_point(|p| *p <= h);
+                let _ = points.splice(start..end, l..=h);
+                2 * points.len() > BITMAP_BYTES
+            },
+            Container::Bitmap(words) => {
+                bitmap_fill(words, l, h, true);
+                false
+            },
+            Container::Run(runs) => {
+                insert_run(runs, l, h);
+                4 * runs.len() > BITMAP_BYTES
+            },
+        };
+        if oversized { self.compact(); }
+    }
+
+    /// Removes the points of the given run from the container.
+    fn remove_run(&mut self, l: u16, h: u16) {
+        let oversized = match self {
+            Container::Array(points) => {
+                let start = points.partition_point(|p| *p < l);
+                let end = points.partition_point(|p| *p <= h);
+                let _ = points.drain(start..end);
+                false
+            },
+            Container::Bitmap(words) => {
+                bitmap_fill(words, l, h, false);
+                let count: usize = words.iter()
+                    .map(|w| w.count_ones() as usize)
+                    .sum();
+                2 * count <= BITMAP_BYTES
+            },
+            Container::Run(runs) => {
+                remove_run(runs, l, h);
+                4 * runs.len() > BITMAP_BYTES
+            },
+        };
+        if oversized { self.compact(); }
+    }
+
+    /// Replaces the container with the smallest container holding its points.
+    fn compact(&mut self) {
+        if let Some(container) = Container::from_runs(self.runs()) {
+            *self = container;
+        }
+    }
+}
+
+/// Combines two bitmaps word by word.
+fn bitmap_zip<F>(a: &[u64; BITMAP_WORDS], b: &[u64; BITMAP_WORDS], f: F)
+    -> Option<Container>
+    where F: Fn(u64, u64) -> u64
+{
+    let mut words = [0u64; BITMAP_WORDS];
+    for (w, (x, y)) in words.iter_mut().zip(a.iter().zip(b.iter())) {
+        *w = f(*x, *y);
+    }
+    Container::from_runs(bitmap_runs(&words))
+}
+
+/// Sets or clears every bit of a bitmap in the given inclusive range.
+fn bitmap_fill(words: &mut [u64; BITMAP_WORDS], l: u16, h: u16, value: bool) {
+    let (first, last) = (usize::from(l) / 64, usize::from(h) / 64);
+    for (i, w) in words[first..=last].iter_mut().enumerate() {
+        let i = i + first;
+        let mut mask = !0u64;
+        if i == first { mask &= !0 << (l % 64); }
+        if i == last { mask &= !0 >> (63 - h % 64); }
+        if value { *w |= mask; } else { *w &= !mask; }
+    }
+}
+
+/// Returns the runs of set bits in a bitmap.
+fn bitmap_runs(words: &[u64; BITMAP_WORDS]) -> Vec<(u16, u16)> {
+    let mut runs = Vec::new();
+    let mut start: Option<u16> = None;
+    for (i, w) in words.iter().enumerate() {
+        let base = (i * 64) as u16;
+        if *w == 0 {
+            if let Some(s) = start.take() { runs.push((s, base - 1)); }
+            continue;
+        }
+        if *w == !0 {
+            if start.is_none() { start = Some(base); }
+            continue;
+        }
+        for b in 0..64 {
+            let p = base + b;
+            if w & (1 << b) != 0 {
+                if start.is_none() { start = Some(p); }
+            } else if let Some(s) = start.take() {
+                runs.push((s, p - 1));
+            }
+        }
+    }
+    if let Some(s) = start { runs.push((s, u16::MAX)); }
+    runs
+}
+
+/// Appends a run, merging it with the last run if they overlap or are
+/// adjacent.
+fn push_run<K>(runs: &mut Vec<(K, K)>, l: K, h: K)
+    where K: Finite + Ord + Copy
+{
+    match runs.last_mut() {
+        Some(last) if last.1.succ().map_or(true, |next| l <= next) => {
+            last.1 = last.1.max(h);
+        },
+        _ => runs.push((l, h)),
+    }
+}
+
+/// Adds a run to sorted, disjoint, and non-adjacent runs, merging it with any
+/// runs it overlaps or adjoins.
+fn insert_run<K>(runs: &mut Vec<(K, K)>, l: K, h: K)
+    where K: Finite + Ord + Copy
+{
+    // Runs ending before the point preceding `l`, or starting after the point
+    // following `h`, are kept.
+    let start = runs
+        .partition_point(|(_, rh)| rh.succ().is_some_and(|n| n < l));
+    let end = runs
+        .partition_point(|(rl, _)| h.succ().map_or(true, |n| *rl <= n));
+    let (mut l, mut h) = (l, h);
+    if start < end {
+        l = l.min(runs[start].0);
+        h = h.max(runs[end - 1].1);
+    }
+    let _ = runs.splice(start..end, std::iter::once((l, h)));
+}
+
+/// Removes a run from sorted, disjoint, and non-adjacent runs, splitting any
+/// run it lies within.
+fn remove_run<K>(runs: &mut Vec<(K, K)>, l: K, h: K)
+    where K: Finite + Ord + Copy
+{
+    let start = runs.partition_point(|(_, rh)| *rh < l);
+    let end = runs.partition_point(|(rl, _)| *rl <= h);
+    if start >= end { return; }
+
+    let (first, last) = (runs[start], runs[end - 1]);
+    let mut rest = Vec::with_capacity(2);
+    if first.0 < l {
+        rest.push((first.0, l.pred().expect("run precedes removed run")));
+    }
+    if last.1 > h {
+        rest.push((h.succ().expect("run follows removed run"), last.1));
+    }
+    let _ = runs.splice(start..end, rest);
+}
+
+/// Returns `true` if any of the given runs contains the point.
+fn runs_contain<K>(runs: &[(K, K)], point: K) -> bool where K: Ord + Copy {
+    let idx = runs.partition_point(|(l, _)| *l <= point);
+    idx > 0 && runs[idx - 1].1 >= point
+}
+
+/// Returns the runs covering either list of runs.
+fn union_runs<K>(a: &[(K, K)], b: &[(K, K)]) -> Vec<(K, K)>
+    where K: Finite + Ord + Copy
+{
+    let mut runs = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        let (l, h) = if j >= b.len() || (i < a.len() && a[i].0 <= b[j].0) {
+            i += 1;
+            a[i - 1]
+        } else {
+            j += 1;
+            b[j - 1]
+        };
+        push_run(&mut runs, l, h);
+    }
+    runs
+}
+
+/// Returns the runs covered by both lists of runs.
+fn intersect_runs<K>(a: &[(K, K)], b: &[(K, K)]) -> Vec<(K, K)>
+    where K: Ord + Copy
+{
+    let mut runs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let l = a[i].0.max(b[j].0);
+        let h = a[i].1.min(b[j].1);
+        if l <= h { runs.push((l, h)); }
+        if a[i].1 <= b[j].1 { i += 1; } else { j += 1; }
+    }
+    runs
+}
+
+/// Returns the runs of the domain not covered by the given runs.
+fn complement_runs<K>(a: &[(K, K)]) -> Vec<(K, K)>
+    where K: Finite + Ord + Copy
+{
+    let mut runs = Vec::with_capacity(a.len() + 1);
+    let mut next = Some(K::MINIMUM);
+    for (l, h) in a {
+        if let Some(start) = next {
+            if start < *l {
+                runs.push((start, l.pred().expect("run follows start")));
+            }
+        }
+        next = h.succ();
+    }
+    if let Some(start) = next { runs.push((start, K::MAXIMUM)); }
+    runs
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Chunk maps
+////////////////////////////////////////////////////////////////////////////////
+/// The chunks of a selection. Runs of chunks containing every point are stored
+/// as runs of their keys, so long intervals need not store every chunk they
+/// cross.
+#[derive(Debug, Clone, Default)]
+struct Chunks {
+    /// Sorted, disjoint, and non-adjacent inclusive runs of the keys of full
+    /// chunks.
+    full: Vec<(u64, u64)>,
+    /// A map from the high bits of a point to the container of its low bits,
+    /// for chunks not covered by `full`.
+    partial: BTreeMap<u64, Container>,
+}
+
+impl Chunks {
+    /// Returns `true` if the chunk map contains the given point index.
+    fn contains(&self, index: u64) -> bool {
+        let key = index >> CHUNK_BITS;
+        runs_contain(&self.full, key) || self.partial
+            .get(&key)
+            .is_some_and(|c| c.contains(index as u16))
+    }
+
+    /// Returns the number of points in the chunk map.
+    fn cardinality(&self) -> u128 {
+        let full: u128 = self.full.iter()
+            .map(|(l, h)| u128::from(h - l) + 1)
+            .sum();
+        let partial: u128 = self.partial.values()
+            .map(|c| u128::from(c.cardinality()))
+            .sum();
+        (full << CHUNK_BITS) + partial
+    }
+
+    /// Returns the number of runs and containers stored in the chunk map.
+    fn stored_len(&self) -> usize {
+        self.full.len() + self.partial.len()
+    }
+
+    /// Adds the given inclusive run of point indices to the chunk map,
+    /// touching only the chunks it crosses.
+    fn insert(&mut self, l: u64, h: u64) {
+        let (keys, edges) = split_run(l, h);
+        if let Some((first, last)) = keys {
+            self.remove_partial(first, last);
+            insert_run(&mut self.full, first, last);
+        }
+        for &(key, low, high) in edges.iter().flatten() {
+            if runs_contain(&self.full, key) { continue; }
+            match self.partial.entry(key) {
+                btree_map::Entry::Occupied(mut entry) => {
+                    entry.get_mut().insert_run(low, high);
+                    if entry.get().is_full() {
+                        let _ = entry.remove();
+                        insert_run(&mut self.full, key, key);
+                    }
+                },
+                btree_map::Entry::Vacant(entry) => {
+                    let _ = entry.insert(Container::from_runs(vec![(low, high)])
+                        .expect("run is not empty"));
+                },
+            }
+        }
+    }
+
+    /// Removes the given inclusive run of point indices from the chunk map,
+    /// touching only the chunks it crosses.
+    fn remove(&mut self, l: u64, h: u64) {
+        let (keys, edges) = split_run(l, h);
+        if let Some((first, last)) = keys {
+            self.remove_partial(first, last);
+            remove_run(&mut self.full, first, last);
+        }
+        for &(key, low, high) in edges.iter().flatten() {
+            if runs_contain(&self.full, key) {
+                remove_run(&mut self.full, key, key);
+                let rest = complement_runs(&[(low, high)]);
+                if let Some(rest) = Container::from_runs(rest) {
+                    let _ = self.partial.insert(key, rest);
+                }
+            } else if let btree_map::Entry::Occupied(mut entry)
+                = self.partial.entry(key)
+            {
+                entry.get_mut().remove_run(low, high);
+                if entry.get().is_empty() { let _ = entry.remove(); }
+            }
+        }
+    }
+
+    /// Removes the containers of the chunks with keys in the given inclusive
+    /// range.
+    fn remove_partial(&mut self, first: u64, last: u64) {
+        let keys: Vec<u64> = self.partial
+            .range(first..=last)
+            .map(|(key, _)| *key)
+            .collect();
+        for key in keys {
+            let _ = self.partial.remove(&key);
+        }
+    }
+}
+
+/// The key of a chunk and an inclusive run of low bits within it.
+type ChunkRun = (u64, u16, u16);
+
+/// Splits an inclusive run of point indices into the run of keys of the chunks
+/// it covers entirely and the runs of low bits it covers in at most two other
+/// chunks.
+fn split_run(l: u64, h: u64)
+    -> (Option<(u64, u64)>, [Option<ChunkRun>; 2])
+{
+    let (lo_key, hi_key) = (l >> CHUNK_BITS, h >> CHUNK_BITS);
+    let (lo_full, hi_full) = (l as u16 == 0, h as u16 == u16::MAX);
+
+    let first = if lo_full { Some(lo_key) } else { lo_key.checked_add(1) };
+    let last = if hi_full { Some(hi_key) } else { hi_key.checked_sub(1) };
+    let keys = match (first, last) {
+        (Some(first), Some(last)) if first <= last => Some((first, last)),
+        _ => None,
+    };
+
+    let lower = (!lo_full).then(|| {
+        let high = if lo_key == hi_key { h as u16 } else { u16::MAX };
+        (lo_key, l as u16, high)
+    });
+    let upper = (!hi_full && (lo_full || lo_key != hi_key)).then(|| {
+        let low = if lo_key == hi_key { l as u16 } else { 0 };
+        (hi_key, low, h as u16)
+    });
+    (keys, [lower, upper])
+}
+
+/// Returns the chunks of points in either chunk map.
+fn union_chunks(a: &Chunks, b: &Chunks) -> Chunks {
+    let full = union_runs(&a.full, &b.full);
+    let mut partial = BTreeMap::new();
+    for (key, container) in a.partial.iter().chain(b.partial.iter()) {
+        if runs_contain(&full, *key) { continue; }
+        let merged = match partial.remove(key) {
+            Some(existing) => Container::union(&existing, container),
+            None           => Some(container.clone()),
+        };
+        if let Some(merged) = merged {
+            let _ = partial.insert(*key, merged);
+        }
+    }
+    Chunks { full, partial }
+}
+
+/// Returns the chunks of points in both chunk maps.
+fn intersect_chunks(a: &Chunks, b: &Chunks) -> Chunks {
+    let full = intersect_runs(&a.full, &b.full);
+    let mut partial = BTreeMap::new();
+    for (key, container) in &a.partial {
+        let other = if runs_contain(&b.full, *key) {
+            Some(container.clone())
+        } else {
+            b.partial.get(key).and_then(|other| container.intersect(other))
+        };
+        if let Some(other) = other {
+            let _ = partial.insert(*key, other);
+        }
+    }
+    for (key, container) in &b.partial {
+        if runs_contain(&a.full, *key) {
+            let _ = partial.insert(*key, container.clone());
+        }
+    }
+    Chunks { full, partial }
+}
+
+/// Returns the chunks of points in the first chunk map but not the second.
+fn minus_chunks(a: &Chunks, b: &Chunks) -> Chunks {
+    let mut removed = b.full.clone();
+    for key in b.partial.keys() {
+        removed = union_runs(&removed, &[(*key, *key)]);
+    }
+    let full = intersect_runs(&a.full, &complement_runs(&removed));
+
+    let mut partial = BTreeMap::new();
+    for (key, container) in &a.partial {
+        let rest = match b.partial.get(key) {
+            Some(other)                          => container.minus(other),
+            None if runs_contain(&b.full, *key)  => None,
+            None                                 => Some(container.clone()),
+        };
+        if let Some(rest) = rest {
+            let _ = partial.insert(*key, rest);
+        }
+    }
+    for (key, container) in &b.partial {
+        if !runs_contain(&a.full, *key) { continue; }
+        if let Some(rest) = container.complement() {
+            let _ = partial.insert(*key, rest);
+        }
+    }
+    Chunks { full, partial }
+}
+
+/// Builds a chunk map from sorted, disjoint, and non-adjacent inclusive runs of
+/// point indices.
+fn chunks_from_runs<I>(runs: I) -> Chunks
+    where I: IntoIterator<Item=(u64, u64)>
+{
+    let mut full = Vec::new();
+    let mut chunk_runs: BTreeMap<u64, Vec<(u16, u16)>> = BTreeMap::new();
+    for (l, h) in runs {
+        let (keys, edges) = split_run(l, h);
+        if let Some((first, last)) = keys {
+            push_run(&mut full, first, last);
+        }
+        for &(key, low, high) in edges.iter().flatten() {
+            chunk_runs.entry(key).or_default().push((low, high));
+        }
+    }
+    let partial = chunk_runs
+        .into_iter()
+        .filter_map(|(key, runs)| Container::from_runs(runs).map(|c| (key, c)))
+        .collect();
+    Chunks { full, partial }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RoaringSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of `Interval`s of the type `T`, stored
+/// as a compressed bitmap in the style of [Roaring bitmaps].
+///
+/// Points are split into chunks of 2<sup>16</sup> by their high bits, and each
+/// chunk is stored in whichever container is smallest: a sorted array for
+/// scattered points, a bitmap for dense chunks, or a list of runs. This makes
+/// a `RoaringSelection` compact for selections which mix long runs with
+/// scattered single points.
+///
+/// Consecutive chunks containing every point are stored as a single run of
+/// chunk keys, and a `RoaringSelection` may also be stored as the complement of
+/// its chunks, so long intervals and complements do not require every chunk
+/// they cross. Memory use is otherwise proportional to the number of partially
+/// selected chunks.
+///
+/// [Roaring bitmaps]: https://roaringbitmap.org/
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::roaring_selection::RoaringSelection;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut ids: RoaringSelection<u64> = RoaringSelection::new();
+/// ids.union_in_place(Interval::closed(1_000, 900_000));
+/// ids.union_in_place(Interval::point(5_000_000));
+///
+/// assert!(ids.contains(&70_000));
+/// assert_eq!(ids.cardinality(), 899_002);
+/// assert!(ids.complement().contains(&u64::MAX));
+///
+/// let sel = Selection::from(ids.clone());
+/// assert_eq!(RoaringSelection::from(sel), ids);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoaringSelection<T> {
+    /// The chunks of the selection, keyed by the high bits of their points.
+    chunks: Chunks,
+    /// Whether the selection is the complement of its chunks.
+    inverted: bool,
+    /// The type of the selected points.
+    marker: PhantomData<T>,
+}
+
+impl<T> RoaringSelection<T> where T: FiniteIndex {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `RoaringSelection`.
+    pub fn new() -> Self {
+        RoaringSelection::from_chunks(Chunks::default(), false)
+    }
+
+    /// Constructs a new `RoaringSelection` containing every point.
+    pub fn full() -> Self {
+        RoaringSelection::from_chunks(Chunks::default(), true)
+    }
+
+    /// Constructs a `RoaringSelection` from the given chunks.
+    fn from_chunks(chunks: Chunks, inverted: bool) -> Self {
+        RoaringSelection { chunks, inverted, marker: PhantomData }
+    }
+
+    /// Constructs a `RoaringSelection` from sorted, disjoint, and non-adjacent
+    /// inclusive runs of point indices, storing either the runs or their
+    /// complement, whichever is smaller.
+    fn from_index_runs(runs: Vec<(u64, u64)>) -> Self {
+        let max = T::MAXIMUM.finite_index();
+        let mut gaps = Vec::with_capacity(runs.len() + 1);
+        let mut next = Some(0u64);
+        for (l, h) in &runs {
+            if let Some(start) = next {
+                if start < *l { gaps.push((start, l - 1)); }
+            }
+            next = h.checked_add(1).filter(|n| *n <= max);
+        }
+        if let Some(start) = next { gaps.push((start, max)); }
+
+        let (runs, gaps) = (chunks_from_runs(runs), chunks_from_runs(gaps));
+        if gaps.stored_len() < runs.stored_len() {
+            RoaringSelection::from_chunks(gaps, true)
+        } else {
+            RoaringSelection::from_chunks(runs, false)
+        }
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `RoaringSelection` contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.interval_iter().next().is_none()
+    }
+
+    /// Returns `true` if the `RoaringSelection` contains every point.
+    pub fn is_full(&self) -> bool {
+        self.complement().is_empty()
+    }
+
+    /// Returns the number of points in the `RoaringSelection`.
+    pub fn cardinality(&self) -> u128 {
+        let stored = self.chunks.cardinality();
+        if self.inverted {
+            u128::from(T::MAXIMUM.finite_index()) + 1 - stored
+        } else {
+            stored
+        }
+    }
+
+    /// Returns `true` if the `RoaringSelection` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        self.chunks.contains(point.finite_index()) != self.inverted
+    }
+
+    /// Returns the greatest lower bound of the `RoaringSelection`, or `None`
+    /// if it is empty.
+    pub fn infimum(&self) -> Option<T> {
+        self.interval_iter().next().and_then(|i| i.infimum())
+    }
+
+    /// Returns the least upper bound of the `RoaringSelection`, or `None` if
+    /// it is empty.
+    pub fn supremum(&self) -> Option<T> {
+        self.interval_iter().last().and_then(|i| i.supremum())
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `RoaringSelection` containing all points not in the
+    /// `RoaringSelection`.
+    pub fn complement(&self) -> Self {
+        RoaringSelection::from_chunks(self.chunks.clone(), !self.inverted)
+    }
+
+    /// Returns a `RoaringSelection` containing all points in both
+    /// `RoaringSelection`s.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let (a, b) = (&self.chunks, &other.chunks);
+        match (self.inverted, other.inverted) {
+            (false, false) =>
+                RoaringSelection::from_chunks(intersect_chunks(a, b), false),
+            (true,  false) =>
+                RoaringSelection::from_chunks(minus_chunks(b, a), false),
+            (false, true)  =>
+                RoaringSelection::from_chunks(minus_chunks(a, b), false),
+            (true,  true)  =>
+                RoaringSelection::from_chunks(union_chunks(a, b), true),
+        }
+    }
+
+    /// Returns a `RoaringSelection` containing all points in either
+    /// `RoaringSelection`.
+    pub fn union(&self, other: &Self) -> Self {
+        let (a, b) = (&self.chunks, &other.chunks);
+        match (self.inverted, other.inverted) {
+            (false, false) =>
+                RoaringSelection::from_chunks(union_chunks(a, b), false),
+            (true,  false) =>
+                RoaringSelection::from_chunks(minus_chunks(a, b), true),
+            (false, true)  =>
+                RoaringSelection::from_chunks(minus_chunks(b, a), true),
+            (true,  true)  =>
+                RoaringSelection::from_chunks(intersect_chunks(a, b), true),
+        }
+    }
+
+    /// Returns a `RoaringSelection` containing all points in the
+    /// `RoaringSelection` which are not in the given `RoaringSelection`.
+    pub fn minus(&self, other: &Self) -> Self {
+        self.intersect(&other.complement())
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the `RoaringSelection` to only those points contained in the
+    /// given `Interval`.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        let max = T::MAXIMUM.finite_index();
+        match interval.index_bounds() {
+            Some((l, h)) => {
+                if l > 0 { self.remove_indices(0, l - 1); }
+                if h < max { self.remove_indices(h + 1, max); }
+            },
+            None => *self = RoaringSelection::new(),
+        }
+    }
+
+    /// Adds all of the points in the given `Interval` to the
+    /// `RoaringSelection`.
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        if let Some((l, h)) = interval.index_bounds() {
+            self.insert_indices(l, h);
+        }
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `RoaringSelection`.
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        if let Some((l, h)) = interval.index_bounds() {
+            self.remove_indices(l, h);
+        }
+    }
+
+    /// Adds the given inclusive run of point indices to the
+    /// `RoaringSelection`.
+    fn insert_indices(&mut self, l: u64, h: u64) {
+        if self.inverted {
+            self.chunks.remove(l, h);
+        } else {
+            self.chunks.insert(l, h);
+        }
+    }
+
+    /// Removes the given inclusive run of point indices from the
+    /// `RoaringSelection`.
+    fn remove_indices(&mut self, l: u64, h: u64) {
+        if self.inverted {
+            self.chunks.insert(l, h);
+        } else {
+            self.chunks.remove(l, h);
+        }
+    }
+
+    // Iterators
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the `Interval`s of the `RoaringSelection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter {
+            runs: RunIter {
+                full: self.chunks.full.iter().peekable(),
+                partial: self.chunks.partial.iter().peekable(),
+                current: Vec::new().into_iter(),
+                key: 0,
+                pending: None,
+            },
+            inverted: self.inverted,
+            next_gap: Some(0),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over each of the points in the `RoaringSelection`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            intervals: self.interval_iter(),
+            current: Interval::empty().iter(),
+        }
+    }
+}
+
+impl<T> Default for RoaringSelection<T> where T: FiniteIndex {
+    fn default() -> Self {
+        RoaringSelection::new()
+    }
+}
+
+impl<T> PartialEq for RoaringSelection<T> where T: FiniteIndex {
+    fn eq(&self, other: &Self) -> bool {
+        self.interval_iter().eq(other.interval_iter())
+    }
+}
+
+impl<T> Eq for RoaringSelection<T> where T: FiniteIndex {}
+
+impl<T> Extend<Interval<T>> for RoaringSelection<T> where T: FiniteIndex {
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        for interval in iter.into_iter() {
+            self.union_in_place(interval);
+        }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for RoaringSelection<T>
+    where T: FiniteIndex,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        let mut roaring = RoaringSelection::new();
+        roaring.extend(iter);
+        roaring
+    }
+}
+
+impl<T> FromIterator<T> for RoaringSelection<T> where T: FiniteIndex {
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
+        let mut roaring = RoaringSelection::new();
+        roaring.extend(iter.into_iter().map(Interval::point));
+        roaring
+    }
+}
+
+impl<T> From<Interval<T>> for RoaringSelection<T> where T: FiniteIndex {
+    fn from(interval: Interval<T>) -> Self {
+        RoaringSelection::from_index_runs(interval.index_bounds()
+            .into_iter()
+            .collect())
+    }
+}
+
+impl<T> From<Selection<T>> for RoaringSelection<T> where T: FiniteIndex {
+    fn from(selection: Selection<T>) -> Self {
+        let runs = selection
+            .interval_iter()
+            .filter_map(|i| i.index_bounds())
+            .collect();
+        RoaringSelection::from_index_runs(runs)
+    }
+}
+
+impl<T> From<RoaringSelection<T>> for Selection<T> where T: FiniteIndex {
+    fn from(roaring: RoaringSelection<T>) -> Self {
+        roaring.interval_iter().collect()
+    }
+}
+
+impl<T> IntervalSet<T> for RoaringSelection<T> where T: FiniteIndex {
+    type Output = RoaringSelection<T>;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        RoaringSelection::interval_iter(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        RoaringSelection::is_empty(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        RoaringSelection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self::Output {
+        RoaringSelection::complement(self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RunIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the stored runs of point indices of a
+/// `RoaringSelection`, merged across chunk boundaries.
+#[derive(Debug)]
+struct RunIter<'t> {
+    full: Peekable<std::slice::Iter<'t, (u64, u64)>>,
+    partial: Peekable<btree_map::Iter<'t, u64, Container>>,
+    current: std::vec::IntoIter<(u16, u16)>,
+    key: u64,
+    pending: Option<(u64, u64)>,
+}
+
+impl<'t> RunIter<'t> {
+    /// Returns the next run of the current or following chunk.
+    fn next_chunk_run(&mut self) -> Option<(u64, u64)> {
+        loop {
+            if let Some((l, h)) = self.current.next() {
+                let base = self.key << CHUNK_BITS;
+                return Some((base + u64::from(l), base + u64::from(h)));
+            }
+            let next_full = match (self.full.peek(), self.partial.peek()) {
+                (Some((first, _)), Some((key, _))) => first < *key,
+                (Some(_), None)                    => true,
+                (None, Some(_))                    => false,
+                (None, None)                       => return None,
+            };
+            if next_full {
+                let (first, last) = self.full.next()?;
+                return Some((
+                    first << CHUNK_BITS,
+                    (last << CHUNK_BITS) | u64::from(u16::MAX)));
+            }
+            let (key, container) = self.partial.next()?;
+            self.key = *key;
+            self.current = container.runs().into_iter();
+        }
+    }
+}
+
+impl<'t> Iterator for RunIter<'t> {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (l, mut h) = self.pending.take()
+            .or_else(|| self.next_chunk_run())?;
+        loop {
+            match self.next_chunk_run() {
+                Some((nl, nh)) if h.checked_add(1) == Some(nl) => h = nh,
+                next => {
+                    self.pending = next;
+                    return Some((l, h));
+                },
+            }
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `RoaringSelection`.
+#[derive(Debug)]
+pub struct IntervalIter<'t, T> {
+    runs: RunIter<'t>,
+    inverted: bool,
+    next_gap: Option<u64>,
+    marker: PhantomData<T>,
+}
+
+impl<'t, T> IntervalIter<'t, T> where T: FiniteIndex {
+    /// Returns the next run of selected point indices.
+    fn next_run(&mut self) -> Option<(u64, u64)> {
+        if !self.inverted { return self.runs.next(); }
+
+        let max = T::MAXIMUM.finite_index();
+        loop {
+            match self.runs.next() {
+                Some((l, h)) => {
+                    let start = self.next_gap?;
+                    self.next_gap = h.checked_add(1).filter(|n| *n <= max);
+                    if start < l { return Some((start, l - 1)); }
+                },
+                None => return self.next_gap.take().map(|start| (start, max)),
+            }
+        }
+    }
+}
+
+impl<'t, T> Iterator for IntervalIter<'t, T> where T: FiniteIndex {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_run().map(|(l, h)| Interval::closed(
+            T::from_finite_index(l),
+            T::from_finite_index(h)))
+    }
+}
+
+impl<'t, T> FusedIterator for IntervalIter<'t, T> where T: FiniteIndex {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points of a `RoaringSelection`.
+#[derive(Debug)]
+pub struct Iter<'t, T> where T: Ord + Clone + Finite {
+    intervals: IntervalIter<'t, T>,
+    current: crate::interval::Iter<T>,
+}
+
+impl<'t, T> Iterator for Iter<'t, T> where T: FiniteIndex {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(next) = self.current.next() {
+            return Some(next);
+        }
+        self.current = self.intervals.next()?.iter();
+        self.current.next()
+    }
+}
+
+impl<'t, T> FusedIterator for Iter<'t, T> where T: FiniteIndex {}
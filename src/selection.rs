@@ -14,9 +14,12 @@
 // Local imports.
 use crate::bound::Bound;
 use crate::interval::Interval;
+use crate::interval::SplitSide;
 use crate::normalize::Normalize;
 use crate::normalize::Finite;
+use crate::normalize::FiniteIndex;
 use crate::raw_interval::RawInterval;
+use crate::tine::Tine;
 use crate::tine_tree::TineTree;
 
 // Standard library imports.
@@ -520,7 +523,11 @@ impl<T> Selection<T>
     /// # }
     /// ```
     pub fn complement(&self) -> Self {
-        Selection(self.0.complement())
+        // Inverting the tines of denormalized intervals does not produce
+        // denormalized intervals.
+        let mut complement = self.0.complement();
+        complement.denormalize();
+        Selection(complement)
     }
 
     /// Returns the `Selection` containing all points in both the given
@@ -562,7 +569,11 @@ impl<T> Selection<T>
     /// # }
     /// ```
     pub fn intersect(&self, other: &Self) -> Self {
-        Selection(self.0.intersect(&other.0))
+        // Intersecting denormalized intervals may produce intervals with no
+        // points.
+        let mut intersection = self.0.intersect(&other.0);
+        intersection.denormalize();
+        Selection(intersection)
     }
 
     /// Returns the `Selection` containing all points in either of the given
@@ -627,7 +638,11 @@ impl<T> Selection<T>
     /// # }
     /// ```
     pub fn minus(&self, other: &Self) -> Self {
-        Selection(self.0.minus(&other.0))
+        // Removing denormalized intervals may leave intervals with included
+        // bounds or with no points.
+        let mut minus = self.0.minus(&other.0);
+        minus.denormalize();
+        Selection(minus)
     }
 
     /// Returns the smallest `Interval` containing all of the points in the 
@@ -722,7 +737,10 @@ impl<T> Selection<T>
     /// # }
     /// ```
     pub fn intersect_in_place(&mut self, interval: Interval<T>) {
-        self.0.intersect_in_place(&interval.0.denormalized());
+        let interval = interval.0.denormalized();
+        self.0.intersect_in_place(&interval);
+        self.0.denormalize_bounds(&interval);
+        self.debug_check_invariants();
     }
 
     /// Adds all of the points in the given `Interval` to the `Selection`.
@@ -766,6 +784,7 @@ impl<T> Selection<T>
     /// ```
     pub fn union_in_place(&mut self, interval: Interval<T>) {
         self.0.union_in_place(&interval.0.denormalized());
+        self.debug_check_invariants();
     }
 
     /// Removes all of the points in the given `Interval` from the `Selection`.
@@ -808,13 +827,144 @@ impl<T> Selection<T>
     /// # }
     /// ```
     pub fn minus_in_place(&mut self, interval: Interval<T>) {
-        self.0.minus_in_place(&interval.0.denormalized());
+        let interval = interval.0.denormalized();
+        self.0.minus_in_place(&interval);
+        self.0.denormalize_bounds(&interval);
+        self.debug_check_invariants();
     }
 
+    ////////////////////////////////////////////////////////////////////////////
+    // Partitioning
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Splits the `Selection` at the given point, returning the portions of
+    /// the `Selection` before and after it. The [`SplitSide`] determines which
+    /// portion includes the point.
+    ///
+    /// [`SplitSide`]: ../interval/enum.SplitSide.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::interval::SplitSide;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.union_in_place(Interval::closed(20, 29));
+    ///
+    /// let (before, after) = sel.split_at(&5, SplitSide::Right);
+    /// assert_eq!(before.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 4)]);
+    /// assert_eq!(after.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(5, 9), Interval::closed(20, 29)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_at(&self, point: &T, side: SplitSide) -> (Self, Self) {
+        let mut before = Selection::new();
+        let mut after = Selection::new();
+        for interval in self.interval_iter() {
+            let (lower, upper) = interval.split_at(point, side);
+            before.union_in_place(lower);
+            after.union_in_place(upper);
+        }
+        (before, after)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Invariants
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Checks the internal invariants of the `Selection`, returning the first
+    /// violation found.
+    ///
+    /// A `Selection` stores the bounds of its intervals in order, in the
+    /// denormalized form which allows adjacent intervals to be merged. Every
+    /// lower bound must be matched by an upper bound, every interval and every
+    /// gap between intervals must contain a point, and every bound must be in
+    /// denormalized form.
+    ///
+    /// The public operations of `Selection` maintain these invariants, so this
+    /// is intended for diagnosing bugs. The `debug-invariants` feature runs it
+    /// after every mutating operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.minus_in_place(Interval::closed(3, 5));
+    ///
+    /// assert_eq!(sel.check_invariants(), Ok(()));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), InvariantError<T>> {
+        use Bound::*;
+        let mut check = InvariantCheck {
+            lower: None,
+            prev_upper: None,
+            unnormalized: None,
+        };
+        for tine in self.0.tine_iter() {
+            match tine.clone() {
+                Tine::Lower(b)          => check.open(b)?,
+                Tine::Upper(b)          => check.close(b)?,
+                // An included point is a whole interval.
+                Tine::Point(Include(p)) => {
+                    check.open(Include(p.clone()))?;
+                    check.close(Include(p))?;
+                },
+                // An excluded point closes an interval and opens the next.
+                Tine::Point(b)          => {
+                    check.close(b.clone())?;
+                    check.open(b)?;
+                },
+            }
+        }
+        check.finish()
+    }
+
+    /// Constructs a `Selection` from the given `TineTree` without checking
+    /// its invariants.
+    #[cfg(test)]
+    pub(in crate) fn from_tine_tree(tree: TineTree<T>) -> Self {
+        Selection(tree)
+    }
+
+    /// Panics if the `Selection` violates its internal invariants.
+    #[cfg(feature = "debug-invariants")]
+    fn debug_check_invariants(&self) {
+        if let Err(error) = self.check_invariants() {
+            panic!("Selection invariant violated: {}", error.reason());
+        }
+    }
+
+    /// Checks the internal invariants of the `Selection` if the
+    /// `debug-invariants` feature is enabled.
+    #[cfg(not(feature = "debug-invariants"))]
+    #[inline]
+    fn debug_check_invariants(&self) {}
+
     ////////////////////////////////////////////////////////////////////////////
     // Iterator conversions
     ////////////////////////////////////////////////////////////////////////////
 
+    /// Returns the `TineTree` representing the `Selection`.
+    #[cfg(test)]
+    pub(in crate) fn tine_tree(&self) -> &TineTree<T> {
+        &self.0
+    }
+
     /// Returns an iterator over each of the `Interval`s in the `Selection`.
     pub fn interval_iter(&self) -> IntervalIter<'_, T> {
         IntervalIter(self.0.interval_iter())
@@ -845,6 +995,326 @@ impl<T> Selection<T>
             current: Interval::empty().iter(),
         }
     }
+
+    // Neighborhood queries
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the least point of the `Selection` which is greater than or
+    /// equal to the given point, or `None` if there is no such point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.minus_in_place(Interval::closed(3, 5));
+    ///
+    /// assert_eq!(sel.next_point_at_or_after(&2), Some(2));
+    /// assert_eq!(sel.next_point_at_or_after(&4), Some(6));
+    /// assert_eq!(sel.next_point_at_or_after(&10), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn next_point_at_or_after(&self, point: &T) -> Option<T> {
+        let interval = self.interval_at_or_after(point)?;
+        if interval.contains(point) {
+            Some(point.clone())
+        } else {
+            interval.infimum()
+        }
+    }
+
+    /// Returns the greatest point of the `Selection` which is less than or
+    /// equal to the given point, or `None` if there is no such point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.minus_in_place(Interval::closed(3, 5));
+    ///
+    /// assert_eq!(sel.prev_point_at_or_before(&7), Some(7));
+    /// assert_eq!(sel.prev_point_at_or_before(&4), Some(2));
+    /// assert_eq!(sel.prev_point_at_or_before(&-1), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn prev_point_at_or_before(&self, point: &T) -> Option<T> {
+        let interval = self.interval_at_or_before(point)?;
+        if interval.contains(point) {
+            Some(point.clone())
+        } else {
+            interval.supremum()
+        }
+    }
+
+    /// Returns the `Interval` of the `Selection` containing the given point,
+    /// or `None` if the point is not in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.minus_in_place(Interval::closed(3, 5));
+    ///
+    /// assert_eq!(sel.interval_containing(&8), Some(Interval::closed(6, 9)));
+    /// assert_eq!(sel.interval_containing(&4), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interval_containing(&self, point: &T) -> Option<Interval<T>> {
+        self.0
+            .interval_containing(point)
+            .map(Normalize::normalized)
+            .map(Interval::from)
+    }
+
+    /// Returns the `Interval` of the `Selection` nearest to the given point,
+    /// or `None` if the `Selection` is empty.
+    ///
+    /// If the point is in the `Selection`, the `Interval` containing it is
+    /// returned. Otherwise the distances to the nearest points on either side
+    /// are compared by [`FiniteIndex`], with ties going to the lesser
+    /// `Interval`.
+    ///
+    /// [`FiniteIndex`]: ../normalize/trait.FiniteIndex.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.minus_in_place(Interval::closed(3, 6));
+    ///
+    /// assert_eq!(sel.nearest_interval(&4), Some(Interval::closed(0, 2)));
+    /// assert_eq!(sel.nearest_interval(&6), Some(Interval::closed(7, 9)));
+    /// assert_eq!(sel.nearest_interval(&20), Some(Interval::closed(7, 9)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn nearest_interval(&self, point: &T) -> Option<Interval<T>>
+        where T: FiniteIndex
+    {
+        if let Some(interval) = self.interval_containing(point) {
+            return Some(interval);
+        }
+        match (self.interval_at_or_before(point),
+            self.interval_at_or_after(point))
+        {
+            (Some(before), Some(after)) => {
+                let sup = before.supremum().expect("nonempty interval");
+                let inf = after.infimum().expect("nonempty interval");
+                let index = point.finite_index();
+                if inf.finite_index() - index < index - sup.finite_index() {
+                    Some(after)
+                } else {
+                    Some(before)
+                }
+            },
+            (before, after) => before.or(after),
+        }
+    }
+
+    /// Returns the first gap in the `Selection` which contains or follows the
+    /// given point, or `None` if there is no such gap. A gap is a maximal
+    /// `Interval` of points not in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 9));
+    /// sel.minus_in_place(Interval::closed(3, 5));
+    ///
+    /// assert_eq!(sel.gap_after(&1), Some(Interval::closed(3, 5)));
+    /// assert_eq!(sel.gap_after(&4), Some(Interval::closed(3, 5)));
+    /// assert_eq!(sel.gap_after(&7), Some(Interval::closed(10, i32::MAX)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gap_after(&self, point: &T) -> Option<Interval<T>> {
+        let start = match self.interval_containing(point) {
+            Some(interval) => interval.supremum()?.succ()?,
+            None => self
+                .interval_at_or_before(point)
+                .and_then(|interval| interval.supremum())
+                .and_then(|sup| sup.succ())
+                .unwrap_or(T::MINIMUM),
+        };
+        let end = self
+            .interval_at_or_after(&start)
+            .and_then(|interval| interval.infimum())
+            .and_then(|inf| inf.pred())
+            .unwrap_or(T::MAXIMUM);
+        Some(Interval::closed(start, end))
+    }
+
+    /// Returns the first `Interval` of the `Selection` which contains or
+    /// follows the given point.
+    fn interval_at_or_after(&self, point: &T) -> Option<Interval<T>> {
+        self.0
+            .interval_at_or_after(point)
+            .map(Normalize::normalized)
+            .map(Interval::from)
+    }
+
+    /// Returns the last `Interval` of the `Selection` which contains or
+    /// precedes the given point.
+    fn interval_at_or_before(&self, point: &T) -> Option<Interval<T>> {
+        self.0
+            .interval_at_or_before(point)
+            .map(Normalize::normalized)
+            .map(Interval::from)
+    }
+
+    // Sub-views
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a borrowed view of the portion of the `Selection` within the
+    /// given `Interval`.
+    ///
+    /// The view is not copied from the `Selection`, so it is cheap to create
+    /// and its operations only visit the intervals near the window.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 99));
+    /// sel.minus_in_place(Interval::closed(10, 89));
+    ///
+    /// let view = sel.range(Interval::closed(5, 94));
+    /// assert_eq!(view.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(5, 9), Interval::closed(90, 94)]);
+    /// assert_eq!(view.infimum(), Some(5));
+    /// assert_eq!(view.iter().next_back(), Some(94));
+    /// assert_eq!(view.count(), 10);
+    /// assert!(!view.contains(&2));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn range(&self, interval: Interval<T>) -> SelectionRange<'_, T> {
+        SelectionRange { selection: self, window: interval }
+    }
+
+    // Partitioning
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an `Iterator` over consecutive `Selection`s of the given number
+    /// of points which cover the `Selection`. The last `Selection` may contain
+    /// fewer points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 4));
+    /// sel.union_in_place(Interval::closed(10, 14));
+    ///
+    /// let chunks: Vec<_> = sel.chunks_by_points(4).collect();
+    /// assert_eq!(chunks[0].interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 3)]);
+    /// assert_eq!(chunks[1].interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::point(4), Interval::closed(10, 12)]);
+    /// assert_eq!(chunks[2].interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(13, 14)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn chunks_by_points(&self, size: usize) -> ChunksByPoints<'_, T>
+        where T: FiniteIndex
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunksByPoints {
+            intervals: self.interval_iter(),
+            current: Interval::empty().chunks(size),
+            size,
+        }
+    }
+
+    /// Partitions the `Selection` into `k` consecutive `Selection`s whose
+    /// point counts differ by at most one. Earlier pieces receive the extra
+    /// points, and if the `Selection` has fewer than `k` points, the trailing
+    /// pieces will be empty.
+    ///
+    /// Points are counted from the bounds of each interval, so this runs in
+    /// time linear in the number of intervals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    /// sel.union_in_place(Interval::closed(10, 14));
+    ///
+    /// let pieces = sel.partition_into(3);
+    /// assert_eq!(pieces.iter().map(|p| p.iter().count()).collect::<Vec<_>>(),
+    ///     [4, 4, 3]);
+    /// assert_eq!(pieces[1].interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(4, 5), Interval::closed(10, 11)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn partition_into(&self, k: usize) -> Vec<Self> where T: FiniteIndex {
+        assert!(k != 0, "partition count must be non-zero");
+        let total: u128 = self.interval_iter().map(|i| i.point_count()).sum();
+        let (base, extra) = (total / k as u128, total % k as u128);
+        let mut chunks = self.chunks_by_points(1);
+        (0..k as u128)
+            .map(|i| if i < extra { base + 1 } else { base })
+            .map(|n| chunks.take_points(n))
+            .collect()
+    }
 }
 
 impl<T> Default for Selection<T> 
@@ -867,6 +1337,7 @@ impl<T> Extend<Interval<T>> for Selection<T>
             let raw = interval.0.denormalized();
             self.0.union_in_place(&raw);
         }
+        self.debug_check_invariants();
     }
 }
 
@@ -922,6 +1393,140 @@ impl<T> IntoIterator for Selection<T>
     }
 }
 
+/// The state of a walk over the bounds of a `Selection` which checks its
+/// invariants.
+struct InvariantCheck<T> {
+    /// The lower bound of the current interval, if any.
+    lower: Option<Bound<T>>,
+    /// The upper bound of the previous interval, if any.
+    prev_upper: Option<Bound<T>>,
+    /// The first bound found which is not denormalized. This is only reported
+    /// once the bounds are known to form valid intervals.
+    unnormalized: Option<Bound<T>>,
+}
+
+impl<T> InvariantCheck<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Opens an interval at the given lower bound, checking that no interval
+    /// is open and that the gap after the previous interval contains a point.
+    fn open(&mut self, lower: Bound<T>) -> Result<(), InvariantError<T>> {
+        use Bound::*;
+        if let Some(l) = self.lower.take() {
+            return Err(InvariantError::UnmatchedLower(l));
+        }
+        if let Some(u) = self.prev_upper.take() {
+            // The gap is bounded by the complements of the interval bounds.
+            let complement = |b: &Bound<T>| match b {
+                Include(p) => Exclude(p.clone()),
+                Exclude(p) => Include(p.clone()),
+                Infinite   => Infinite,
+            };
+            let gap = RawInterval::new(complement(&u), complement(&lower));
+            if gap.normalized().is_empty() {
+                return Err(InvariantError::UnmergedIntervals(u, lower));
+            }
+        }
+        self.lower = Some(lower);
+        Ok(())
+    }
+
+    /// Closes the current interval at the given upper bound, checking that it
+    /// contains a point.
+    fn close(&mut self, upper: Bound<T>) -> Result<(), InvariantError<T>> {
+        let lower = match self.lower.take() {
+            Some(lower) => lower,
+            None        => return Err(InvariantError::UnmatchedUpper(upper)),
+        };
+        let normalized = RawInterval::new(lower.clone(), upper.clone())
+            .normalized();
+        if normalized.is_empty() {
+            return Err(InvariantError::EmptyInterval(lower, upper));
+        }
+
+        if self.unnormalized.is_none() {
+            let denormalized = normalized.denormalized();
+            if denormalized.lower_bound().as_ref() != Some(&lower) {
+                self.unnormalized = Some(lower);
+            } else if denormalized.upper_bound().as_ref() != Some(&upper) {
+                self.unnormalized = Some(upper.clone());
+            }
+        }
+        self.prev_upper = Some(upper);
+        Ok(())
+    }
+
+    /// Checks that no interval is open and that every bound is denormalized.
+    fn finish(self) -> Result<(), InvariantError<T>> {
+        if let Some(l) = self.lower {
+            return Err(InvariantError::UnmatchedLower(l));
+        }
+        match self.unnormalized {
+            Some(b) => Err(InvariantError::UnnormalizedBound(b)),
+            None    => Ok(()),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// InvariantError
+////////////////////////////////////////////////////////////////////////////////
+/// A violation of the internal invariants of a [`Selection`], as returned by
+/// [`Selection::check_invariants`].
+///
+/// [`Selection`]: struct.Selection.html
+/// [`Selection::check_invariants`]: struct.Selection.html#method.check_invariants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvariantError<T> {
+    /// A lower bound is not followed by an upper bound before the next lower
+    /// bound or the end of the `Selection`.
+    UnmatchedLower(Bound<T>),
+    /// An upper bound is not preceded by a lower bound.
+    UnmatchedUpper(Bound<T>),
+    /// The interval between the given lower and upper bounds contains no
+    /// points.
+    EmptyInterval(Bound<T>, Bound<T>),
+    /// The intervals ending at the given upper bound and starting at the given
+    /// lower bound are adjacent, and were not merged.
+    UnmergedIntervals(Bound<T>, Bound<T>),
+    /// The bound is not in denormalized form.
+    UnnormalizedBound(Bound<T>),
+}
+
+impl<T> InvariantError<T> {
+    /// Returns a description of the violated invariant.
+    fn reason(&self) -> &'static str {
+        use InvariantError::*;
+        match *self {
+            UnmatchedLower(_)     => "unmatched lower bound",
+            UnmatchedUpper(_)     => "unmatched upper bound",
+            EmptyInterval(..)     => "interval contains no points",
+            UnmergedIntervals(..) => "adjacent intervals were not merged",
+            UnnormalizedBound(_)  => "bound is not denormalized",
+        }
+    }
+}
+
+impl<T> std::fmt::Display for InvariantError<T> where T: std::fmt::Debug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use InvariantError::*;
+        match self {
+            UnmatchedLower(b)
+            | UnmatchedUpper(b)
+            | UnnormalizedBound(b)    =>
+                write!(f, "{} at {:?}", self.reason(), b),
+            EmptyInterval(l, u)
+            | UnmergedIntervals(l, u) =>
+                write!(f, "{} between {:?} and {:?}", self.reason(), l, u),
+        }
+    }
+}
+
+impl<T> std::error::Error for InvariantError<T> where T: std::fmt::Debug {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // IntoIntervalIter
 ////////////////////////////////////////////////////////////////////////////////
@@ -968,7 +1573,7 @@ impl<T> FusedIterator for IntoIntervalIter<T>
 // IntervalIter
 ////////////////////////////////////////////////////////////////////////////////
 /// An `Iterator` over the `Interval`s of a `Selection`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IntervalIter<'t, T>(crate::tine_tree::Iter<'t, T>)
     where T: Ord + Clone;
 
@@ -1129,3 +1734,230 @@ impl<'t, T> DoubleEndedIterator for Iter<'t, T>
 impl<'t, T> FusedIterator for Iter<'t, T>
     where T: Ord + Clone + Finite,
 {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionRange
+////////////////////////////////////////////////////////////////////////////////
+/// A borrowed view of the portion of a `Selection` within an `Interval`.
+///
+/// Returned by [`Selection::range`].
+///
+/// [`Selection::range`]: struct.Selection.html#method.range
+#[derive(Debug, Clone, Copy)]
+pub struct SelectionRange<'t, T> {
+    selection: &'t Selection<T>,
+    window: Interval<T>,
+}
+
+impl<'t, T> SelectionRange<'t, T> where T: Ord + Clone + Finite {
+    /// Returns the `Interval` bounding the view.
+    pub fn window(&self) -> Interval<T> {
+        self.window.clone()
+    }
+
+    /// Returns `true` if the view contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.interval_iter().next().is_none()
+    }
+
+    /// Returns `true` if the view contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        self.window.contains(point)
+            && self.selection.interval_containing(point).is_some()
+    }
+
+    /// Returns the least point in the view, or `None` if it is empty.
+    pub fn infimum(&self) -> Option<T> {
+        let (l, r) = self.window.closed_bounds()?;
+        self.selection
+            .next_point_at_or_after(&l)
+            .filter(|p| *p <= r)
+    }
+
+    /// Returns the greatest point in the view, or `None` if it is empty.
+    pub fn supremum(&self) -> Option<T> {
+        let (l, r) = self.window.closed_bounds()?;
+        self.selection
+            .prev_point_at_or_before(&r)
+            .filter(|p| *p >= l)
+    }
+
+    /// Returns the number of points in the view.
+    pub fn count(&self) -> u128 where T: FiniteIndex {
+        self.interval_iter().map(|i| i.point_count()).sum()
+    }
+
+    /// Returns the number of `Interval`s in the view.
+    pub fn interval_count(&self) -> usize {
+        self.interval_iter().count()
+    }
+
+    /// Returns a `Selection` containing the points in the view.
+    pub fn to_selection(&self) -> Selection<T> {
+        self.interval_iter().collect()
+    }
+
+    /// Returns an iterator over each of the `Interval`s in the view, clipped
+    /// to the window.
+    pub fn interval_iter(&self) -> RangeIntervalIter<'t, T> {
+        let intervals = match (self.window.infimum(), self.window.supremum()) {
+            (Some(l), Some(r)) => {
+                Some(self.selection.0.interval_range_iter(&l, &r))
+            },
+            _ => None,
+        };
+        RangeIntervalIter { intervals, window: self.window.clone() }
+    }
+
+    /// Returns an iterator over each of the points in the view.
+    pub fn iter(&self) -> RangeIter<'t, T> {
+        RangeIter {
+            intervals: self.interval_iter(),
+            front: Interval::empty().iter(),
+            back: Interval::empty().iter(),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RangeIntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `SelectionRange`.
+#[derive(Debug)]
+pub struct RangeIntervalIter<'t, T> where T: Ord + Clone {
+    intervals: Option<crate::tine_tree::Iter<'t, T>>,
+    window: Interval<T>,
+}
+
+impl<'t, T> Iterator for RangeIntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = &self.window;
+        self.intervals.as_mut()?
+            .map(|raw| Interval::from(raw.normalized()).intersect(window))
+            .find(|interval| !interval.is_empty())
+    }
+}
+
+impl<'t, T> DoubleEndedIterator for RangeIntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let window = &self.window;
+        self.intervals.as_mut()?
+            .rev()
+            .map(|raw| Interval::from(raw.normalized()).intersect(window))
+            .find(|interval| !interval.is_empty())
+    }
+}
+
+impl<'t, T> FusedIterator for RangeIntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RangeIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points of a `SelectionRange`.
+#[derive(Debug)]
+pub struct RangeIter<'t, T> where T: Ord + Clone + Finite {
+    intervals: RangeIntervalIter<'t, T>,
+    front: crate::interval::Iter<T>,
+    back: crate::interval::Iter<T>,
+}
+
+impl<'t, T> Iterator for RangeIter<'t, T>
+    where T: Ord + Clone + Finite,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(next) = self.front.next() {
+                return Some(next);
+            }
+            match self.intervals.next() {
+                Some(interval) => self.front = interval.iter(),
+                None           => return self.back.next(),
+            }
+        }
+    }
+}
+
+impl<'t, T> DoubleEndedIterator for RangeIter<'t, T>
+    where T: Ord + Clone + Finite,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(next_back) = self.back.next_back() {
+                return Some(next_back);
+            }
+            match self.intervals.next_back() {
+                Some(interval) => self.back = interval.iter(),
+                None           => return self.front.next_back(),
+            }
+        }
+    }
+}
+
+impl<'t, T> FusedIterator for RangeIter<'t, T>
+    where T: Ord + Clone + Finite,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ChunksByPoints
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over consecutive `Selection`s with a fixed number of points
+/// covering a `Selection`.
+#[derive(Debug)]
+pub struct ChunksByPoints<'t, T> where T: Ord + Clone {
+    intervals: IntervalIter<'t, T>,
+    current: crate::interval::Chunks<T>,
+    size: usize,
+}
+
+impl<'t, T> ChunksByPoints<'t, T> where T: FiniteIndex {
+    /// Returns a `Selection` of the next `n` points, or fewer if the points
+    /// run out.
+    fn take_points(&mut self, n: u128) -> Selection<T> {
+        let mut chunk = Selection::new();
+        let mut remaining = n;
+        while remaining > 0 {
+            match self.current.take_points(remaining) {
+                Some((interval, count)) => {
+                    chunk.union_in_place(interval);
+                    remaining -= count;
+                },
+                None => match self.intervals.next() {
+                    Some(interval) => {
+                        self.current = interval.chunks(self.size);
+                    },
+                    None => break,
+                },
+            }
+        }
+        chunk
+    }
+}
+
+impl<'t, T> Iterator for ChunksByPoints<'t, T>
+    where T: FiniteIndex,
+{
+    type Item = Selection<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = self.size as u128;
+        Some(self.take_points(size)).filter(|chunk| !chunk.is_empty())
+    }
+}
+
+impl<'t, T> FusedIterator for ChunksByPoints<'t, T>
+    where T: FiniteIndex,
+{}
@@ -0,0 +1,329 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides lazily evaluated set expressions over selections and intervals.
+//!
+//! Combining selections with [`Selection::union`] and the like materializes
+//! a temporary `Selection` at each step. A [`SetExpr`] instead records the
+//! expression, and evaluates it by stepping through the bounds of all of its
+//! leaves together, so that no intermediate results are stored.
+//!
+//! [`Selection::union`]: ../selection/struct.Selection.html#method.union
+//! [`SetExpr`]: struct.SetExpr.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::FiniteIndex;
+use crate::selection::Selection;
+use crate::selection;
+use crate::stream::ChangePoints;
+use crate::stream::Operand;
+use crate::stream::next_interval;
+
+// Standard library imports.
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SetExpr
+////////////////////////////////////////////////////////////////////////////////
+/// A lazily evaluated set expression over borrowed `Selection`s and
+/// `Interval`s.
+///
+/// Point queries cost O(k log n) for an expression with k leaves of at most
+/// n intervals each, and do not allocate. Iterating over the result advances
+/// a cursor over each leaf in a single forward pass, so it costs O(k) for
+/// each of the O(kn) leaf bounds, and allocates only the cursors.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::set_expr::SetExpr;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a: Selection<i32> = Interval::closed(0, 10).into();
+/// let b: Selection<i32> = Interval::closed(20, 30).into();
+/// let c: Selection<i32> = Interval::closed(5, 25).into();
+/// let d: Selection<i32> = Interval::closed(8, 22).into();
+///
+/// // (a ∪ b) ∩ ¬(c ∖ d)
+/// let expr = SetExpr::from(&a)
+///     .union(&b)
+///     .intersect(SetExpr::from(&c).minus(&d).complement());
+///
+/// assert!(expr.contains(&10));
+/// assert!(!expr.contains(&6));
+/// assert_eq!(expr.count(), 16);
+///
+/// let sel: Selection<i32> = expr.interval_iter().collect();
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(0, 4),
+///     Interval::closed(8, 10),
+///     Interval::closed(20, 22),
+///     Interval::closed(26, 30),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SetExpr<'s, T>(Node<'s, T>);
+
+/// A node of a `SetExpr` tree.
+#[derive(Debug, Clone)]
+enum Node<'s, T> {
+    Selection(&'s Selection<T>),
+    Interval(Interval<T>),
+    Union(Box<Node<'s, T>>, Box<Node<'s, T>>),
+    Intersect(Box<Node<'s, T>>, Box<Node<'s, T>>),
+    Minus(Box<Node<'s, T>>, Box<Node<'s, T>>),
+    Complement(Box<Node<'s, T>>),
+}
+
+impl<'s, T> SetExpr<'s, T> where T: Ord + Clone + Finite {
+    ////////////////////////////////////////////////////////////////////////////
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a `SetExpr` of the given `Selection`.
+    pub fn selection(selection: &'s Selection<T>) -> Self {
+        SetExpr(Node::Selection(selection))
+    }
+
+    /// Constructs a `SetExpr` of the given `Interval`.
+    pub fn interval(interval: Interval<T>) -> Self {
+        SetExpr(Node::Interval(interval))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `SetExpr` of the points in either expression.
+    pub fn union<E>(self, other: E) -> Self where E: Into<Self> {
+        SetExpr(Node::Union(Box::new(self.0), Box::new(other.into().0)))
+    }
+
+    /// Returns a `SetExpr` of the points in both expressions.
+    pub fn intersect<E>(self, other: E) -> Self where E: Into<Self> {
+        SetExpr(Node::Intersect(Box::new(self.0), Box::new(other.into().0)))
+    }
+
+    /// Returns a `SetExpr` of the points in the expression but not in the
+    /// other.
+    pub fn minus<E>(self, other: E) -> Self where E: Into<Self> {
+        SetExpr(Node::Minus(Box::new(self.0), Box::new(other.into().0)))
+    }
+
+    /// Returns a `SetExpr` of the points not in the expression.
+    pub fn complement(self) -> Self {
+        SetExpr(Node::Complement(Box::new(self.0)))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Queries
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the expression contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        self.0.contains(point)
+    }
+
+    /// Returns `true` if the expression contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.interval_iter().next().is_none()
+    }
+
+    /// Returns the number of points in the expression.
+    pub fn count(&self) -> u128 where T: FiniteIndex {
+        self.interval_iter().map(|interval| interval.point_count()).sum()
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s of the expression.
+    ///
+    /// The intervals are normalized, ordered, and separated by at least one
+    /// point, as in [`Selection::interval_iter`], so they can be collected
+    /// into a `Selection`.
+    ///
+    /// [`Selection::interval_iter`]: ../selection/struct.Selection.html#method.interval_iter
+    pub fn interval_iter(&self) -> IntervalIter<'_, 's, T> {
+        IntervalIter {
+            cursor: self.0.cursor(),
+            position: Some(T::MINIMUM),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'s, T> Node<'s, T> where T: Ord + Clone + Finite {
+    /// Returns `true` if the node contains the given point.
+    fn contains(&self, point: &T) -> bool {
+        use Node::*;
+        match self {
+            Selection(sel)     => sel.interval_containing(point).is_some(),
+            Interval(interval) => interval.contains(point),
+            Union(a, b)        => a.contains(point) || b.contains(point),
+            Intersect(a, b)    => a.contains(point) && b.contains(point),
+            Minus(a, b)        => a.contains(point) && !b.contains(point),
+            Complement(a)      => !a.contains(point),
+        }
+    }
+
+    /// Returns a `Cursor` positioned before the first point of the node.
+    fn cursor(&self) -> Cursor<'s, T> {
+        use Node::*;
+        match self {
+            Selection(sel)     => Cursor::Selection(
+                Operand::new(sel.interval_iter())),
+            Interval(interval) => Cursor::Interval(
+                Operand::new(Some(interval.clone()).into_iter())),
+            Union(a, b)        => Cursor::Union(
+                Box::new(a.cursor()),
+                Box::new(b.cursor())),
+            Intersect(a, b)    => Cursor::Intersect(
+                Box::new(a.cursor()),
+                Box::new(b.cursor())),
+            Minus(a, b)        => Cursor::Minus(
+                Box::new(a.cursor()),
+                Box::new(b.cursor())),
+            Complement(a)      => Cursor::Complement(Box::new(a.cursor())),
+        }
+    }
+}
+
+impl<'s, T> From<&'s Selection<T>> for SetExpr<'s, T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(selection: &'s Selection<T>) -> Self {
+        SetExpr::selection(selection)
+    }
+}
+
+impl<'s, T> From<Interval<T>> for SetExpr<'s, T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(interval: Interval<T>) -> Self {
+        SetExpr::interval(interval)
+    }
+}
+
+impl<'e, 's, T> IntoIterator for &'e SetExpr<'s, T>
+    where T: Ord + Clone + Finite,
+{
+    type Item = Interval<T>;
+    type IntoIter = IntervalIter<'e, 's, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.interval_iter()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Cursor
+////////////////////////////////////////////////////////////////////////////////
+/// A node of a `SetExpr` tree with a forward cursor over the intervals of each
+/// of its leaves.
+#[derive(Debug, Clone)]
+enum Cursor<'s, T> where T: Ord + Clone {
+    Selection(Operand<selection::IntervalIter<'s, T>, T>),
+    Interval(Operand<std::option::IntoIter<Interval<T>>, T>),
+    Union(Box<Cursor<'s, T>>, Box<Cursor<'s, T>>),
+    Intersect(Box<Cursor<'s, T>>, Box<Cursor<'s, T>>),
+    Minus(Box<Cursor<'s, T>>, Box<Cursor<'s, T>>),
+    Complement(Box<Cursor<'s, T>>),
+}
+
+impl<'s, T> ChangePoints<T> for Cursor<'s, T> where T: Ord + Clone + Finite {
+    fn contains(&mut self, point: &T) -> bool {
+        use Cursor::*;
+        // Both operands are always advanced, so that every leaf is at the
+        // point before its next change is queried.
+        match self {
+            Selection(leaf)    => leaf.seek(point),
+            Interval(leaf)     => leaf.seek(point),
+            Union(a, b)        => a.contains(point) | b.contains(point),
+            Intersect(a, b)    => a.contains(point) & b.contains(point),
+            Minus(a, b)        => a.contains(point) & !b.contains(point),
+            Complement(a)      => !a.contains(point),
+        }
+    }
+
+    fn may_contain(&self) -> bool {
+        use Cursor::*;
+        match self {
+            Selection(leaf)    => !leaf.is_exhausted(),
+            Interval(leaf)     => !leaf.is_exhausted(),
+            Union(a, b)        => a.may_contain() || b.may_contain(),
+            Intersect(a, b)    => a.may_contain() && b.may_contain(),
+            Minus(a, _)        => a.may_contain(),
+            Complement(_)      => true,
+        }
+    }
+
+    fn next_change(&self, point: &T) -> Option<T> {
+        use Cursor::*;
+        match self {
+            Selection(leaf)    => leaf.next_change(point),
+            Interval(leaf)     => leaf.next_change(point),
+            Union(a, b)
+            | Intersect(a, b)
+            | Minus(a, b)      => {
+                match (a.next_change(point), b.next_change(point)) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b)             => a.or(b),
+                }
+            },
+            Complement(a)      => a.next_change(point),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `SetExpr`.
+#[derive(Debug, Clone)]
+pub struct IntervalIter<'e, 's, T> where T: Ord + Clone {
+    cursor: Cursor<'s, T>,
+    /// The least point not yet visited, or `None` if every point has been
+    /// visited.
+    position: Option<T>,
+    /// The lifetime of the borrowed `SetExpr`.
+    marker: PhantomData<&'e SetExpr<'s, T>>,
+}
+
+impl<'e, 's, T> Iterator for IntervalIter<'e, 's, T>
+    where T: Ord + Clone + Finite,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.position.take()?;
+        let (interval, position) = next_interval(&mut self.cursor, start)?;
+        self.position = position;
+        Some(interval)
+    }
+}
+
+impl<'e, 's, T> FusedIterator for IntervalIter<'e, 's, T>
+    where T: Ord + Clone + Finite,
+{}
@@ -0,0 +1,471 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a selection which stores a few intervals inline.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::iter::Peekable;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SmallSelection<T, N>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of `Interval`s of the type `T`, which
+/// stores up to `N` intervals inline before spilling into a [`Selection`].
+///
+/// Most selections hold only a handful of intervals; a `SmallSelection` holds
+/// them without allocating. Constructing a `SmallSelection` from a single
+/// `Interval` never allocates, and set operations whose results fit within
+/// `N` intervals return to inline storage.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::small_selection::SmallSelection;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: SmallSelection<i32, 2> = Interval::closed(0, 4).into();
+/// assert!(sel.is_inline());
+///
+/// sel.union_in_place(Interval::closed(8, 12));
+/// assert!(sel.is_inline());
+///
+/// sel.union_in_place(Interval::closed(20, 24));
+/// assert!(!sel.is_inline());
+///
+/// sel.minus_in_place(Interval::closed(3, 30));
+/// assert!(sel.is_inline());
+/// assert_eq!(sel.iter().collect::<Vec<_>>(), [0, 1, 2]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SmallSelection<T, const N: usize = 4>(Storage<T, N>);
+
+/// The storage of a `SmallSelection`.
+#[derive(Debug, Clone)]
+enum Storage<T, const N: usize> {
+    /// The closed bounds of each interval, in ascending order, followed by
+    /// `None`s. The intervals are non-empty, disjoint, and non-adjacent.
+    Inline([Option<(T, T)>; N]),
+    /// A `Selection` holding more than `N` intervals.
+    Spilled(Selection<T>),
+}
+
+impl<T, const N: usize> SmallSelection<T, N> where T: Ord + Clone + Finite {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `SmallSelection`.
+    pub fn new() -> Self {
+        SmallSelection(Storage::Inline(std::array::from_fn(|_| None)))
+    }
+
+    /// Constructs a new `SmallSelection` containing every point.
+    pub fn full() -> Self {
+        SmallSelection::from(Interval::full())
+    }
+
+    /// Appends the given closed bounds, merging them with the last interval if
+    /// they overlap or are adjacent, and spilling if there is no room. The
+    /// bounds must not start before the last interval.
+    fn push(&mut self, lower: T, upper: T) {
+        let bounds = match &mut self.0 {
+            Storage::Inline(bounds) => bounds,
+            Storage::Spilled(sel)   => {
+                sel.union_in_place(Interval::closed(lower, upper));
+                return;
+            },
+        };
+
+        let len = bounds.iter().take_while(|b| b.is_some()).count();
+        if let Some((_, last)) = len.checked_sub(1)
+            .and_then(|i| bounds[i].as_mut())
+        {
+            if last.succ().map_or(true, |next| lower <= next) {
+                if upper > *last { *last = upper; }
+                return;
+            }
+        }
+        if len < N {
+            bounds[len] = Some((lower, upper));
+            return;
+        }
+
+        let mut sel: Selection<T> = self.interval_iter().collect();
+        sel.union_in_place(Interval::closed(lower, upper));
+        self.0 = Storage::Spilled(sel);
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the intervals of the `SmallSelection` are stored
+    /// inline.
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, Storage::Inline(_))
+    }
+
+    /// Returns `true` if the `SmallSelection` contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.interval_iter().next().is_none()
+    }
+
+    /// Returns `true` if the `SmallSelection` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        match &self.0 {
+            Storage::Inline(bounds) => bounds.iter()
+                .map_while(|b| b.as_ref())
+                .any(|(l, r)| l <= point && point <= r),
+            Storage::Spilled(sel)   => sel.contains(point),
+        }
+    }
+
+    /// Returns the greatest lower bound of the `SmallSelection`, or `None` if
+    /// it is empty.
+    pub fn infimum(&self) -> Option<T> {
+        self.interval_iter().next().and_then(|i| i.infimum())
+    }
+
+    /// Returns the least upper bound of the `SmallSelection`, or `None` if it
+    /// is empty.
+    pub fn supremum(&self) -> Option<T> {
+        self.interval_iter().last().and_then(|i| i.supremum())
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `SmallSelection` containing all points not in the
+    /// `SmallSelection`.
+    pub fn complement(&self) -> Self {
+        let mut complement = SmallSelection::new();
+        let mut next = Some(T::MINIMUM);
+        for (l, r) in self.bounds() {
+            if let Some(start) = next.take() {
+                if start < l {
+                    let end = l.pred().expect("predecessor of lower bound");
+                    complement.push(start, end);
+                }
+            }
+            next = r.succ();
+        }
+        if let Some(start) = next {
+            complement.push(start, T::MAXIMUM);
+        }
+        complement
+    }
+
+    /// Returns a `SmallSelection` containing all points in both
+    /// `SmallSelection`s.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut intersection = SmallSelection::new();
+        let mut left = self.bounds().peekable();
+        let mut right = other.bounds().peekable();
+        while let (Some((al, ar)), Some((bl, br))) = (left.peek(), right.peek())
+        {
+            let lower = std::cmp::max(al, bl);
+            let upper = std::cmp::min(ar, br);
+            if lower <= upper {
+                intersection.push(lower.clone(), upper.clone());
+            }
+            if ar <= br { let _ = left.next(); } else { let _ = right.next(); }
+        }
+        intersection
+    }
+
+    /// Returns a `SmallSelection` containing all points in either
+    /// `SmallSelection`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = SmallSelection::new();
+        let mut left = self.bounds().peekable();
+        let mut right = other.bounds().peekable();
+        while let Some((l, r)) = next_lower(&mut left, &mut right) {
+            union.push(l, r);
+        }
+        union
+    }
+
+    /// Returns a `SmallSelection` containing all points in the
+    /// `SmallSelection` which are not in the given `SmallSelection`.
+    pub fn minus(&self, other: &Self) -> Self {
+        self.intersect(&other.complement())
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the `SmallSelection` to only those points contained in the
+    /// given `Interval`.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        match &mut self.0 {
+            Storage::Spilled(sel) => {
+                sel.intersect_in_place(interval);
+                self.shrink_to_inline();
+            },
+            Storage::Inline(_)    =>
+                *self = self.intersect(&SmallSelection::from(interval)),
+        }
+    }
+
+    /// Adds all of the points in the given `Interval` to the
+    /// `SmallSelection`.
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        match &mut self.0 {
+            Storage::Spilled(sel) => sel.union_in_place(interval),
+            Storage::Inline(_)    =>
+                *self = self.union(&SmallSelection::from(interval)),
+        }
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `SmallSelection`.
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        match &mut self.0 {
+            Storage::Spilled(sel) => {
+                sel.minus_in_place(interval);
+                self.shrink_to_inline();
+            },
+            Storage::Inline(_)    =>
+                *self = self.minus(&SmallSelection::from(interval)),
+        }
+    }
+
+    /// Returns a spilled `SmallSelection` to inline storage if its intervals
+    /// fit within `N`.
+    fn shrink_to_inline(&mut self) {
+        if let Storage::Spilled(sel) = &self.0 {
+            if sel.interval_iter().nth(N).is_none() {
+                let mut inline = SmallSelection::new();
+                for (l, r) in self.bounds() { inline.push(l, r); }
+                *self = inline;
+            }
+        }
+    }
+
+    // Iterators
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the `Interval`s of the `SmallSelection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter(match &self.0 {
+            Storage::Inline(bounds) =>
+                IntervalIterStorage::Inline(bounds.iter()),
+            Storage::Spilled(sel)   =>
+                IntervalIterStorage::Spilled(sel.interval_iter()),
+        })
+    }
+
+    /// Returns an iterator over each of the points in the `SmallSelection`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            intervals: self.interval_iter(),
+            current: Interval::empty().iter(),
+        }
+    }
+
+    /// Returns an iterator over the closed bounds of each interval of the
+    /// `SmallSelection`.
+    fn bounds(&self) -> impl Iterator<Item=(T, T)> + '_ {
+        self.interval_iter()
+            .filter_map(|i| i.closed_bounds())
+    }
+}
+
+/// Returns the next closed bounds with the lowest lower bound from either
+/// iterator.
+fn next_lower<T, I, J>(left: &mut Peekable<I>, right: &mut Peekable<J>)
+    -> Option<(T, T)>
+    where
+        T: Ord,
+        I: Iterator<Item=(T, T)>,
+        J: Iterator<Item=(T, T)>,
+{
+    match (left.peek(), right.peek()) {
+        (Some((al, _)), Some((bl, _))) if bl < al => right.next(),
+        (Some(_), _) => left.next(),
+        (None, _)    => right.next(),
+    }
+}
+
+impl<T, const N: usize> Default for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{
+    fn default() -> Self {
+        SmallSelection::new()
+    }
+}
+
+impl<T, const N: usize> PartialEq for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bounds().eq(other.bounds())
+    }
+}
+
+impl<T, const N: usize> Eq for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{}
+
+impl<T, const N: usize> Extend<Interval<T>> for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        for interval in iter.into_iter() {
+            self.union_in_place(interval);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<Interval<T>> for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        let mut sel = SmallSelection::new();
+        sel.extend(iter);
+        sel
+    }
+}
+
+impl<T, const N: usize> From<Interval<T>> for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{
+    fn from(interval: Interval<T>) -> Self {
+        let mut sel = SmallSelection::new();
+        if let (Some(l), Some(r)) = (interval.infimum(), interval.supremum()) {
+            if l <= r { sel.push(l, r); }
+        }
+        sel
+    }
+}
+
+impl<T, const N: usize> From<Selection<T>> for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{
+    fn from(selection: Selection<T>) -> Self {
+        if selection.interval_iter().nth(N).is_some() {
+            return SmallSelection(Storage::Spilled(selection));
+        }
+        let mut sel = SmallSelection::new();
+        for interval in selection.interval_iter() {
+            let (l, r) = (interval.infimum(), interval.supremum());
+            if let (Some(l), Some(r)) = (l, r) { sel.push(l, r); }
+        }
+        sel
+    }
+}
+
+impl<T, const N: usize> From<SmallSelection<T, N>> for Selection<T>
+    where T: Ord + Clone + Finite,
+{
+    fn from(small: SmallSelection<T, N>) -> Self {
+        match small.0 {
+            Storage::Spilled(sel) => sel,
+            Storage::Inline(_)    => small.interval_iter().collect(),
+        }
+    }
+}
+
+impl<T, const N: usize> IntervalSet<T> for SmallSelection<T, N>
+    where T: Ord + Clone + Finite,
+{
+    type Output = SmallSelection<T, N>;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        SmallSelection::interval_iter(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        SmallSelection::is_empty(self)
+    }
+
+    fn contains(&self, point: &T) -> bool {
+        SmallSelection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self::Output {
+        SmallSelection::complement(self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `SmallSelection`.
+#[derive(Debug)]
+pub struct IntervalIter<'t, T>(IntervalIterStorage<'t, T>)
+    where T: Ord + Clone + Finite;
+
+/// The underlying iterator of an `IntervalIter`.
+#[derive(Debug)]
+enum IntervalIterStorage<'t, T> where T: Ord + Clone + Finite {
+    /// An iterator over inline intervals.
+    Inline(std::slice::Iter<'t, Option<(T, T)>>),
+    /// An iterator over spilled intervals.
+    Spilled(crate::selection::IntervalIter<'t, T>),
+}
+
+impl<'t, T> Iterator for IntervalIter<'t, T> where T: Ord + Clone + Finite {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IntervalIterStorage::Inline(bounds) => bounds.next()?
+                .as_ref()
+                .map(|(l, r)| Interval::closed(l.clone(), r.clone())),
+            IntervalIterStorage::Spilled(iter)  => iter.next(),
+        }
+    }
+}
+
+impl<'t, T> FusedIterator for IntervalIter<'t, T>
+    where T: Ord + Clone + Finite,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points of a `SmallSelection`.
+#[derive(Debug)]
+pub struct Iter<'t, T> where T: Ord + Clone + Finite {
+    intervals: IntervalIter<'t, T>,
+    current: crate::interval::Iter<T>,
+}
+
+impl<'t, T> Iterator for Iter<'t, T> where T: Ord + Clone + Finite {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(next) = self.current.next() {
+            return Some(next);
+        }
+        self.current = self.intervals.next()?.iter();
+        self.current.next()
+    }
+}
+
+impl<'t, T> FusedIterator for Iter<'t, T> where T: Ord + Clone + Finite {}
@@ -0,0 +1,504 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides set operations over sorted streams of intervals.
+//!
+//! The adaptors in this module combine `Iterator`s of [`Interval`]s sorted by
+//! their lower bounds, holding only the current interval of each input. They
+//! are useful for interval data which is too large to collect into a
+//! [`Selection`]. Each adaptor yields normalized intervals in order, separated
+//! by at least one point, as [`Selection::interval_iter`] does.
+//!
+//! [`Interval`]: ../interval/struct.Interval.html
+//! [`Selection`]: ../selection/struct.Selection.html
+//! [`Selection::interval_iter`]: ../selection/struct.Selection.html#method.interval_iter
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+
+// Standard library imports.
+use std::iter::FusedIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// normalize_stream
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator merging the overlapping and adjacent intervals of the
+/// given stream, which must be sorted by lower bound. Empty intervals are
+/// skipped.
+///
+/// This is the streaming analogue of `RawInterval::union_all`.
+///
+/// # Panics
+///
+/// In debug builds, the iterator panics if the stream is not sorted by lower
+/// bound.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::stream::normalize_stream;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let intervals = vec![
+///     Interval::closed(0, 4),
+///     Interval::closed(2, 6),
+///     Interval::empty(),
+///     Interval::closed(7, 9),
+///     Interval::point(12),
+/// ];
+///
+/// assert_eq!(normalize_stream(intervals).collect::<Vec<_>>(),
+///     [Interval::closed(0, 9), Interval::point(12)]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn normalize_stream<I, T>(intervals: I) -> NormalizeStream<I::IntoIter, T>
+    where
+        I: IntoIterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    NormalizeStream {
+        intervals: intervals.into_iter(),
+        pending: None,
+    }
+}
+
+/// An `Iterator` merging the overlapping and adjacent intervals of a sorted
+/// stream. Returned by [`normalize_stream`].
+///
+/// [`normalize_stream`]: fn.normalize_stream.html
+#[derive(Debug, Clone)]
+pub struct NormalizeStream<I, T> {
+    intervals: I,
+    /// The bounds of the merged interval not yet yielded, if any.
+    pending: Option<(T, T)>,
+}
+
+impl<I, T> Iterator for NormalizeStream<I, T>
+    where
+        I: Iterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for interval in &mut self.intervals {
+            let (l, r) = match (interval.infimum(), interval.supremum()) {
+                (Some(l), Some(r)) => (l, r),
+                _                  => continue,
+            };
+            let (lower, upper) = match self.pending.take() {
+                Some(pending) => pending,
+                None          => { self.pending = Some((l, r)); continue; },
+            };
+            debug_assert!(lower <= l, "interval stream is not sorted");
+
+            // Merge the interval if it overlaps or is adjacent.
+            if upper.succ().map_or(true, |next| l <= next) {
+                self.pending = Some((lower, upper.max(r)));
+            } else {
+                self.pending = Some((l, r));
+                return Some(Interval::closed(lower, upper));
+            }
+        }
+        self.pending.take().map(|(l, r)| Interval::closed(l, r))
+    }
+}
+
+impl<I, T> FusedIterator for NormalizeStream<I, T>
+    where
+        I: FusedIterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Binary set operations
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator over the union of the given sorted interval streams.
+///
+/// Each stream must be sorted by lower bound, but may contain overlapping,
+/// adjacent, or empty intervals.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::stream::union;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = vec![Interval::closed(0, 4), Interval::closed(10, 14)];
+/// let b = vec![Interval::closed(5, 7), Interval::closed(20, 24)];
+///
+/// assert_eq!(union(a, b).collect::<Vec<_>>(), [
+///     Interval::closed(0, 7),
+///     Interval::closed(10, 14),
+///     Interval::closed(20, 24),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn union<A, B, T>(a: A, b: B) -> SetOpStream<A::IntoIter, B::IntoIter, T>
+    where
+        A: IntoIterator<Item=Interval<T>>,
+        B: IntoIterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    SetOpStream::new(SetOp::Union, a, b)
+}
+
+/// Returns an iterator over the intersection of the given sorted interval
+/// streams.
+///
+/// Each stream must be sorted by lower bound, but may contain overlapping,
+/// adjacent, or empty intervals.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::stream::intersect;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = vec![Interval::closed(0, 4), Interval::closed(10, 14)];
+/// let b = vec![Interval::closed(3, 11)];
+///
+/// assert_eq!(intersect(a, b).collect::<Vec<_>>(),
+///     [Interval::closed(3, 4), Interval::closed(10, 11)]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn intersect<A, B, T>(a: A, b: B)
+    -> SetOpStream<A::IntoIter, B::IntoIter, T>
+    where
+        A: IntoIterator<Item=Interval<T>>,
+        B: IntoIterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    SetOpStream::new(SetOp::Intersect, a, b)
+}
+
+/// Returns an iterator over the points of the first sorted interval stream
+/// which are not in the second.
+///
+/// Each stream must be sorted by lower bound, but may contain overlapping,
+/// adjacent, or empty intervals.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::stream::minus;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = vec![Interval::closed(0, 14)];
+/// let b = vec![Interval::closed(3, 5), Interval::closed(10, 20)];
+///
+/// assert_eq!(minus(a, b).collect::<Vec<_>>(), [
+///     Interval::closed(0, 2),
+///     Interval::closed(6, 9),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn minus<A, B, T>(a: A, b: B) -> SetOpStream<A::IntoIter, B::IntoIter, T>
+    where
+        A: IntoIterator<Item=Interval<T>>,
+        B: IntoIterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    SetOpStream::new(SetOp::Minus, a, b)
+}
+
+/// Returns an iterator over the points in exactly one of the given sorted
+/// interval streams.
+///
+/// Each stream must be sorted by lower bound, but may contain overlapping,
+/// adjacent, or empty intervals.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::stream::symmetric_difference;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = vec![Interval::closed(0, 9)];
+/// let b = vec![Interval::closed(5, 14)];
+///
+/// assert_eq!(symmetric_difference(a, b).collect::<Vec<_>>(),
+///     [Interval::closed(0, 4), Interval::closed(10, 14)]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn symmetric_difference<A, B, T>(a: A, b: B)
+    -> SetOpStream<A::IntoIter, B::IntoIter, T>
+    where
+        A: IntoIterator<Item=Interval<T>>,
+        B: IntoIterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    SetOpStream::new(SetOp::SymmetricDifference, a, b)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SetOp
+////////////////////////////////////////////////////////////////////////////////
+/// A set operation combining two interval streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetOp {
+    /// Points in either stream.
+    Union,
+    /// Points in both streams.
+    Intersect,
+    /// Points in the first stream but not the second.
+    Minus,
+    /// Points in exactly one of the streams.
+    SymmetricDifference,
+}
+
+impl SetOp {
+    /// Returns whether a point is in the result, given whether it is in each
+    /// of the streams.
+    pub fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            SetOp::Union               => a || b,
+            SetOp::Intersect           => a && b,
+            SetOp::Minus               => a && !b,
+            SetOp::SymmetricDifference => a != b,
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SetOpStream
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over a set operation of two sorted interval streams.
+/// Returned by [`union`], [`intersect`], [`minus`], and
+/// [`symmetric_difference`].
+///
+/// [`union`]: fn.union.html
+/// [`intersect`]: fn.intersect.html
+/// [`minus`]: fn.minus.html
+/// [`symmetric_difference`]: fn.symmetric_difference.html
+#[derive(Debug, Clone)]
+pub struct SetOpStream<A, B, T> {
+    op: SetOp,
+    a: Operand<A, T>,
+    b: Operand<B, T>,
+    /// The least point not yet visited, or `None` if every point has been
+    /// visited.
+    position: Option<T>,
+}
+
+impl<A, B, T> SetOpStream<A, B, T>
+    where
+        A: Iterator<Item=Interval<T>>,
+        B: Iterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    /// Constructs a new `SetOpStream` applying the operation to the streams.
+    pub fn new<IA, IB>(op: SetOp, a: IA, b: IB) -> Self
+        where
+            IA: IntoIterator<Item=Interval<T>, IntoIter=A>,
+            IB: IntoIterator<Item=Interval<T>, IntoIter=B>,
+    {
+        SetOpStream {
+            op,
+            a: Operand::new(a.into_iter()),
+            b: Operand::new(b.into_iter()),
+            position: Some(T::MINIMUM),
+        }
+    }
+
+    /// Returns the `SetOp` applied to the streams.
+    pub fn op(&self) -> SetOp {
+        self.op
+    }
+
+}
+
+impl<A, B, T> ChangePoints<T> for SetOpStream<A, B, T>
+    where
+        A: Iterator<Item=Interval<T>>,
+        B: Iterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    fn contains(&mut self, point: &T) -> bool {
+        let a = self.a.seek(point);
+        let b = self.b.seek(point);
+        self.op.apply(a, b)
+    }
+
+    fn may_contain(&self) -> bool {
+        let a_live = !self.a.is_exhausted();
+        let b_live = !self.b.is_exhausted();
+        [(false, false), (true, false), (false, true), (true, true)]
+            .iter()
+            .any(|&(a, b)| {
+                (a_live || !a) && (b_live || !b) && self.op.apply(a, b)
+            })
+    }
+
+    fn next_change(&self, point: &T) -> Option<T> {
+        match (self.a.next_change(point), self.b.next_change(point)) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b)             => a.or(b),
+        }
+    }
+}
+
+impl<A, B, T> Iterator for SetOpStream<A, B, T>
+    where
+        A: Iterator<Item=Interval<T>>,
+        B: Iterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.position.take()?;
+        let (interval, position) = next_interval(self, start)?;
+        self.position = position;
+        Some(interval)
+    }
+}
+
+impl<A, B, T> FusedIterator for SetOpStream<A, B, T>
+    where
+        A: Iterator<Item=Interval<T>>,
+        B: Iterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ChangePoints
+////////////////////////////////////////////////////////////////////////////////
+/// A set of points combined from sorted inputs, which is visited in increasing
+/// order at the points where its inputs change.
+pub(in crate) trait ChangePoints<T> {
+    /// Returns whether the given point is in the set, advancing the inputs
+    /// past any intervals before it.
+    fn contains(&mut self, point: &T) -> bool;
+
+    /// Returns whether any point after the current intervals of the inputs
+    /// may be in the set.
+    fn may_contain(&self) -> bool;
+
+    /// Returns the first point after the given one at which any input starts
+    /// or stops containing points.
+    fn next_change(&self, point: &T) -> Option<T>;
+}
+
+/// Returns the first interval of the set which starts at or after the given
+/// point, along with the least point after it which is not yet visited, or
+/// `None` if every point has been visited.
+pub(in crate) fn next_interval<S, T>(set: &mut S, start: T)
+    -> Option<(Interval<T>, Option<T>)>
+    where
+        S: ChangePoints<T>,
+        T: Ord + Clone + Finite,
+{
+    let mut point = start;
+    while !set.contains(&point) {
+        if !set.may_contain() { return None; }
+        point = set.next_change(&point)?;
+    }
+
+    // Extend the interval over changes which keep the point contained.
+    let lower = point.clone();
+    loop {
+        match set.next_change(&point) {
+            Some(next) if set.contains(&next) => point = next,
+            Some(next) => {
+                let upper = next.pred().expect("change follows a point");
+                return Some((Interval::closed(lower, upper), Some(next)));
+            },
+            None => return Some((Interval::closed(lower, T::MAXIMUM), None)),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Operand
+////////////////////////////////////////////////////////////////////////////////
+/// A normalized input stream of a `SetOpStream` or `SetExpr`.
+#[derive(Debug, Clone)]
+pub(in crate) struct Operand<I, T> {
+    intervals: NormalizeStream<I, T>,
+    /// The bounds of the first interval which does not end before the last
+    /// visited point, or `None` if the stream is exhausted.
+    current: Option<(T, T)>,
+}
+
+impl<I, T> Operand<I, T>
+    where
+        I: Iterator<Item=Interval<T>>,
+        T: Ord + Clone + Finite,
+{
+    /// Constructs a new `Operand` from the given stream.
+    pub(in crate) fn new(intervals: I) -> Self {
+        let mut intervals = normalize_stream(intervals);
+        let current = intervals.next().and_then(|i| i.closed_bounds());
+        Operand { intervals, current }
+    }
+
+    /// Returns `true` if no interval of the stream ends at or after the last
+    /// visited point.
+    pub(in crate) fn is_exhausted(&self) -> bool {
+        self.current.is_none()
+    }
+
+    /// Advances past the intervals ending before the given point, and returns
+    /// whether the stream contains it.
+    pub(in crate) fn seek(&mut self, point: &T) -> bool {
+        while let Some((_, r)) = &self.current {
+            if r >= point { break; }
+            self.current = self.intervals
+                .next()
+                .and_then(|i| i.closed_bounds());
+        }
+        match &self.current {
+            Some((l, _)) => l <= point,
+            None         => false,
+        }
+    }
+
+    /// Returns the first point after the given one at which the stream starts
+    /// or stops containing points.
+    pub(in crate) fn next_change(&self, point: &T) -> Option<T> {
+        let (l, r) = self.current.as_ref()?;
+        if point < l {
+            Some(l.clone())
+        } else if point <= r {
+            r.succ()
+        } else {
+            None
+        }
+    }
+}
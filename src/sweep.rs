@@ -0,0 +1,183 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a sweep-line for combining many selections at once.
+//!
+//! Rather than folding selections together pairwise, [`sweep`] visits the
+//! bounds of every selection in order, tracking which of them cover the
+//! current point. A predicate on that [`Cover`] decides which points belong
+//! to the result, so thresholds such as "covered by at least 3 of these 20
+//! selections" are computed in a single pass.
+//!
+//! [`sweep`]: fn.sweep.html
+//! [`Cover`]: struct.Cover.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Cover
+////////////////////////////////////////////////////////////////////////////////
+/// The set of selections covering a point during a [`sweep`].
+///
+/// [`sweep`]: fn.sweep.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cover<'c> {
+    /// The indices of the covering selections.
+    indices: &'c BTreeSet<usize>,
+    /// The total number of selections being swept.
+    total: usize,
+}
+
+impl<'c> Cover<'c> {
+    /// Returns the number of selections covering the point.
+    pub fn count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns the total number of selections being swept.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns `true` if the selection with the given index covers the point.
+    pub fn contains(&self, index: usize) -> bool {
+        self.indices.contains(&index)
+    }
+
+    /// Returns the indices of the selections covering the point.
+    pub fn indices(&self) -> &'c BTreeSet<usize> {
+        self.indices
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Predicates
+////////////////////////////////////////////////////////////////////////////////
+/// Returns a predicate accepting points covered by at least `k` selections.
+pub fn threshold(k: usize) -> impl Fn(&Cover<'_>) -> bool {
+    move |cover| cover.count() >= k
+}
+
+/// Returns a predicate accepting points covered by exactly one selection.
+pub fn exactly_one() -> impl Fn(&Cover<'_>) -> bool {
+    |cover| cover.count() == 1
+}
+
+/// Returns a predicate accepting points covered by more than half of the
+/// selections.
+pub fn majority() -> impl Fn(&Cover<'_>) -> bool {
+    |cover| 2 * cover.count() > cover.total()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// sweep
+////////////////////////////////////////////////////////////////////////////////
+/// Returns a `Selection` of the points whose [`Cover`] by the given
+/// selections satisfies the predicate.
+///
+/// Selections are indexed in iteration order. The predicate is evaluated once
+/// for each distinct cover encountered, including the empty cover of points
+/// outside every selection, so this runs in O(n log n) for n intervals in
+/// total.
+///
+/// [`Cover`]: struct.Cover.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::sweep::{sweep, threshold, exactly_one};
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let selections: Vec<Selection<i32>> = vec![
+///     Interval::closed(0, 10).into(),
+///     Interval::closed(5, 15).into(),
+///     Interval::closed(8, 20).into(),
+/// ];
+///
+/// let twice = sweep(&selections, threshold(2));
+/// assert_eq!(twice.interval_iter().collect::<Vec<_>>(),
+///     [Interval::closed(5, 15)]);
+///
+/// let once = sweep(&selections, exactly_one());
+/// assert_eq!(once.interval_iter().collect::<Vec<_>>(),
+///     [Interval::closed(0, 4), Interval::closed(16, 20)]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn sweep<'s, T, I, P>(selections: I, mut predicate: P) -> Selection<T>
+    where
+        T: Ord + Clone + Finite + 's,
+        I: IntoIterator<Item=&'s Selection<T>>,
+        P: FnMut(&Cover<'_>) -> bool,
+{
+    // Each event marks a selection entering (`true`) or leaving (`false`)
+    // the cover at a point. The minimum is always visited so that the empty
+    // cover before the first interval is tested.
+    let mut events: BTreeMap<T, Vec<(usize, bool)>> = BTreeMap::new();
+    let _ = events.insert(T::MINIMUM, Vec::new());
+    let mut total = 0;
+    for (index, selection) in selections.into_iter().enumerate() {
+        total += 1;
+        for interval in selection.interval_iter() {
+            let (l, r) = match (interval.infimum(), interval.supremum()) {
+                (Some(l), Some(r)) if l <= r => (l, r),
+                _                            => continue,
+            };
+            if let Some(end) = r.succ() {
+                events.entry(end).or_default().push((index, false));
+            }
+            events.entry(l).or_default().push((index, true));
+        }
+    }
+
+    let mut active = BTreeSet::new();
+    let mut start: Option<T> = None;
+    let mut result = Vec::new();
+    for (point, changes) in events {
+        for (index, entering) in changes {
+            if entering {
+                let _ = active.insert(index);
+            } else {
+                let _ = active.remove(&index);
+            }
+        }
+
+        let accepted = predicate(&Cover { indices: &active, total });
+        match (accepted, start.take()) {
+            (true, None)         => start = Some(point),
+            (true, Some(l))      => start = Some(l),
+            (false, Some(l))     => {
+                let r = point.pred().expect("predecessor of event point");
+                result.push(Interval::closed(l, r));
+            },
+            (false, None)        => (),
+        }
+    }
+    if let Some(l) = start {
+        result.push(Interval::closed(l, T::MAXIMUM));
+    }
+
+    result.into_iter().collect()
+}
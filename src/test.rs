@@ -11,6 +11,12 @@
 //!
 ////////////////////////////////////////////////////////////////////////////////
 
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
 // Needs to be defined before submodule declarations.
 
 /// Variant of `assert_eq` that collects items into a HashSet before comparing.
@@ -38,6 +44,70 @@ macro_rules! assert_eq_i {
     })
 }
 
+/// A linear congruential generator for reproducible pseudo-random test data.
+#[derive(Debug, Clone)]
+struct Lcg(u32);
+
+impl Lcg {
+    /// Constructs a new `Lcg` from the given seed.
+    fn new(seed: u32) -> Self {
+        Lcg(seed)
+    }
+
+    /// Returns the next pseudo-random value less than `m`.
+    fn next(&mut self, m: u32) -> u32 {
+        self.0 = self.0.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (self.0 >> 16) % m
+    }
+}
+
+/// Returns a `Selection` of the given intervals.
+fn selection<T>(intervals: &[Interval<T>]) -> Selection<T>
+    where T: Ord + Clone + Finite
+{
+    intervals.iter().cloned().collect()
+}
+
+/// Returns the bounds of each interval of the set. `Interval` equality
+/// distinguishes a point from a closed interval with equal bounds, so these
+/// are compared instead.
+fn intervals<T, S>(set: &S) -> Vec<(Option<T>, Option<T>)>
+    where
+        T: Ord + Clone + Finite,
+        S: IntervalSet<T>,
+{
+    set.interval_iter().map(|i| (i.infimum(), i.supremum())).collect()
+}
+
+/// Returns the intervals of the first operand of the set operation tests.
+fn a() -> [Interval<i32>; 3] {
+    [Interval::closed(-10, -5), Interval::closed(0, 4), Interval::closed(8, 20)]
+}
+
+/// Returns the intervals of the second operand of the set operation tests.
+fn b() -> [Interval<i32>; 3] {
+    [Interval::closed(-7, 1), Interval::closed(3, 3), Interval::closed(15, 30)]
+}
+
 // Module declarations.
+mod allocator;
+mod arbitrary;
+mod bit_selection;
+mod calendar;
+mod datetime;
+mod frozen_selection;
+mod interval;
+mod interval_counter;
+mod interval_set;
+mod model;
+mod parallel;
+mod persistent_selection;
 mod raw_interval;
+mod recurrence;
+mod roaring_selection;
+mod selection;
+mod set_expr;
+mod small_selection;
+mod stream;
+mod sweep;
 mod tine_tree;
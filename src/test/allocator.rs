@@ -0,0 +1,174 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`RangeAllocator`].
+//!
+//! [`RangeAllocator`]: ../../allocator/struct.RangeAllocator.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::allocator::AllocError;
+use crate::allocator::FitPolicy;
+use crate::allocator::RangeAllocator;
+use crate::interval::Interval;
+use crate::test::Lcg;
+
+// Standard library imports.
+use std::collections::BTreeSet;
+
+
+/// Returns an allocator over [0, 99] with [10, 19], [30, 59], and [70, 79]
+/// allocated, leaving free ranges of length 10, 10, 10, and 20.
+fn fragmented(policy: FitPolicy) -> RangeAllocator {
+    let space = Interval::closed(0, 99);
+    let mut alloc = RangeAllocator::with_policy(space, policy);
+    for range in &[(10, 19), (30, 59), (70, 79)] {
+        alloc.allocate_at(Interval::closed(range.0, range.1)).unwrap();
+    }
+    alloc
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Policy tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn first_fit() {
+    let mut alloc = fragmented(FitPolicy::FirstFit);
+    assert_eq!(alloc.allocate(5), Ok(Interval::closed(0, 4)));
+    assert_eq!(alloc.allocate(12), Ok(Interval::closed(80, 91)));
+    assert_eq!(alloc.allocate(5), Ok(Interval::closed(5, 9)));
+    assert_eq!(alloc.allocate(11), Err(AllocError::OutOfSpace));
+}
+
+#[test]
+fn best_fit() {
+    let mut alloc = fragmented(FitPolicy::BestFit);
+    alloc.free(Interval::closed(30, 34)).unwrap();
+    // Free ranges are now [0, 9], [20, 34], [60, 69], [80, 99].
+    assert_eq!(alloc.allocate(12), Ok(Interval::closed(20, 31)));
+    assert_eq!(alloc.allocate(3), Ok(Interval::closed(32, 34)));
+    assert_eq!(alloc.allocate(10), Ok(Interval::closed(0, 9)));
+    assert_eq!(alloc.allocate(11), Ok(Interval::closed(80, 90)));
+}
+
+#[test]
+fn next_fit() {
+    let mut alloc = fragmented(FitPolicy::NextFit);
+    assert_eq!(alloc.allocate(4), Ok(Interval::closed(0, 3)));
+    assert_eq!(alloc.allocate(4), Ok(Interval::closed(4, 7)));
+    assert_eq!(alloc.allocate(4), Ok(Interval::closed(20, 23)));
+    assert_eq!(alloc.allocate(15), Ok(Interval::closed(80, 94)));
+    assert_eq!(alloc.allocate(5), Ok(Interval::closed(95, 99)));
+    // Wraps around to the start of the space.
+    assert_eq!(alloc.allocate(2), Ok(Interval::closed(8, 9)));
+    assert_eq!(alloc.allocate(5), Ok(Interval::closed(24, 28)));
+}
+
+#[test]
+fn aligned() {
+    let mut alloc = fragmented(FitPolicy::FirstFit);
+    assert_eq!(alloc.allocate_aligned(4, 8), Ok(Interval::closed(0, 3)));
+    assert_eq!(alloc.allocate_aligned(4, 8), Ok(Interval::closed(24, 27)));
+    assert_eq!(alloc.allocate_aligned(8, 16), Ok(Interval::closed(80, 87)));
+    assert_eq!(alloc.allocate_aligned(4, 0), Err(AllocError::ZeroAlignment));
+    assert_eq!(alloc.allocate(0), Err(AllocError::ZeroLength));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Free tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn free_coalesces() {
+    let mut alloc = fragmented(FitPolicy::FirstFit);
+    assert_eq!(alloc.stats().fragments, 4);
+
+    alloc.free(Interval::closed(10, 19)).unwrap();
+    alloc.free(Interval::closed(30, 59)).unwrap();
+    assert_eq!(alloc.free_space().interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 69),
+        Interval::closed(80, 99),
+    ]);
+
+    alloc.free(Interval::closed(70, 79)).unwrap();
+    assert!(alloc.is_free(alloc.space()));
+    assert_eq!(alloc.stats().fragmentation(), 0.0);
+}
+
+#[test]
+fn free_errors() {
+    let mut alloc = fragmented(FitPolicy::FirstFit);
+    let before = alloc.clone();
+
+    assert_eq!(alloc.free(Interval::closed(15, 25)),
+        Err(AllocError::DoubleFree));
+    assert_eq!(alloc.free(Interval::point(5)), Err(AllocError::DoubleFree));
+    assert_eq!(alloc.free(Interval::closed(90, 100)),
+        Err(AllocError::OutOfRange));
+    assert_eq!(alloc.allocate_at(Interval::closed(5, 10)),
+        Err(AllocError::Unavailable));
+    assert_eq!(alloc.allocate_at(Interval::closed(95, 105)),
+        Err(AllocError::OutOfRange));
+    assert_eq!(alloc, before);
+}
+
+#[test]
+fn stats() {
+    let alloc = fragmented(FitPolicy::FirstFit);
+    let stats = alloc.stats();
+    assert_eq!(stats.free, 50);
+    assert_eq!(stats.largest, 20);
+    assert_eq!(stats.fragments, 4);
+    assert!((stats.fragmentation() - 0.6).abs() < 1e-9);
+
+    let full = RangeAllocator::new(Interval::full());
+    assert_eq!(full.stats().free, 1 << 64);
+}
+
+#[test]
+fn matches_model() {
+    // Apply a pseudo-random sequence of allocations and frees to both an
+    // allocator and a set of allocated offsets.
+    let mut rng = Lcg::new(4242);
+    let mut next = move |m: u32| u64::from(rng.next(m));
+
+    for policy in [FitPolicy::FirstFit, FitPolicy::BestFit, FitPolicy::NextFit]
+    {
+        let mut alloc = RangeAllocator::with_policy(
+            Interval::closed(0, 255),
+            policy);
+        let mut live: Vec<Interval<u64>> = Vec::new();
+        let mut used = BTreeSet::new();
+        for _ in 0..400 {
+            if next(3) > 0 || live.is_empty() {
+                let (len, align) = (next(16) + 1, 1 << next(3));
+                match alloc.allocate_aligned(len, align) {
+                    Ok(range) => {
+                        assert_eq!(range.infimum().unwrap() % align, 0);
+                        assert_eq!(range.iter().count() as u64, len);
+                        for p in range.iter() { assert!(used.insert(p)); }
+                        live.push(range);
+                    },
+                    Err(e) => assert_eq!(e, AllocError::OutOfSpace),
+                }
+            } else {
+                let range = live.swap_remove(next(live.len() as u32) as usize);
+                alloc.free(range).unwrap();
+                for p in range.iter() { assert!(used.remove(&p)); }
+            }
+            let free: BTreeSet<u64> = alloc.free_space().iter().collect();
+            assert!(free.is_disjoint(&used));
+            assert_eq!(free.len() + used.len(), 256);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the [`arbitrary`] property testing generators.
+//!
+//! [`arbitrary`]: ../../arbitrary/index.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+
+/// Returns whether the intervals of the `Selection` are non-empty, ordered,
+/// and separated by at least one point.
+#[cfg(any(
+    feature = "proptest",
+    feature = "quickcheck",
+    feature = "arbitrary"))]
+fn is_normalized(sel: &crate::selection::Selection<i8>) -> bool {
+    let intervals: Vec<_> = sel.interval_iter().collect();
+    intervals.iter().all(|i| !i.is_empty())
+        && intervals.windows(2).all(|w| {
+            i16::from(w[0].supremum().unwrap()) + 1
+                < i16::from(w[1].infimum().unwrap())
+        })
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// proptest tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "proptest")]
+mod proptest {
+    // Local imports.
+    use crate::arbitrary::proptest::interval;
+    use crate::arbitrary::proptest::selection;
+    use crate::interval::Interval;
+    use crate::selection::Selection;
+    use super::is_normalized;
+
+    // External library imports.
+    use ::proptest::prelude::*;
+    use ::proptest::strategy::ValueTree;
+    use ::proptest::test_runner::TestError;
+    use ::proptest::test_runner::TestRunner;
+
+    #[test]
+    fn interval_covers_shapes() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = interval(-4i8..4);
+        let samples: Vec<Interval<i8>> = (0..2000)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect();
+
+        assert!(samples.iter().any(|i| i.is_empty()));
+        assert!(samples.iter().any(|i| i.iter().count() == 1));
+        assert!(samples.iter().any(|i| i.iter().count() > 1));
+        assert!(samples.iter().any(|i| i.infimum() == Some(i8::MIN)));
+        assert!(samples.iter().any(|i| i.supremum() == Some(i8::MAX)));
+        assert!(samples.iter().all(|i| Interval::from(i.0) == *i));
+    }
+
+    #[test]
+    fn selection_shrinks_to_fewer_intervals() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&any::<Selection<i8>>(), |sel| {
+            prop_assert!(sel.interval_iter().count() < 2);
+            Ok(())
+        });
+        match result {
+            Err(TestError::Fail(_, sel)) => {
+                assert_eq!(sel.interval_iter().count(), 2);
+                assert_eq!(sel.iter().count(), 2);
+            },
+            _ => panic!("expected a failing case"),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn selection_is_normalized(sel in selection(-20i8..20, 0..6)) {
+            prop_assert!(is_normalized(&sel));
+        }
+
+        #[test]
+        fn any_selection_is_normalized(sel in any::<Selection<i8>>()) {
+            prop_assert!(is_normalized(&sel));
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// quickcheck tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "quickcheck")]
+mod quickcheck {
+    // Local imports.
+    use crate::bound::Bound;
+    use crate::interval::Interval;
+    use crate::selection::Selection;
+    use super::is_normalized;
+
+    // External library imports.
+    use ::quickcheck::Arbitrary;
+    use ::quickcheck::Gen;
+    use ::quickcheck::QuickCheck;
+
+    #[test]
+    fn arbitrary_selection_is_normalized() {
+        let prop: fn(Selection<i8>) -> bool = |sel| is_normalized(&sel);
+        QuickCheck::new().quickcheck(prop);
+    }
+
+    #[test]
+    fn arbitrary_interval_covers_shapes() {
+        let mut g = Gen::new(8);
+        let samples: Vec<Interval<i8>> = (0..2000)
+            .map(|_| Interval::arbitrary(&mut g))
+            .collect();
+
+        assert!(samples.iter().any(|i| i.is_empty()));
+        assert!(samples.iter().any(|i| i.iter().count() == 1));
+        assert!(samples.iter().any(|i| i.iter().count() > 1));
+        assert!(samples.iter().any(|i| i.infimum() == Some(i8::MIN)));
+        assert!(samples.iter().any(|i| i.supremum() == Some(i8::MAX)));
+    }
+
+    #[test]
+    fn interval_shrinks_are_smaller() {
+        for interval in [
+            Interval::closed(-50i8, 90),
+            Interval::closed(3, 4),
+            Interval::point(100),
+            Interval::full(),
+        ] {
+            let count = interval.iter().count();
+            for shrunk in interval.shrink() {
+                let shrunk_count = shrunk.iter().count();
+                assert!(shrunk_count < count || (shrunk_count == 1
+                    && shrunk.infimum().unwrap().unsigned_abs()
+                        < interval.infimum().unwrap().unsigned_abs()));
+            }
+        }
+        assert_eq!(Interval::<i8>::empty().shrink().count(), 0);
+    }
+
+    #[test]
+    fn selection_shrinks_are_smaller() {
+        let sel: Selection<i8> = [
+            Interval::closed(-50, -20),
+            Interval::point(0),
+            Interval::closed(30, 90),
+        ].iter().cloned().collect();
+
+        let shrinks: Vec<Selection<i8>> = sel.shrink().collect();
+        assert!(!shrinks.is_empty());
+        for shrunk in shrinks {
+            assert!(is_normalized(&shrunk));
+            assert!(shrunk.interval_iter().count() <= 3);
+            assert!(shrunk.iter().count() <= sel.iter().count());
+        }
+    }
+
+    #[test]
+    fn bound_shrinks_to_include() {
+        assert_eq!(Bound::Exclude(5i8).shrink().next(),
+            Some(Bound::Include(5)));
+        assert_eq!(Bound::<i8>::Infinite.shrink().count(), 0);
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// arbitrary tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "arbitrary")]
+mod fuzz {
+    // Local imports.
+    use crate::arbitrary::fuzz::assert_canonical;
+    use crate::interval::Interval;
+    use crate::selection::Selection;
+    use crate::test::Lcg;
+    use super::is_normalized;
+
+    // External library imports.
+    use ::arbitrary::Arbitrary;
+    use ::arbitrary::Unstructured;
+
+    /// Returns reproducible pseudo-random bytes for the given seed.
+    fn bytes(seed: u32, len: usize) -> Vec<u8> {
+        let mut rng = Lcg::new(seed);
+        (0..len).map(|_| rng.next(256) as u8).collect()
+    }
+
+    #[test]
+    fn arbitrary_selection_is_canonical() {
+        for seed in 0..500 {
+            let data = bytes(seed, 64);
+            let sel = Selection::<i8>::arbitrary_take_rest(
+                Unstructured::new(&data)).unwrap();
+            assert!(is_normalized(&sel));
+            assert_canonical(&sel);
+            assert_canonical(&sel.complement());
+        }
+    }
+
+    #[test]
+    fn arbitrary_interval_covers_shapes() {
+        let data = bytes(7, 8000);
+        let mut u = Unstructured::new(&data);
+        let samples: Vec<Interval<i8>> = (0..2000)
+            .map(|_| Interval::arbitrary(&mut u).unwrap())
+            .collect();
+
+        assert!(samples.iter().any(|i| i.is_empty()));
+        assert!(samples.iter().any(|i| i.iter().count() == 1));
+        assert!(samples.iter().any(|i| i.iter().count() > 1));
+        assert!(samples.iter().any(|i| i.infimum() == Some(i8::MIN)));
+        assert!(samples.iter().any(|i| i.supremum() == Some(i8::MAX)));
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`BitSelection`].
+//!
+//! [`BitSelection`]: ../../bit_selection/struct.BitSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bit_selection::BitBudgetError;
+use crate::bit_selection::BitSelection;
+use crate::bit_selection::DEFAULT_BIT_BUDGET;
+use crate::interval::Interval;
+use crate::normalize::FiniteIndex;
+use crate::selection::Selection;
+use crate::test::intervals;
+use crate::test::selection;
+
+
+fn bits(intervals: &[Interval<i16>]) -> BitSelection<i16> {
+    intervals.iter().cloned().collect()
+}
+
+fn a() -> [Interval<i16>; 3] {
+    [
+        Interval::closed(-100, -5),
+        Interval::closed(0, 63),
+        Interval::closed(64, 200),
+    ]
+}
+
+fn b() -> [Interval<i16>; 3] {
+    [
+        Interval::closed(-70, 1),
+        Interval::point(130),
+        Interval::closed(150, 3000),
+    ]
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Index tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn finite_index_preserves_order() {
+    assert_eq!(i8::MIN.finite_index(), 0);
+    assert_eq!((-1i8).finite_index(), 127);
+    assert_eq!(0i8.finite_index(), 128);
+    assert_eq!(i8::MAX.finite_index(), 255);
+    assert_eq!(u16::MAX.finite_index(), BitSelection::<u16>::domain_size() - 1);
+
+    for i in i8::MIN..=i8::MAX {
+        assert_eq!(i8::from_finite_index(i.finite_index()), i);
+    }
+}
+
+#[test]
+fn budget() {
+    assert_eq!(BitSelection::<u16>::with_budget(1 << 8), Err(BitBudgetError {
+        required: 1 << 16,
+        budget: 1 << 8,
+    }));
+    assert!(BitSelection::<u8>::with_budget(256).is_ok());
+    assert!(BitSelection::<u8>::with_budget(255).is_err());
+}
+
+#[test]
+fn budget_beyond_default() {
+    assert_eq!(BitSelection::<u32>::with_budget(DEFAULT_BIT_BUDGET),
+        Err(BitBudgetError { required: 1 << 32, budget: DEFAULT_BIT_BUDGET }));
+    assert_eq!(BitSelection::<u64>::with_budget(u64::MAX), Err(BitBudgetError {
+        required: 1 << 64,
+        budget: u64::MAX,
+    }));
+
+    // A larger budget than the default does not change the domain.
+    let b = BitSelection::<u16>::with_budget(1 << 20).unwrap();
+    assert_eq!(b, BitSelection::new());
+    assert_eq!(b.complement().cardinality(), 1 << 16);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn selection_round_trip() {
+    let sel = selection(&a());
+    let b = BitSelection::from(sel.clone());
+
+    // Adjacent intervals are merged.
+    assert_eq!(intervals(&b), [(Some(-100), Some(-5)), (Some(0), Some(200))]);
+    assert_eq!(intervals(&Selection::from(b)), intervals(&sel));
+}
+
+#[test]
+fn intervals_at_extremes() {
+    let b: BitSelection<u8> = [
+        Interval::closed(0, 3),
+        Interval::point(64),
+        Interval::closed(250, 255),
+    ].iter().cloned().collect();
+
+    assert_eq!(b.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(0, 3),
+        Interval::closed(64, 64),
+        Interval::closed(250, 255),
+    ]);
+    assert_eq!(b.iter().collect::<Vec<_>>(),
+        [0, 1, 2, 3, 64, 250, 251, 252, 253, 254, 255]);
+    assert_eq!(b.infimum(), Some(0));
+    assert_eq!(b.supremum(), Some(255));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn contains_and_cardinality() {
+    let b = bits(&a());
+    for p in -120..220 {
+        let expected = a().iter().any(|i| i.contains(&p));
+        assert_eq!(b.contains(&p), expected, "point {}", p);
+    }
+    assert_eq!(b.cardinality(), 96 + 201);
+    assert_eq!(b.iter().count() as u64, b.cardinality());
+}
+
+#[test]
+fn empty_and_full() {
+    let empty = BitSelection::<u8>::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.infimum(), None);
+    assert_eq!(empty.supremum(), None);
+    assert_eq!(empty.interval_iter().next(), None);
+
+    let full = BitSelection::<u8>::full();
+    assert!(full.is_full());
+    assert_eq!(full.cardinality(), 256);
+    assert_eq!(full.interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(0, 255)]);
+    assert_eq!(full.complement(), empty);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Set operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn set_operations_match_selection() {
+    let (ba, bb) = (bits(&a()), bits(&b()));
+    let (sa, sb) = (selection(&a()), selection(&b()));
+
+    assert_eq!(intervals(&ba.union(&bb)), intervals(&sa.union(&sb)));
+    assert_eq!(intervals(&ba.minus(&bb)), intervals(&sa.minus(&sb)));
+    assert_eq!(intervals(&ba.complement()), intervals(&sa.complement()));
+    assert_eq!(intervals(&ba.intersect(&bb)), [
+        (Some(-70), Some(-5)),
+        (Some(0), Some(1)),
+        (Some(130), Some(130)),
+        (Some(150), Some(200)),
+    ]);
+}
+
+#[test]
+fn in_place_operations() {
+    let mut b = BitSelection::<u8>::new();
+    b.union_in_place(Interval::closed(10, 100));
+    b.minus_in_place(Interval::open(20, 30));
+    assert_eq!(b.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(10, 20),
+        Interval::closed(30, 100),
+    ]);
+
+    b.intersect_in_place(Interval::closed(15, 40));
+    assert_eq!(b.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(15, 20),
+        Interval::closed(30, 40),
+    ]);
+
+    b.intersect_in_place(Interval::empty());
+    assert!(b.is_empty());
+}
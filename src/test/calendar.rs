@@ -0,0 +1,169 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`BusinessCalendar`].
+//!
+//! [`BusinessCalendar`]: ../../calendar/struct.BusinessCalendar.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+#![cfg(feature = "chrono")]
+
+// Local imports.
+use crate::calendar::BusinessCalendar;
+use crate::calendar::Weekday;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+
+// External library imports.
+use ::chrono::NaiveDate;
+
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+/// Returns a calendar with the 2021 US market holidays in Q3.
+fn q3_calendar() -> BusinessCalendar<NaiveDate> {
+    let mut calendar = BusinessCalendar::new();
+    calendar.add_holiday(date(2021, 7, 5));
+    calendar.add_holiday(date(2021, 9, 6));
+    calendar
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn business_days_q3() {
+    let calendar = q3_calendar();
+    let q3 = Interval::closed(date(2021, 7, 1), date(2021, 9, 30));
+    let days = calendar.business_days(q3);
+
+    assert_eq!(days.iter().count(), 64);
+    assert_eq!(days.iter().next(), Some(date(2021, 7, 1)));
+    assert_eq!(days.iter().next_back(), Some(date(2021, 9, 30)));
+    assert!(!days.contains(&date(2021, 7, 5)));
+    assert!(!days.contains(&date(2021, 7, 10)));
+
+    // The first week is Thursday and Friday, with the holiday Monday removed.
+    assert_eq!(days.interval_iter().take(2).collect::<Vec<_>>(), [
+        Interval::closed(date(2021, 7, 1), date(2021, 7, 2)),
+        Interval::closed(date(2021, 7, 6), date(2021, 7, 9)),
+    ]);
+}
+
+#[test]
+fn business_days_custom_week() {
+    let calendar: BusinessCalendar<NaiveDate> = BusinessCalendar
+        ::with_working_days(vec![Weekday::Sunday, Weekday::Monday]);
+    let days = calendar.business_days(
+        Interval::closed(date(2021, 1, 1), date(2021, 1, 14)));
+
+    // Sunday and Monday are adjacent across the week boundary.
+    assert_eq!(days.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(date(2021, 1, 3), date(2021, 1, 4)),
+        Interval::closed(date(2021, 1, 10), date(2021, 1, 11)),
+    ]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Arithmetic tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn next_and_prev_business_day() {
+    let calendar = q3_calendar();
+
+    // Friday before a holiday Monday.
+    assert_eq!(calendar.next_business_day_after(&date(2021, 7, 2)),
+        Some(date(2021, 7, 6)));
+    assert_eq!(calendar.prev_business_day_before(&date(2021, 7, 6)),
+        Some(date(2021, 7, 2)));
+
+    let never: BusinessCalendar<NaiveDate> = BusinessCalendar
+        ::with_working_days(vec![]);
+    assert_eq!(never.next_business_day_after(&date(2021, 7, 2)), None);
+
+    // Holidays to the end of the date range are skipped as a single run.
+    let mut closed = calendar.clone();
+    closed.add_holidays(Interval::unbounded_from(date(2021, 1, 1)));
+    assert_eq!(closed.next_business_day_after(&date(2021, 7, 2)), None);
+    assert_eq!(closed.prev_business_day_before(&date(2021, 7, 2)),
+        Some(date(2020, 12, 31)));
+    assert_eq!(closed.next_business_day_after(&date(2020, 12, 30)),
+        Some(date(2020, 12, 31)));
+    assert_eq!(calendar.next_business_day_after(&NaiveDate::MAXIMUM), None);
+    assert_eq!(calendar.prev_business_day_before(&NaiveDate::MINIMUM), None);
+}
+
+#[test]
+fn add_business_days() {
+    let calendar = q3_calendar();
+
+    assert_eq!(calendar.add_business_days(&date(2021, 7, 1), 0),
+        Some(date(2021, 7, 1)));
+    assert_eq!(calendar.add_business_days(&date(2021, 7, 1), 2),
+        Some(date(2021, 7, 6)));
+    assert_eq!(calendar.add_business_days(&date(2021, 7, 6), -2),
+        Some(date(2021, 7, 1)));
+    // Starting on a weekend.
+    assert_eq!(calendar.add_business_days(&date(2021, 7, 3), 1),
+        Some(date(2021, 7, 6)));
+    // Starting on a holiday.
+    assert_eq!(calendar.add_business_days(&date(2021, 9, 6), -1),
+        Some(date(2021, 9, 3)));
+}
+
+#[test]
+fn add_business_days_matches_stepping() {
+    let mut calendar = q3_calendar();
+    calendar.add_holidays(
+        Interval::closed(date(2021, 8, 10), date(2021, 8, 20)));
+    calendar.add_holiday(date(2021, 8, 28));
+    // 2021-06-25 is a Friday.
+    let start = date(2021, 6, 25);
+
+    // Step one business day at a time from the start.
+    let mut expected = start;
+    for count in 1..80 {
+        expected = Finite::succ(&expected).unwrap();
+        while !calendar.is_business_day(&expected) {
+            expected = Finite::succ(&expected).unwrap();
+        }
+        assert_eq!(calendar.add_business_days(&start, count), Some(expected));
+        assert_eq!(calendar.add_business_days(&expected, -count), Some(start));
+        assert_eq!(calendar.business_days_between(&start, &expected),
+            count as usize);
+    }
+}
+
+#[test]
+fn business_days_between() {
+    let calendar = q3_calendar();
+
+    assert_eq!(calendar.business_days_between(
+        &date(2021, 7, 1), &date(2021, 10, 1)), 64);
+    assert_eq!(calendar.business_days_between(
+        &date(2021, 7, 1), &date(2021, 7, 1)), 0);
+    assert_eq!(calendar.business_days_between(
+        &date(2021, 7, 9), &date(2021, 7, 1)), 0);
+
+    // Holidays outside the dates are not counted.
+    let mut closed = calendar.clone();
+    closed.add_holidays(Interval::unbounded_from(date(2021, 9, 1)));
+    assert_eq!(closed.business_days_between(
+        &date(2021, 7, 1), &date(2021, 10, 1)), 43);
+    assert_eq!(calendar.business_days_between(
+        &NaiveDate::MINIMUM, &NaiveDate::MAXIMUM),
+        calendar.business_days_between(
+            &NaiveDate::MINIMUM, &date(2021, 1, 1))
+        + calendar.business_days_between(
+            &date(2021, 1, 1), &NaiveDate::MAXIMUM));
+}
@@ -0,0 +1,212 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the date and time [`Finite`] implementations.
+//!
+//! [`Finite`]: ../../normalize/trait.Finite.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+
+////////////////////////////////////////////////////////////////////////////////
+// chrono tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "chrono")]
+mod chrono {
+    // Local imports.
+    use crate::datetime::chrono::NanoTimestamp;
+    use crate::datetime::chrono::SecondTimestamp;
+    use crate::interval::Interval;
+    use crate::normalize::Finite;
+    use crate::normalize::FiniteIndex;
+    use crate::selection::Selection;
+
+    // External library imports.
+    use ::chrono::NaiveDate;
+    use ::chrono::NaiveDateTime;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn datetime(y: i32, m: u32, d: u32, h: u32, mi: u32, s: u32, n: u32)
+        -> NaiveDateTime
+    {
+        date(y, m, d).and_hms_nano_opt(h, mi, s, n).unwrap()
+    }
+
+    #[test]
+    fn date_normalization() {
+        let i = Interval::open(date(2020, 2, 28), date(2020, 3, 2));
+        assert_eq!(i, Interval::closed(date(2020, 2, 29), date(2020, 3, 1)));
+        assert_eq!(Finite::pred(&NaiveDate::MINIMUM), None);
+        assert_eq!(Finite::succ(&NaiveDate::MAXIMUM), None);
+    }
+
+    #[test]
+    fn date_finite_index() {
+        assert_eq!(NaiveDate::MIN.finite_index(), 0);
+        assert!(NaiveDate::MAX.finite_index() < 1 << NaiveDate::INDEX_BITS);
+        for d in [NaiveDate::MIN, date(1970, 1, 1), NaiveDate::MAX] {
+            assert_eq!(NaiveDate::from_finite_index(d.finite_index()), d);
+        }
+        assert_eq!(date(2021, 3, 1).finite_index()
+            - date(2021, 2, 28).finite_index(), 1);
+    }
+
+    #[test]
+    fn calendar_constructors() {
+        assert_eq!(Interval::<NaiveDate>::month(2021, 2),
+            Some(Interval::closed(date(2021, 2, 1), date(2021, 2, 28))));
+        assert_eq!(Interval::<NaiveDate>::month(2021, 12),
+            Some(Interval::closed(date(2021, 12, 1), date(2021, 12, 31))));
+        assert_eq!(Interval::<NaiveDate>::month(2021, 0), None);
+
+        assert_eq!(Interval::<NaiveDate>::iso_week(2021, 52),
+            Some(Interval::closed(date(2021, 12, 27), date(2022, 1, 2))));
+        assert_eq!(Interval::<NaiveDate>::iso_week(2021, 53), None);
+
+        assert_eq!(Interval::<NaiveDate>::year(2020),
+            Some(Interval::closed(date(2020, 1, 1), date(2020, 12, 31))));
+    }
+
+    #[test]
+    fn date_selection() {
+        let mut sel: Selection<NaiveDate> = Interval::<NaiveDate>::year(2021)
+            .unwrap()
+            .into();
+        sel.minus_in_place(Interval::<NaiveDate>::month(2021, 2).unwrap());
+        sel.intersect_in_place(
+            Interval::closed(date(2021, 1, 30), date(2021, 3, 2)));
+
+        assert_eq!(sel.iter().collect::<Vec<_>>(), [
+            date(2021, 1, 30),
+            date(2021, 1, 31),
+            date(2021, 3, 1),
+            date(2021, 3, 2),
+        ]);
+    }
+
+    #[test]
+    fn second_timestamp() {
+        let t = SecondTimestamp::new(datetime(2020, 12, 31, 23, 59, 59, 500));
+        assert_eq!(t.datetime(), datetime(2020, 12, 31, 23, 59, 59, 0));
+        assert_eq!(t.succ().map(|t| t.datetime()),
+            Some(datetime(2021, 1, 1, 0, 0, 0, 0)));
+        assert_eq!(SecondTimestamp::MAXIMUM.succ(), None);
+        assert_eq!(SecondTimestamp::MINIMUM.pred(), None);
+    }
+
+    #[test]
+    fn nano_timestamp() {
+        let t = NanoTimestamp::new(datetime(2021, 1, 1, 0, 0, 0, 0));
+        assert_eq!(t.pred().map(|t| t.datetime()),
+            Some(datetime(2020, 12, 31, 23, 59, 59, 999_999_999)));
+
+        let i = Interval::open(t, NanoTimestamp::new(
+            datetime(2021, 1, 1, 0, 0, 0, 3)));
+        assert_eq!(i.iter().map(|t| t.datetime()).collect::<Vec<_>>(), [
+            datetime(2021, 1, 1, 0, 0, 0, 1),
+            datetime(2021, 1, 1, 0, 0, 0, 2),
+        ]);
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// time tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "time")]
+mod time {
+    // Local imports.
+    use crate::datetime::time::NanoTimestamp;
+    use crate::datetime::time::SecondTimestamp;
+    use crate::interval::Interval;
+    use crate::normalize::Finite;
+    use crate::normalize::FiniteIndex;
+    use crate::selection::Selection;
+
+    // External library imports.
+    use ::time::Date;
+    use ::time::Month;
+    use ::time::PrimitiveDateTime;
+    use ::time::Time;
+
+    fn date(y: i32, m: Month, d: u8) -> Date {
+        Date::from_calendar_date(y, m, d).unwrap()
+    }
+
+    fn datetime(date: Date, h: u8, mi: u8, s: u8, n: u32) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(date, Time::from_hms_nano(h, mi, s, n).unwrap())
+    }
+
+    #[test]
+    fn date_normalization() {
+        let i = Interval::open(date(2020, Month::February, 28),
+            date(2020, Month::March, 2));
+        assert_eq!(i, Interval::closed(date(2020, Month::February, 29),
+            date(2020, Month::March, 1)));
+        assert_eq!(Date::MINIMUM.pred(), None);
+        assert_eq!(Date::MAXIMUM.succ(), None);
+    }
+
+    #[test]
+    fn date_finite_index() {
+        assert_eq!(Date::MIN.finite_index(), 0);
+        assert!(Date::MAX.finite_index() < 1 << Date::INDEX_BITS);
+        assert!(Date::MAX.finite_index() >= 1 << (Date::INDEX_BITS - 1));
+        for d in [Date::MIN, date(1970, Month::January, 1), Date::MAX] {
+            assert_eq!(Date::from_finite_index(d.finite_index()), d);
+        }
+    }
+
+    #[test]
+    fn calendar_constructors() {
+        assert_eq!(Interval::<Date>::month(2021, Month::February),
+            Some(Interval::closed(date(2021, Month::February, 1),
+                date(2021, Month::February, 28))));
+        assert_eq!(Interval::<Date>::iso_week(2021, 52),
+            Some(Interval::closed(date(2021, Month::December, 27),
+                date(2022, Month::January, 2))));
+        assert_eq!(Interval::<Date>::iso_week(2021, 53), None);
+        assert_eq!(Interval::<Date>::year(2021),
+            Some(Interval::closed(date(2021, Month::January, 1),
+                date(2021, Month::December, 31))));
+    }
+
+    #[test]
+    fn date_selection() {
+        let sel: Selection<Date> = vec![
+                Interval::<Date>::month(2021, Month::January).unwrap(),
+                Interval::<Date>::month(2021, Month::February).unwrap(),
+            ]
+            .into_iter()
+            .collect();
+
+        assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+            Interval::closed(date(2021, Month::January, 1),
+                date(2021, Month::February, 28)),
+        ]);
+    }
+
+    #[test]
+    fn timestamps() {
+        let midnight = datetime(date(2021, Month::January, 1), 0, 0, 0, 0);
+
+        let t = SecondTimestamp::new(
+            datetime(date(2020, Month::December, 31), 23, 59, 59, 500));
+        assert_eq!(t.succ().map(|t| t.datetime()), Some(midnight));
+        assert_eq!(SecondTimestamp::MAXIMUM.succ(), None);
+
+        let t = NanoTimestamp::new(midnight);
+        assert_eq!(t.pred().map(|t| t.datetime()), Some(datetime(
+            date(2020, Month::December, 31), 23, 59, 59, 999_999_999)));
+        assert_eq!(NanoTimestamp::MINIMUM.pred(), None);
+    }
+}
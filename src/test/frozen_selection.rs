@@ -0,0 +1,159 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`FrozenSelection`].
+//!
+//! [`FrozenSelection`]: ../../frozen_selection/struct.FrozenSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::frozen_selection::FrozenSelection;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::selection::Selection;
+use crate::test::a;
+use crate::test::b;
+use crate::test::intervals;
+use crate::test::selection;
+
+
+fn frozen(intervals: &[Interval<i32>]) -> FrozenSelection<i32> {
+    intervals.iter().cloned().collect()
+}
+
+fn frozen_u8(intervals: &[Interval<u8>]) -> FrozenSelection<u8> {
+    intervals.iter().cloned().collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Construction tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn from_iter_merges() {
+    let f = frozen(&[
+        Interval::closed(5, 8),
+        Interval::closed(0, 2),
+        Interval::closed(3, 4),
+        Interval::empty(),
+        Interval::open(10, 12),
+    ]);
+    assert_eq!(intervals(&f), [(Some(0), Some(8)), (Some(11), Some(11))]);
+    assert_eq!(f.interval_count(), 2);
+}
+
+#[test]
+fn selection_round_trip() {
+    let sel = selection(&a());
+    let f = sel.freeze();
+    assert_eq!(intervals(&f), intervals(&sel));
+    assert_eq!(FrozenSelection::from(sel.clone()), f);
+    assert_eq!(intervals(&Selection::from(f)), intervals(&sel));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn contains() {
+    let f = frozen(&a());
+    for p in -12..25 {
+        let expected = a().iter().any(|i| i.contains(&p));
+        assert_eq!(f.contains(&p), expected, "point {}", p);
+    }
+    assert!(!FrozenSelection::new().contains(&0));
+    assert!(FrozenSelection::full().contains(&i32::MIN));
+}
+
+#[test]
+fn bounds_and_points() {
+    let f = frozen(&a());
+    assert_eq!(f.infimum(), Some(-10));
+    assert_eq!(f.supremum(), Some(20));
+    assert_eq!(f.iter().count(), 6 + 5 + 13);
+    assert_eq!(f.iter().take(3).collect::<Vec<_>>(), [-10, -9, -8]);
+    assert_eq!(f.interval_iter().next_back(), Some(Interval::closed(8, 20)));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Set operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn set_operations_match_selection() {
+    let (fa, fb) = (frozen(&a()), frozen(&b()));
+    let sa = selection(&a());
+    let sb = selection(&b());
+
+    assert_eq!(intervals(&fa.union(&fb)), intervals(&sa.union(&sb)));
+    assert_eq!(intervals(&fa.minus(&fb)), intervals(&sa.minus(&sb)));
+    assert_eq!(intervals(&fa.complement()), intervals(&sa.complement()));
+    assert_eq!(intervals(&fa.intersect(&fb)), [
+        (Some(-7), Some(-5)),
+        (Some(0), Some(1)),
+        (Some(3), Some(3)),
+        (Some(15), Some(20)),
+    ]);
+}
+
+#[test]
+fn extend_merges_unsorted_intervals() {
+    let mut f = frozen(&a());
+    f.extend(vec![
+        Interval::closed(21, 25),
+        Interval::closed(-30, -20),
+        Interval::empty(),
+        Interval::closed(2, 6),
+    ]);
+    assert_eq!(intervals(&f), [
+        (Some(-30), Some(-20)),
+        (Some(-10), Some(-5)),
+        (Some(0), Some(6)),
+        (Some(8), Some(25)),
+    ]);
+}
+
+#[test]
+fn interval_set_operations_match_selection() {
+    let fa = frozen(&a());
+    let sb = selection(&b());
+    let sa = selection(&a());
+
+    assert_eq!(intervals(&IntervalSet::union(&fa, &sb)),
+        intervals(&sa.union(&sb)));
+    assert_eq!(intervals(&IntervalSet::intersect(&fa, &sb)),
+        intervals(&sa.intersect(&sb)));
+    assert_eq!(intervals(&IntervalSet::minus(&fa, &sb)),
+        intervals(&sa.minus(&sb)));
+}
+
+#[test]
+fn complement_extremes() {
+    let empty = FrozenSelection::<i32>::new();
+    assert_eq!(empty.complement(), FrozenSelection::full());
+    assert!(FrozenSelection::<i32>::full().complement().is_empty());
+
+    let edges = frozen(&[
+        Interval::closed(i32::MIN, 0),
+        Interval::closed(10, i32::MAX),
+    ]);
+    assert_eq!(intervals(&edges.complement()), [(Some(1), Some(9))]);
+}
+
+#[test]
+fn union_adjacent_at_maximum() {
+    let f = frozen_u8(&[Interval::closed(0, 100)])
+        .union(&frozen_u8(&[Interval::closed(101, u8::MAX)]));
+    assert_eq!(f, FrozenSelection::full());
+}
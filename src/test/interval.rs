@@ -0,0 +1,338 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Interval`] conversions.
+//!
+//! [`Interval`]: ../../interval/struct.Interval.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::interval::IntervalError;
+use crate::interval::ParseIntervalError;
+use crate::interval::SplitSide;
+use crate::interval::TryFromIntervalError;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::ops::Range;
+use std::ops::RangeInclusive;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Range conversion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn from_range_inclusive() {
+    assert_eq!(Interval::from(2..=5), Interval::closed(2, 5));
+    assert_eq!(Interval::from(5..=5), Interval::point(5));
+    assert_eq!(Interval::from(RangeInclusive::new(6, 5)), Interval::empty());
+
+    let mut exhausted = 0..=0;
+    assert_eq!(exhausted.next(), Some(0));
+    assert_eq!(Interval::from(exhausted), Interval::empty());
+}
+
+#[test]
+fn range_bounds() {
+    let map: BTreeMap<i32, char> = (0..10).zip("abcdefghij".chars()).collect();
+
+    let selected: String = map
+        .range(Interval::open(2, 6))
+        .map(|(_, c)| *c)
+        .collect();
+    assert_eq!(selected, "def");
+
+    let selected: String = map
+        .range(Interval::unbounded_to(1))
+        .map(|(_, c)| *c)
+        .collect();
+    assert_eq!(selected, "ab");
+
+    let mut v: Vec<u8> = (0..6).collect();
+    let drained: Vec<u8> = v.drain(Interval::closed(1usize, 3)).collect();
+    assert_eq!(drained, [1, 2, 3]);
+    assert_eq!(v, [0, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn range_bounds_empty() {
+    use std::ops::RangeBounds;
+    let _ = Interval::<i32>::empty().start_bound();
+}
+
+#[test]
+fn to_range_bounds() {
+    let map: BTreeMap<i32, char> = (0..10).zip("abcdefghij".chars()).collect();
+
+    let interval = Interval::open(2, 6);
+    let bounds = interval.to_range_bounds().unwrap();
+    assert_eq!(map.range(bounds).map(|(_, c)| *c).collect::<String>(), "def");
+    assert_eq!(Interval::<i32>::empty().to_range_bounds(),
+        Err(TryFromIntervalError::Empty));
+}
+
+#[test]
+fn try_into_range() {
+    assert_eq!(Range::try_from(Interval::open(0, 4)), Ok(1..4));
+    assert_eq!(Range::try_from(Interval::point(7u8)), Ok(7..8));
+    assert_eq!(Range::try_from(Interval::closed(0, u8::MAX)),
+        Err(TryFromIntervalError::Unbounded));
+    assert_eq!(Range::try_from(Interval::unbounded_from(0)),
+        Err(TryFromIntervalError::Unbounded));
+    assert_eq!(Range::try_from(Interval::unbounded_to(0)),
+        Ok(i32::MIN..1));
+    assert_eq!(Range::<i32>::try_from(Interval::empty()),
+        Err(TryFromIntervalError::Empty));
+}
+
+#[test]
+fn try_into_range_inclusive() {
+    assert_eq!(RangeInclusive::try_from(Interval::open(0, 4)), Ok(1..=3));
+    assert_eq!(RangeInclusive::try_from(Interval::closed(0, u8::MAX)),
+        Ok(0..=u8::MAX));
+    assert_eq!(RangeInclusive::try_from(Interval::unbounded_from(0)),
+        Ok(0..=i32::MAX));
+    assert_eq!(RangeInclusive::<i32>::try_from(Interval::empty()),
+        Err(TryFromIntervalError::Empty));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bound conversion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn std_bound_round_trip() {
+    use std::ops::Bound::*;
+    assert_eq!(Bound::from(Included(1)), Bound::Include(1));
+    assert_eq!(Bound::from(Excluded(1)), Bound::Exclude(1));
+    assert_eq!(Bound::<i32>::from(Unbounded), Bound::Infinite);
+
+    for bound in [Bound::Include(1), Bound::Exclude(1), Bound::Infinite] {
+        assert_eq!(Bound::from(std::ops::Bound::from(bound)), bound);
+    }
+}
+
+#[test]
+fn from_bounds() {
+    use std::ops::Bound::*;
+    assert_eq!(Interval::from_bounds((Excluded(0), Excluded(4))),
+        Interval::closed(1, 3));
+    assert_eq!(Interval::from_bounds((Unbounded, Included(4))),
+        Interval::unbounded_to(4));
+    assert_eq!(Interval::from_bounds((Excluded(4), Excluded(4))),
+        Interval::empty());
+    assert_eq!(Interval::from_bounds(2..=6), Interval::closed(2, 6));
+    assert_eq!(Interval::<i32>::from_bounds(..), Interval::full());
+
+    // Round trip through `RangeBounds`.
+    let interval = Interval::closed(-3, 8);
+    assert_eq!(Interval::from_bounds(interval), interval);
+}
+
+#[test]
+fn closed_and_index_bounds() {
+    assert_eq!(Interval::open(2, 6).closed_bounds(), Some((3, 5)));
+    assert_eq!(Interval::<i32>::empty().closed_bounds(), None);
+    assert_eq!(Interval::<u8>::full().closed_bounds(), Some((0, 255)));
+
+    assert_eq!(Interval::closed(-1i8, 1).index_bounds(), Some((127, 129)));
+    assert_eq!(Interval::<i8>::empty().index_bounds(), None);
+    assert_eq!(Interval::<u8>::full().point_count(), 256);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Checked constructor tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn checked_constructors_match_unchecked() {
+    for (l, r) in [(0, 5), (-3, 3), (0, 2), (i32::MIN, i32::MAX)] {
+        assert_eq!(Interval::try_open(l, r), Ok(Interval::open(l, r)));
+        assert_eq!(Interval::try_left_open(l, r),
+            Ok(Interval::left_open(l, r)));
+        assert_eq!(Interval::try_right_open(l, r),
+            Ok(Interval::right_open(l, r)));
+        assert_eq!(Interval::try_closed(l, r), Ok(Interval::closed(l, r)));
+        assert_eq!(Interval::try_new(Bound::Infinite, Bound::Include(r)),
+            Ok(Interval::unbounded_to(r)));
+    }
+}
+
+#[test]
+fn checked_constructors_inverted() {
+    use IntervalError::*;
+    assert_eq!(Interval::try_open(5, 0), Err(InvertedBounds));
+    assert_eq!(Interval::try_left_open(5, 0), Err(InvertedBounds));
+    assert_eq!(Interval::try_right_open(5, 0), Err(InvertedBounds));
+    assert_eq!(Interval::try_closed(5, 0), Err(InvertedBounds));
+    assert_eq!(Interval::try_new(Bound::Exclude(5), Bound::Include(0)),
+        Err(InvertedBounds));
+}
+
+#[test]
+fn checked_constructors_degenerate() {
+    use IntervalError::*;
+    assert_eq!(Interval::try_open(5, 5), Err(DegenerateOpen));
+    assert_eq!(Interval::try_open(5, 6), Err(DegenerateOpen));
+    assert_eq!(Interval::try_left_open(5, 5), Err(DegenerateOpen));
+    assert_eq!(Interval::try_right_open(5, 5), Err(DegenerateOpen));
+    assert_eq!(Interval::try_closed(5, 5), Ok(Interval::point(5)));
+
+    // Excluding a bound at the extreme of a finite type leaves no points.
+    assert_eq!(Interval::try_new(Bound::Exclude(u8::MAX), Bound::Infinite),
+        Err(DegenerateOpen));
+}
+
+#[test]
+fn normalize_degenerate_bounds() {
+    // Adjusting open bounds may leave a single point or no points.
+    assert_eq!(Interval::open(3, 5), Interval::point(4));
+    assert_eq!(Interval::open(3, 4), Interval::empty());
+    assert_eq!(Interval::left_open(3, 4), Interval::point(4));
+    assert_eq!(Interval::right_open(3, 4), Interval::point(3));
+    assert_eq!(Interval::<u8>::unbounded_to(0), Interval::point(0));
+    assert_eq!(Interval::<u8>::unbounded_up_to(1), Interval::point(0));
+}
+
+#[test]
+fn interval_error_is_error() {
+    let error: Box<dyn std::error::Error>
+        = Box::new(IntervalError::InvertedBounds);
+    assert_eq!(error.to_string(), "interval bounds are out of order");
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Display and parsing tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn display_raw_interval_notation() {
+    use RawInterval::*;
+    assert_eq!(To(5).to_string(), "(-∞, 5]");
+    assert_eq!(UpTo(5).to_string(), "(-∞, 5)");
+    assert_eq!(From(5).to_string(), "[5, ∞)");
+    assert_eq!(UpFrom(5).to_string(), "(5, ∞)");
+    assert_eq!(LeftOpen(1, 5).to_string(), "(1, 5]");
+}
+
+#[test]
+fn parse_interval_notation() {
+    let parse = |s: &str| s.parse::<Interval<i32>>();
+    assert_eq!(parse("[3, 7]"), Ok(Interval::closed(3, 7)));
+    assert_eq!(parse(" ( 3,7 ] "), Ok(Interval::closed(4, 7)));
+    assert_eq!(parse("(3, 7)"), Ok(Interval::closed(4, 6)));
+    assert_eq!(parse("-4"), Ok(Interval::point(-4)));
+    assert_eq!(parse("Ø"), Ok(Interval::empty()));
+    assert_eq!(parse("(-∞, 7)"), Ok(Interval::unbounded_up_to(7)));
+    assert_eq!(parse("[3, ∞)"), Ok(Interval::unbounded_from(3)));
+    assert_eq!(parse("(-∞, ∞)"), Ok(Interval::full()));
+}
+
+#[test]
+fn parse_interval_errors() {
+    let parse = |s: &str| s.parse::<Interval<i32>>();
+    assert_eq!(parse(""), Err(ParseIntervalError::Point));
+    assert_eq!(parse("[3, x]"), Err(ParseIntervalError::Point));
+    assert_eq!(parse("[3 7]"), Err(ParseIntervalError::Syntax));
+    assert_eq!(parse("(3, 7"), Err(ParseIntervalError::Syntax));
+    assert_eq!(parse("[-∞, 7]"), Err(ParseIntervalError::Syntax));
+    assert_eq!(parse("[7, 3]"), Err(ParseIntervalError::Interval(
+        IntervalError::InvertedBounds)));
+    assert_eq!(parse("(3, 4)"), Err(ParseIntervalError::Interval(
+        IntervalError::DegenerateOpen)));
+}
+
+#[test]
+fn parse_display_round_trip() {
+    for interval in [
+        Interval::empty(),
+        Interval::point(i8::MIN),
+        Interval::closed(-3, 9),
+        Interval::unbounded_up_to(0),
+        Interval::full(),
+    ] {
+        assert_eq!(interval.to_string().parse(), Ok(interval));
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Split and chunk tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn split_at_matches_points() {
+    let intervals = [
+        Interval::closed(-3, 4),
+        Interval::open(-3, 4),
+        Interval::point(0),
+        Interval::empty(),
+    ];
+    for interval in intervals.iter() {
+        let points: Vec<i32> = interval.iter().collect();
+        for p in -5..7 {
+            for side in [SplitSide::Left, SplitSide::Right] {
+                let (before, after) = interval.split_at(&p, side);
+                let in_before = |q: &i32| match side {
+                    SplitSide::Left  => *q <= p,
+                    SplitSide::Right => *q < p,
+                };
+                assert_eq!(before.iter().collect::<Vec<_>>(), points.iter()
+                    .cloned().filter(|q| in_before(q)).collect::<Vec<_>>());
+                assert_eq!(after.iter().collect::<Vec<_>>(), points.iter()
+                    .cloned().filter(|q| !in_before(q)).collect::<Vec<_>>());
+            }
+        }
+    }
+}
+
+#[test]
+fn split_at_extremes() {
+    let full: Interval<u8> = Interval::full();
+    assert_eq!(full.split_at(&0, SplitSide::Right),
+        (Interval::empty(), Interval::closed(0, 255)));
+    assert_eq!(full.split_at(&255, SplitSide::Left),
+        (Interval::closed(0, 255), Interval::empty()));
+}
+
+#[test]
+fn chunks_cover_interval() {
+    for size in 1..12 {
+        let interval = Interval::closed(-4, 5);
+        let chunks: Vec<_> = interval.chunks(size).collect();
+        assert_eq!(chunks.len(), 10usize.div_ceil(size));
+        assert!(chunks[..chunks.len() - 1].iter()
+            .all(|c| c.iter().count() == size));
+        assert_eq!(chunks.iter().flat_map(|c| c.iter()).collect::<Vec<_>>(),
+            interval.iter().collect::<Vec<_>>());
+    }
+    assert_eq!(Interval::<i32>::empty().chunks(3).next(), None);
+
+    // Chunking up to the maximum point does not overflow.
+    let top: Interval<u8> = Interval::closed(250, 255);
+    assert_eq!(top.chunks(4).collect::<Vec<_>>(),
+        [Interval::closed(250, 253), Interval::closed(254, 255)]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn chunks_zero_size() {
+    let _ = Interval::closed(0, 5).chunks(0);
+}
+
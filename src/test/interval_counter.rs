@@ -0,0 +1,135 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`IntervalCounter`].
+//!
+//! [`IntervalCounter`]: ../../interval_counter/struct.IntervalCounter.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_counter::IntervalCounter;
+use crate::test::Lcg;
+
+
+/// Returns the bounds and depth of each segment of the counter.
+fn segments(counter: &IntervalCounter<i32>) -> Vec<(i32, i32, usize)> {
+    counter.segments()
+        .map(|(i, d)| (i.infimum().unwrap(), i.supremum().unwrap(), d))
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Depth tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn add_and_remove() {
+    let mut counter = IntervalCounter::new();
+    counter.add(Interval::closed(0, 10), 1);
+    counter.add(Interval::closed(5, 15), 2);
+    counter.add(Interval::open(20, 22), 1);
+    assert_eq!(segments(&counter), [
+        (0, 4, 1),
+        (5, 10, 3),
+        (11, 15, 2),
+        (21, 21, 1),
+    ]);
+    assert_eq!(counter.max_depth(), 3);
+
+    // Removing more than is present fails without changes.
+    assert!(!counter.remove(Interval::closed(3, 8), 2));
+    assert!(!counter.remove(Interval::closed(20, 21), 1));
+    assert_eq!(counter.depth(&3), 1);
+
+    assert!(counter.remove(Interval::closed(5, 15), 2));
+    assert!(counter.remove(Interval::point(21), 1));
+    assert_eq!(segments(&counter), [(0, 10, 1)]);
+
+    assert!(counter.remove(Interval::closed(0, 10), 1));
+    assert!(counter.is_empty());
+}
+
+#[test]
+fn depth_matches_model() {
+    // Apply a pseudo-random sequence of updates to both a counter and an
+    // array of depths.
+    let mut rng = Lcg::new(777);
+    let mut next = move |m: u32| rng.next(m) as i32;
+
+    let mut counter = IntervalCounter::new();
+    let mut model = [0usize; 100];
+    for _ in 0..500 {
+        let (l, len) = (next(100), next(15));
+        let r = (l + len).min(99);
+        let m = next(3) as usize;
+        if next(2) == 0 {
+            counter.add(Interval::closed(l, r), m);
+            for d in &mut model[l as usize..=r as usize] { *d += m; }
+        } else {
+            let removable = model[l as usize..=r as usize]
+                .iter()
+                .all(|d| *d >= m);
+            assert_eq!(counter.remove(Interval::closed(l, r), m), removable);
+            if removable {
+                for d in &mut model[l as usize..=r as usize] { *d -= m; }
+            }
+        }
+
+        for (p, d) in model.iter().enumerate() {
+            assert_eq!(counter.depth(&(p as i32)), *d);
+        }
+        // Segments are maximal.
+        let segs = segments(&counter);
+        for pair in segs.windows(2) {
+            assert!(pair[0].1 + 1 < pair[1].0 || pair[0].2 != pair[1].2);
+        }
+    }
+}
+
+#[test]
+fn extremes() {
+    let mut counter = IntervalCounter::new();
+    counter.add(Interval::unbounded_from(250u8), 1);
+    counter.add(Interval::full(), 1);
+    assert_eq!(counter.depth(&0), 1);
+    assert_eq!(counter.depth(&255), 2);
+    assert_eq!(counter.segments().collect::<Vec<_>>(), [
+        (Interval::closed(0, 249), 1),
+        (Interval::closed(250, 255), 2),
+    ]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn at_least() {
+    let counter: IntervalCounter<i32> = vec![
+        Interval::closed(0, 10),
+        Interval::closed(5, 15),
+        Interval::closed(8, 20),
+        Interval::closed(30, 40),
+    ].into_iter().collect();
+
+    let bounds = |k| counter.at_least(k)
+        .interval_iter()
+        .map(|i| (i.infimum(), i.supremum()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(bounds(1), [(Some(0), Some(20)), (Some(30), Some(40))]);
+    assert_eq!(bounds(2), [(Some(5), Some(15))]);
+    assert_eq!(bounds(3), [(Some(8), Some(10))]);
+    assert!(counter.at_least(4).is_empty());
+    assert!(counter.at_least(0).is_full());
+}
@@ -0,0 +1,144 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`IntervalSet`].
+//!
+//! [`IntervalSet`]: ../../interval_set/trait.IntervalSet.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::selection::Selection;
+use crate::test::a;
+use crate::test::b;
+use crate::test::intervals;
+use crate::test::selection;
+
+
+/// A minimal `IntervalSet` backend which relies on the default methods, and
+/// counts the calls to `extend`, each of which rebuilds the whole set.
+#[derive(Debug, Default)]
+struct VecSet(Vec<Interval<i32>>, usize);
+
+impl Extend<Interval<i32>> for VecSet {
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<i32>> {
+        let mut sel: Selection<i32> = self.0.drain(..).collect();
+        sel.extend(iter);
+        self.0 = sel.interval_iter().collect();
+        self.1 += 1;
+    }
+}
+
+impl IntervalSet<i32> for VecSet {
+    type Output = VecSet;
+
+    fn interval_iter(&self) -> impl Iterator<Item=Interval<i32>> + '_ {
+        self.0.iter().cloned()
+    }
+}
+
+fn vec_set(intervals: &[Interval<i32>]) -> VecSet {
+    let mut set = VecSet::default();
+    set.extend(intervals.iter().cloned());
+    set
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Default method tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn defaults_match_selection() {
+    let (va, vb) = (vec_set(&a()), vec_set(&b()));
+    let (sa, sb) = (selection(&a()), selection(&b()));
+
+    assert_eq!(intervals(&va.union(&vb)), intervals(&sa.union(&sb)));
+    assert_eq!(intervals(&va.minus(&vb)), intervals(&sa.minus(&sb)));
+    assert_eq!(intervals(&va.intersect(&vb)), intervals(&selection(&[
+        Interval::closed(-7, -5),
+        Interval::closed(0, 1),
+        Interval::point(3),
+        Interval::closed(15, 20),
+    ])));
+    assert_eq!(intervals(&IntervalSet::complement(&va)),
+        intervals(&sa.complement()));
+}
+
+#[test]
+fn defaults_extend_once() {
+    let (va, vb) = (vec_set(&a()), vec_set(&b()));
+
+    assert_eq!(va.union(&vb).1, 1);
+    assert_eq!(va.intersect(&vb).1, 1);
+    assert_eq!(va.minus(&vb).1, 1);
+    assert_eq!(IntervalSet::complement(&va).1, 1);
+}
+
+#[test]
+fn defaults_bounds_and_queries() {
+    let va = vec_set(&a());
+
+    assert_eq!(va.lower_bound(), Some(Bound::Include(-10)));
+    assert_eq!(va.upper_bound(), Some(Bound::Include(20)));
+    assert_eq!(va.infimum(), Some(-10));
+    assert_eq!(va.supremum(), Some(20));
+    assert_eq!(va.enclose(), Interval::closed(-10, 20));
+
+    assert!(va.contains(&-5));
+    assert!(!va.contains(&5));
+    assert!(va.intersects(&vec_set(&b())));
+    assert!(!va.intersects(&vec_set(&[Interval::closed(5, 7)])));
+}
+
+#[test]
+fn defaults_empty_and_full() {
+    let empty = VecSet::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty.infimum(), None);
+    assert_eq!(empty.enclose(), Interval::empty());
+    assert_eq!(intervals(&empty.complement()),
+        [(Some(i32::MIN), Some(i32::MAX))]);
+
+    let full = vec_set(&[Interval::full()]);
+    assert!(full.complement().is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Implementation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn interval_as_set() {
+    let i = Interval::closed(0, 10);
+
+    assert_eq!(IntervalSet::enclose(&i), i);
+    assert_eq!(intervals(&IntervalSet::complement(&i)), [
+        (Some(i32::MIN), Some(-1)),
+        (Some(11), Some(i32::MAX)),
+    ]);
+    assert_eq!(intervals(&IntervalSet::minus(&i, &selection(&b()))),
+        intervals(&selection(&[Interval::point(2), Interval::closed(4, 10)])));
+    assert!(IntervalSet::is_empty(&Interval::<i32>::empty()));
+}
+
+#[test]
+fn mixed_set_types() {
+    let sa = selection(&a());
+    let va = vec_set(&b());
+
+    // A `Selection` combined with another backend produces a `Selection`.
+    let sel: Selection<i32> = IntervalSet::intersect(&sa, &va);
+    assert_eq!(intervals(&sel), intervals(&IntervalSet::intersect(&va, &sa)));
+    assert_eq!(sel.iter().count(), 3 + 2 + 1 + 6);
+}
@@ -0,0 +1,314 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Model-based tests for [`Selection`].
+//!
+//! Random sequences of operations are applied to a `Selection` over a 256
+//! point domain and to a reference bitset. After each operation the
+//! `Selection` must contain exactly the points of the bitset, and its tines
+//! must be in canonical form.
+//!
+//! [`Selection`]: ../../selection/struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+use crate::test::Lcg;
+use crate::tine::Tine;
+
+// Standard library imports.
+use std::fmt::Debug;
+
+
+/// The number of operations applied for each seed.
+const STEPS: usize = 48;
+
+/// The number of seeds tested for each point type.
+const SEEDS: u32 = 150;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Model
+////////////////////////////////////////////////////////////////////////////////
+/// A `Finite` type with 256 points, indexed from `MINIMUM`.
+trait Point: Finite + Ord + Copy + Debug {
+    fn index(self) -> usize;
+    fn from_index(index: usize) -> Self;
+}
+
+impl Point for u8 {
+    fn index(self) -> usize { usize::from(self) }
+    fn from_index(index: usize) -> Self { index as u8 }
+}
+
+impl Point for i8 {
+    fn index(self) -> usize { (i16::from(self) + 128) as usize }
+    fn from_index(index: usize) -> Self { (index as i16 - 128) as i8 }
+}
+
+/// A reference set of the indices of up to 256 points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bitset([u64; 4]);
+
+impl Bitset {
+    fn from_fn<F>(f: F) -> Self where F: Fn(usize) -> bool {
+        let mut words = [0; 4];
+        for i in (0..256).filter(|&i| f(i)) {
+            words[i / 64] |= 1 << (i % 64);
+        }
+        Bitset(words)
+    }
+
+    fn from_raw<T>(interval: &RawInterval<T>) -> Self where T: Point {
+        Bitset::from_fn(|i| interval.contains(&T::from_index(i)))
+    }
+
+    fn contains(&self, i: usize) -> bool {
+        self.0[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    fn zip<F>(self, other: Self, f: F) -> Self where F: Fn(u64, u64) -> u64 {
+        let mut words = self.0;
+        for (word, other) in words.iter_mut().zip(other.0.iter()) {
+            *word = f(*word, *other);
+        }
+        Bitset(words)
+    }
+}
+
+/// An operation applied to both the `Selection` and the model.
+#[derive(Debug, Clone)]
+enum Op<T> {
+    UnionInPlace(RawInterval<T>),
+    MinusInPlace(RawInterval<T>),
+    IntersectInPlace(RawInterval<T>),
+    Complement,
+    Union(Vec<RawInterval<T>>),
+    Minus(Vec<RawInterval<T>>),
+    Intersect(Vec<RawInterval<T>>),
+}
+
+/// Returns a pseudo-random point.
+fn point<T>(rng: &mut Lcg) -> T where T: Point {
+    // Favor the extremes, where normalization has the most edge cases.
+    let index = match rng.next(8) {
+        0 => 0,
+        1 => 1,
+        2 => 254,
+        3 => 255,
+        _ => rng.next(256),
+    };
+    T::from_index(index as usize)
+}
+
+/// Returns a pseudo-random interval of any shape.
+fn interval<T>(rng: &mut Lcg) -> RawInterval<T> where T: Point {
+    use RawInterval::*;
+    let (a, b) = (point::<T>(rng), point::<T>(rng));
+    let (l, r) = (a.min(b), a.max(b));
+    match rng.next(11) {
+        0  => Empty,
+        1  => Point(l),
+        2  => Open(l, r),
+        3  => LeftOpen(l, r),
+        4  => RightOpen(l, r),
+        5  => Closed(l, r),
+        6  => UpTo(l),
+        7  => UpFrom(l),
+        8  => To(l),
+        9  => From(l),
+        _  => Full,
+    }
+}
+
+/// Returns up to three pseudo-random intervals.
+fn intervals<T>(rng: &mut Lcg) -> Vec<RawInterval<T>> where T: Point {
+    let len = rng.next(4);
+    (0..len).map(|_| interval(rng)).collect()
+}
+
+/// Returns a pseudo-random operation.
+fn op<T>(rng: &mut Lcg) -> Op<T> where T: Point {
+    match rng.next(10) {
+        0 | 1 => Op::UnionInPlace(interval(rng)),
+        2 | 3 => Op::MinusInPlace(interval(rng)),
+        4     => Op::IntersectInPlace(interval(rng)),
+        5     => Op::Complement,
+        6 | 7 => Op::Union(intervals(rng)),
+        8     => Op::Minus(intervals(rng)),
+        _     => Op::Intersect(intervals(rng)),
+    }
+}
+
+/// Returns the `Selection` and model for the given intervals.
+fn build<T>(intervals: &[RawInterval<T>]) -> (Selection<T>, Bitset)
+    where T: Point
+{
+    let selection = intervals.iter().cloned().map(Interval::from).collect();
+    let model = intervals.iter()
+        .map(Bitset::from_raw)
+        .fold(Bitset([0; 4]), |a, b| a.zip(b, |a, b| a | b));
+    (selection, model)
+}
+
+/// Applies the operation to the `Selection` and model.
+fn apply<T>(op: &Op<T>, selection: &mut Selection<T>, model: &mut Bitset)
+    where T: Point
+{
+    match op {
+        Op::UnionInPlace(raw) => {
+            selection.union_in_place(Interval::from(*raw));
+            *model = model.zip(Bitset::from_raw(raw), |a, b| a | b);
+        },
+        Op::MinusInPlace(raw) => {
+            selection.minus_in_place(Interval::from(*raw));
+            *model = model.zip(Bitset::from_raw(raw), |a, b| a & !b);
+        },
+        Op::IntersectInPlace(raw) => {
+            selection.intersect_in_place(Interval::from(*raw));
+            *model = model.zip(Bitset::from_raw(raw), |a, b| a & b);
+        },
+        Op::Complement => {
+            *selection = selection.complement();
+            *model = model.zip(*model, |a, _| !a);
+        },
+        Op::Union(intervals) => {
+            let (other, other_model) = build(intervals);
+            *selection = selection.union(&other);
+            *model = model.zip(other_model, |a, b| a | b);
+        },
+        Op::Minus(intervals) => {
+            let (other, other_model) = build(intervals);
+            *selection = selection.minus(&other);
+            *model = model.zip(other_model, |a, b| a & !b);
+        },
+        Op::Intersect(intervals) => {
+            let (other, other_model) = build(intervals);
+            *selection = selection.intersect(&other);
+            *model = model.zip(other_model, |a, b| a & b);
+        },
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Invariants
+////////////////////////////////////////////////////////////////////////////////
+
+/// Checks that the `Selection` contains exactly the points of the model.
+fn check_points<T>(selection: &Selection<T>, model: &Bitset, ops: &[Op<T>])
+    where T: Point
+{
+    let contained = Bitset::from_fn(|i| selection.contains(&T::from_index(i)));
+    assert_eq!(contained, *model, "contains after {:?}", ops);
+
+    let points: Vec<T> = (0..256)
+        .filter(|&i| model.contains(i))
+        .map(T::from_index)
+        .collect();
+    assert_eq!(selection.iter().collect::<Vec<_>>(), points,
+        "points after {:?}", ops);
+    assert_eq!(selection.is_empty(), points.is_empty());
+    assert_eq!(selection.is_full(), points.len() == 256);
+}
+
+/// Checks that the `Selection` yields normalized, ordered, and separated
+/// intervals.
+fn check_intervals<T>(selection: &Selection<T>, ops: &[Op<T>])
+    where T: Point
+{
+    let intervals: Vec<Interval<T>> = selection.interval_iter().collect();
+    for interval in &intervals {
+        assert!(match interval.0 {
+            RawInterval::Point(_)     => true,
+            RawInterval::Closed(l, r) => l < r,
+            _                         => false,
+        }, "unnormalized interval {:?} after {:?}", interval, ops);
+    }
+    for pair in intervals.windows(2) {
+        let upper = pair[0].supremum().unwrap().index();
+        let lower = pair[1].infimum().unwrap().index();
+        assert!(upper + 1 < lower,
+            "adjacent intervals {:?} after {:?}", pair, ops);
+    }
+}
+
+/// Checks that the tines of the `Selection` are in the canonical denormalized
+/// form: finite bounds are excluded, lower and upper bounds alternate, and
+/// every interval between them contains a point.
+fn check_tines<T>(selection: &Selection<T>, ops: &[Op<T>]) where T: Point {
+    use Bound::*;
+    use Tine::*;
+    let tines: Vec<&Tine<T>> = selection.tine_tree().tine_iter().collect();
+    let context = || format!("in {:?} after {:?}", tines, ops);
+
+    // The index of the first point of the current interval, if any.
+    let mut open: Option<usize> = None;
+    for (i, tine) in tines.iter().enumerate() {
+        let (first, last) = (i == 0, i + 1 == tines.len());
+        // The index one past the last point of the closed interval, if any.
+        let end = match tine {
+            Lower(Infinite) if first => { open = Some(0); None },
+            Lower(Exclude(p))        => {
+                assert!(open.is_none(), "nested lower bound {}", context());
+                open = Some(p.index() + 1);
+                None
+            },
+            Upper(Exclude(p))
+            | Point(Exclude(p))      => Some(p.index()),
+            Upper(Infinite) if last  => Some(256),
+            _                        => panic!(
+                "non-canonical tine {:?} {}", tine, context()),
+        };
+
+        if let Some(end) = end {
+            let start = open.take().unwrap_or_else(|| panic!(
+                "unmatched upper bound {}", context()));
+            assert!(start < end, "empty interval {}", context());
+            if let Point(Exclude(p)) = tine { open = Some(p.index() + 1); }
+        }
+    }
+    assert!(open.is_none(), "unmatched lower bound {}", context());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Model tests
+////////////////////////////////////////////////////////////////////////////////
+
+fn run_model<T>() where T: Point {
+    for seed in 0..SEEDS {
+        let mut rng = Lcg::new(seed);
+        let mut selection = Selection::new();
+        let mut model = Bitset([0; 4]);
+        let mut ops = Vec::with_capacity(STEPS);
+        for _ in 0..STEPS {
+            ops.push(op::<T>(&mut rng));
+            apply(ops.last().unwrap(), &mut selection, &mut model);
+            check_points(&selection, &model, &ops);
+            check_intervals(&selection, &ops);
+            check_tines(&selection, &ops);
+        }
+    }
+}
+
+#[test]
+fn model_u8() {
+    run_model::<u8>();
+}
+
+#[test]
+fn model_i8() {
+    run_model::<i8>();
+}
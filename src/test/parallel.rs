@@ -0,0 +1,102 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the [`parallel`] iterators.
+//!
+//! [`parallel`]: ../../parallel/index.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+#![cfg(feature = "rayon")]
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::FiniteIndex;
+use crate::selection::Selection;
+
+// External library imports.
+use ::rayon::iter::IntoParallelIterator;
+use ::rayon::iter::ParallelIterator;
+
+
+/// Returns a `Selection` with one large interval and many small ones.
+fn lopsided() -> Selection<i64> {
+    let mut sel = Selection::from(Interval::closed(-5000, 4999));
+    for i in 0..200 {
+        sel.union_in_place(Interval::closed(10_000 + i * 3, 10_001 + i * 3));
+    }
+    sel
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iterator tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn par_iter_matches_iter() {
+    let sel = lopsided();
+    assert_eq!(sel.par_iter().collect::<Vec<_>>(),
+        sel.iter().collect::<Vec<_>>());
+    assert_eq!(sel.par_iter().count(), 10_400);
+    assert_eq!(sel.par_iter().map(|p| p as i128).sum::<i128>(),
+        sel.iter().map(|p| p as i128).sum::<i128>());
+
+    let empty: Selection<i64> = Selection::new();
+    assert_eq!(empty.par_iter().count(), 0);
+}
+
+#[test]
+fn par_interval_iter_matches_interval_iter() {
+    let sel = lopsided();
+    assert_eq!(sel.par_interval_iter().collect::<Vec<_>>(),
+        sel.interval_iter().collect::<Vec<_>>());
+    assert_eq!(sel.par_interval_iter().count(), 201);
+}
+
+#[test]
+fn par_iter_at_extremes() {
+    let full: Selection<i8> = Selection::full();
+    assert_eq!(full.par_iter().collect::<Vec<_>>(),
+        (i8::MIN..=i8::MAX).collect::<Vec<_>>());
+
+    // The full u64 domain has more points than fit in a usize.
+    let full: Selection<u64> = Selection::full();
+    assert_eq!(full.par_interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(0, u64::MAX)]);
+    assert!(full.par_iter().any(|p| p == 17));
+}
+
+#[test]
+fn finite_index_round_trip() {
+    for p in [i64::MIN, -1, 0, 1, i64::MAX] {
+        assert_eq!(i64::from_finite_index(p.finite_index()), p);
+    }
+    assert_eq!(i8::MIN.finite_index(), 0);
+    assert_eq!(i8::MAX.finite_index(), 255);
+    assert_eq!(u16::MAX.finite_index(), 65_535);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Construction tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn from_par_iter_merges() {
+    let intervals: Vec<Interval<i64>> = (0..1000)
+        .map(|i| Interval::closed(i * 4, i * 4 + (i % 3) + 1))
+        .collect();
+
+    let par: Selection<i64> = intervals.clone().into_par_iter().collect();
+    let seq: Selection<i64> = intervals.into_iter().collect();
+    assert_eq!(par, seq);
+
+    let copied: Selection<i64> = lopsided().par_interval_iter().collect();
+    assert_eq!(copied, lopsided());
+}
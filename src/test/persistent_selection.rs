@@ -0,0 +1,142 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`PersistentSelection`].
+//!
+//! [`PersistentSelection`]: ../../persistent_selection/struct.PersistentSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::persistent_selection::PersistentSelection;
+use crate::selection::Selection;
+use crate::test::Lcg;
+use crate::test::a;
+use crate::test::b;
+use crate::test::intervals;
+use crate::test::selection;
+
+// Standard library imports.
+use std::collections::BTreeSet;
+
+
+fn persistent(intervals: &[Interval<i32>]) -> PersistentSelection<i32> {
+    intervals.iter().cloned().collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Persistence tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn versions_are_independent() {
+    let v0 = persistent(&a());
+    let mut v1 = v0.clone();
+    assert!(v1.ptr_eq(&v0));
+
+    v1.minus_in_place(Interval::closed(2, 10));
+    v1.union_in_place(Interval::closed(50, 60));
+    assert!(!v1.ptr_eq(&v0));
+
+    assert_eq!(intervals(&v0), intervals(&selection(&a())));
+    assert_eq!(intervals(&v1), [
+        (Some(-10), Some(-5)),
+        (Some(0), Some(1)),
+        (Some(11), Some(20)),
+        (Some(50), Some(60)),
+    ]);
+}
+
+#[test]
+fn in_place_operations_match_model() {
+    // Apply a pseudo-random sequence of updates to both a persistent
+    // selection and a set of points, keeping every version.
+    let mut rng = Lcg::new(12345);
+    let mut next = move |m: u32| rng.next(m) as i32;
+
+    let mut versions = vec![(PersistentSelection::new(), BTreeSet::new())];
+    for _ in 0..300 {
+        let (mut sel, mut model) = versions.last().cloned().unwrap();
+        let (l, len) = (next(200) - 100, next(20));
+        let interval = Interval::closed(l, l + len);
+        match next(3) {
+            0 => {
+                sel.union_in_place(interval);
+                model.extend(interval.iter());
+            },
+            1 => {
+                sel.minus_in_place(interval);
+                for p in interval.iter() { let _ = model.remove(&p); }
+            },
+            _ => {
+                let wide = Interval::closed(l - 50, l + len + 50);
+                sel.intersect_in_place(wide);
+                model.retain(|p| wide.contains(p));
+            },
+        }
+        versions.push((sel, model));
+    }
+
+    for (sel, model) in &versions {
+        assert_eq!(sel.iter().collect::<BTreeSet<_>>(), *model);
+        assert_eq!(sel.infimum(), model.iter().next().cloned());
+        assert_eq!(sel.supremum(), model.iter().next_back().cloned());
+    }
+}
+
+#[test]
+fn many_intervals() {
+    let mut sel = PersistentSelection::new();
+    for i in 0..10_000 {
+        sel.union_in_place(Interval::closed(3 * i, 3 * i + 1));
+    }
+    assert_eq!(sel.interval_iter().count(), 10_000);
+    assert!(sel.contains(&29_998));
+    assert!(!sel.contains(&29_999));
+
+    sel.union_in_place(Interval::closed(2, 29_000));
+    assert_eq!(sel.interval_iter().count(), 333);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion and set operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn selection_round_trip() {
+    let sel = selection(&a());
+    let p = PersistentSelection::from(sel.clone());
+    assert_eq!(intervals(&p), intervals(&sel));
+    assert_eq!(intervals(&Selection::from(p)), intervals(&sel));
+    assert_eq!(PersistentSelection::from(Interval::closed(1, 5)),
+        persistent(&[Interval::closed(1, 3), Interval::closed(4, 5)]));
+}
+
+#[test]
+fn set_operations_match_selection() {
+    let (pa, pb) = (persistent(&a()), persistent(&b()));
+    let (sa, sb) = (selection(&a()), selection(&b()));
+
+    assert_eq!(intervals(&pa.union(&pb)), intervals(&sa.union(&sb)));
+    assert_eq!(intervals(&pa.complement()), intervals(&sa.complement()));
+    assert_eq!(intervals(&pa.minus(&pb)),
+        intervals(&IntervalSet::minus(&sa, &sb)));
+    assert_eq!(intervals(&pb.minus(&pa)),
+        intervals(&IntervalSet::minus(&sb, &sa)));
+    assert_eq!(intervals(&pa.intersect(&pb)),
+        intervals(&IntervalSet::intersect(&sa, &sb)));
+
+    assert!(PersistentSelection::<i32>::full().complement().is_empty());
+    assert_eq!(PersistentSelection::<i32>::new().complement(),
+        PersistentSelection::full());
+}
@@ -0,0 +1,219 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Recurrence`].
+//!
+//! [`Recurrence`]: ../../recurrence/struct.Recurrence.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+#![cfg(feature = "chrono")]
+
+// Local imports.
+use crate::calendar::Weekday;
+use crate::datetime::chrono::SecondTimestamp;
+use crate::interval::Interval;
+use crate::recurrence::ByDay;
+use crate::recurrence::ByDayError;
+use crate::recurrence::Frequency;
+use crate::recurrence::Recurrence;
+use crate::selection::Selection;
+
+// External library imports.
+use ::chrono::NaiveDate;
+
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn at(date: NaiveDate, hour: u32) -> SecondTimestamp {
+    SecondTimestamp::new(date.and_hms_opt(hour, 0, 0).unwrap())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Occurrence tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn daily_interval_until() {
+    let rule = Recurrence::new(Frequency::Daily, date(2021, 1, 30))
+        .with_interval(2)
+        .with_until(date(2021, 2, 5));
+
+    assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+        date(2021, 1, 30),
+        date(2021, 2, 1),
+        date(2021, 2, 3),
+        date(2021, 2, 5),
+    ]);
+}
+
+#[test]
+fn daily_by_day() {
+    let rule = Recurrence::new(Frequency::Daily, date(2021, 1, 1))
+        .with_by_day(vec![ByDay::Every(Weekday::Saturday)]).unwrap()
+        .with_count(2);
+
+    assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+        date(2021, 1, 2),
+        date(2021, 1, 9),
+    ]);
+}
+
+#[test]
+fn weekly_by_day() {
+    // Starting on a Wednesday skips the Monday of the first week.
+    let rule = Recurrence::new(Frequency::Weekly, date(2021, 1, 6))
+        .with_interval(2)
+        .with_by_day(vec![
+            ByDay::Every(Weekday::Monday),
+            ByDay::Every(Weekday::Friday),
+        ]).unwrap()
+        .with_count(4);
+
+    assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+        date(2021, 1, 8),
+        date(2021, 1, 18),
+        date(2021, 1, 22),
+        date(2021, 2, 1),
+    ]);
+}
+
+#[test]
+fn monthly_same_day_skips_short_months() {
+    let rule = Recurrence::new(Frequency::Monthly, date(2021, 1, 31))
+        .with_count(3);
+
+    assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+        date(2021, 1, 31),
+        date(2021, 3, 31),
+        date(2021, 5, 31),
+    ]);
+}
+
+#[test]
+fn monthly_nth_weekday() {
+    let rule = Recurrence::new(Frequency::Monthly, date(2021, 1, 1))
+        .with_by_day(vec![
+            ByDay::Nth(1, Weekday::Monday),
+            ByDay::Nth(-1, Weekday::Friday),
+        ]).unwrap()
+        .with_until(date(2021, 3, 1));
+
+    assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+        date(2021, 1, 4),
+        date(2021, 1, 29),
+        date(2021, 2, 1),
+        date(2021, 2, 26),
+        date(2021, 3, 1),
+    ]);
+}
+
+#[test]
+fn yearly_leap_day() {
+    let rule = Recurrence::new(Frequency::Yearly, date(2020, 2, 29))
+        .with_count(2);
+
+    assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+        date(2020, 2, 29),
+        date(2024, 2, 29),
+    ]);
+}
+
+#[test]
+fn yearly_nth_weekday() {
+    let rule = Recurrence::new(Frequency::Yearly, date(2021, 1, 1))
+        .with_by_day(vec![ByDay::Nth(-1, Weekday::Sunday)]).unwrap()
+        .with_count(2);
+
+    assert_eq!(rule.occurrences().collect::<Vec<_>>(), [
+        date(2021, 12, 26),
+        date(2022, 12, 25),
+    ]);
+}
+
+#[test]
+fn by_day_rejects_unmatchable_ordinals() {
+    let start = date(2021, 1, 1);
+    let monthly = Recurrence::new(Frequency::Monthly, start);
+    let yearly = Recurrence::new(Frequency::Yearly, start);
+
+    assert_eq!(monthly.clone()
+        .with_by_day(vec![ByDay::Nth(0, Weekday::Monday)]).unwrap_err(),
+        ByDayError::ZeroOrdinal);
+    assert_eq!(monthly.clone()
+        .with_by_day(vec![ByDay::Nth(-6, Weekday::Monday)]).unwrap_err(),
+        ByDayError::OrdinalOutOfRange);
+    assert!(monthly
+        .with_by_day(vec![ByDay::Nth(-5, Weekday::Monday)]).is_ok());
+    assert_eq!(yearly.clone()
+        .with_by_day(vec![ByDay::Nth(54, Weekday::Monday)]).unwrap_err(),
+        ByDayError::OrdinalOutOfRange);
+    assert!(yearly
+        .with_by_day(vec![ByDay::Nth(53, Weekday::Monday)]).is_ok());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Expansion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn expand_clips_to_bounds() {
+    let rule = Recurrence::new(Frequency::Weekly, date(2021, 1, 1))
+        .with_by_day(vec![ByDay::Every(Weekday::Tuesday)]).unwrap();
+
+    // The first and last windows are clipped by the bounds.
+    let bounds = Interval::right_open(at(date(2021, 1, 5), 3),
+        at(date(2021, 1, 19), 3));
+    let windows = rule.expand(bounds,
+        |d| Interval::right_open(at(d, 2), at(d, 4)));
+
+    assert_eq!(windows.interval_iter().collect::<Vec<_>>(), [
+        Interval::right_open(at(date(2021, 1, 5), 3), at(date(2021, 1, 5), 4)),
+        Interval::right_open(at(date(2021, 1, 12), 2),
+            at(date(2021, 1, 12), 4)),
+        Interval::right_open(at(date(2021, 1, 19), 2),
+            at(date(2021, 1, 19), 3)),
+    ]);
+}
+
+#[test]
+fn expand_set_operations() {
+    let january = Interval::<NaiveDate>::month(2021, 1).unwrap();
+    let weekends = Recurrence::new(Frequency::Daily, date(2021, 1, 1))
+        .with_by_day(vec![
+            ByDay::Every(Weekday::Saturday),
+            ByDay::Every(Weekday::Sunday),
+        ]).unwrap()
+        .expand(january, Interval::point);
+
+    let weekdays = Selection::from(january).minus(&weekends);
+
+    assert_eq!(weekends.iter().count(), 10);
+    assert_eq!(weekdays.iter().count(), 21);
+    assert!(weekdays.intersect(&weekends).is_empty());
+}
+
+#[test]
+fn expand_stops_after_bounds_without_matches() {
+    // No leap day falls within the bounds, so expansion must stop at the
+    // first period after the bounds rather than at the next leap day.
+    let rule = Recurrence::new(Frequency::Yearly, date(2020, 2, 29));
+    let bounds = Interval::closed(date(2021, 1, 1), date(2023, 12, 31));
+    let latest = std::cell::Cell::new(date(2020, 1, 1));
+
+    let selection = rule.expand(bounds, |d| {
+        latest.set(latest.get().max(d));
+        Interval::point(d)
+    });
+
+    assert!(selection.is_empty());
+    assert!(latest.get() < date(2024, 2, 29));
+}
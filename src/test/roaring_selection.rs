@@ -0,0 +1,254 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`RoaringSelection`].
+//!
+//! [`RoaringSelection`]: ../../roaring_selection/struct.RoaringSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::roaring_selection::RoaringSelection;
+use crate::selection::Selection;
+use crate::test::Lcg;
+use crate::test::intervals;
+use crate::test::selection;
+
+
+fn roaring(intervals: &[Interval<u32>]) -> RoaringSelection<u32> {
+    intervals.iter().cloned().collect()
+}
+
+/// A mix of long runs crossing chunk boundaries, scattered points, and a
+/// dense chunk.
+fn a() -> Vec<Interval<u32>> {
+    let mut a = vec![
+        Interval::closed(10, 70_000),
+        Interval::closed(200_000, 400_000),
+    ];
+    a.extend((0..100).map(|i| Interval::point(500_000 + 7 * i)));
+    a.extend((0..5_000).map(|i| Interval::point((1 << 20) + 2 * i)));
+    a
+}
+
+fn b() -> Vec<Interval<u32>> {
+    let mut b = vec![
+        Interval::closed(65_000, 250_000),
+        Interval::closed(500_100, 500_300),
+    ];
+    b.extend((0..3_000).map(|i| Interval::point((1 << 20) + 3 * i)));
+    b
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn selection_round_trip() {
+    let sel = selection(&a());
+    let r = RoaringSelection::from(sel.clone());
+    assert_eq!(intervals(&r), intervals(&sel));
+    assert_eq!(intervals(&Selection::from(r.clone())), intervals(&sel));
+    assert_eq!(r, roaring(&a()));
+}
+
+#[test]
+fn merges_across_chunks() {
+    let r = roaring(&[
+        Interval::closed(0, 65_535),
+        Interval::closed(65_536, 131_071),
+        Interval::point(131_072),
+    ]);
+    assert_eq!(intervals(&r), [(Some(0), Some(131_072))]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn contains_and_cardinality() {
+    let r = roaring(&a());
+    let sel = selection(&a());
+    for p in (0..1_200_000).step_by(997) {
+        assert_eq!(r.contains(&p), sel.contains(&p), "point {}", p);
+    }
+    assert_eq!(r.cardinality(), 69_991 + 200_001 + 100 + 5_000);
+    assert_eq!(r.iter().count() as u128, r.cardinality());
+    assert_eq!(r.infimum(), Some(10));
+    assert_eq!(r.supremum(), Some((1 << 20) + 9_998));
+}
+
+#[test]
+fn empty_and_full() {
+    let empty = RoaringSelection::<u64>::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.cardinality(), 0);
+
+    let full = RoaringSelection::<u64>::full();
+    assert!(full.is_full());
+    assert_eq!(full.cardinality(), 1 << 64);
+    assert_eq!(full.interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(0, u64::MAX)]);
+    assert_eq!(full.complement(), empty);
+}
+
+#[test]
+fn unbounded_intervals() {
+    let r = RoaringSelection::from(Interval::unbounded_from(1_000u64));
+    assert!(r.contains(&u64::MAX));
+    assert!(!r.contains(&999));
+    assert_eq!(r.cardinality(), (1 << 64) - 1_000);
+    assert_eq!(r.complement().interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(0, 999)]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Set operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn set_operations_match_selection() {
+    let (ra, rb) = (roaring(&a()), roaring(&b()));
+    let (sa, sb) = (selection(&a()), selection(&b()));
+
+    assert_eq!(intervals(&ra.union(&rb)), intervals(&sa.union(&sb)));
+    assert_eq!(intervals(&ra.minus(&rb)),
+        intervals(&IntervalSet::minus(&sa, &sb)));
+    assert_eq!(intervals(&rb.minus(&ra)),
+        intervals(&IntervalSet::minus(&sb, &sa)));
+    assert_eq!(intervals(&ra.complement()), intervals(&sa.complement()));
+    assert_eq!(intervals(&ra.intersect(&rb)),
+        intervals(&IntervalSet::intersect(&sa, &sb)));
+}
+
+#[test]
+fn set_operations_with_complements() {
+    let (ra, rb) = (roaring(&a()), roaring(&b()));
+    let (ca, cb) = (ra.complement(), rb.complement());
+
+    assert_eq!(ca.union(&cb), ra.intersect(&rb).complement());
+    assert_eq!(ca.intersect(&cb), ra.union(&rb).complement());
+    assert_eq!(ca.union(&rb), ra.minus(&rb).complement());
+    assert_eq!(ra.intersect(&cb), ra.minus(&rb));
+    assert!(ra.intersect(&ca).is_empty());
+    assert!(ra.union(&ca).is_full());
+}
+
+#[test]
+fn in_place_operations() {
+    let mut r = RoaringSelection::<u32>::new();
+    r.union_in_place(Interval::closed(10, 100_000));
+    r.minus_in_place(Interval::open(20, 70_000));
+    r.intersect_in_place(Interval::closed(15, 80_000));
+    assert_eq!(intervals(&r), [
+        (Some(15), Some(20)),
+        (Some(70_000), Some(80_000)),
+    ]);
+}
+
+/// In-place operations on stored and complemented chunks, moving containers
+/// between array, bitmap, run, and full chunk forms, match `Selection`.
+#[test]
+fn in_place_operations_match_selection() {
+    let mut rng = Lcg::new(77);
+    let mut r = RoaringSelection::<u32>::new();
+    let mut sel = Selection::<u32>::new();
+    for step in 0..400 {
+        let l = (rng.next(8) << 16) | rng.next(1 << 16);
+        let h = l.saturating_add(match rng.next(4) {
+            0 => 0,
+            1 => rng.next(100),
+            2 => rng.next(1 << 16),
+            _ => rng.next(1 << 16) << 2,
+        });
+        let interval = Interval::closed(l, h);
+        match rng.next(3) {
+            0 => {
+                r.union_in_place(interval);
+                sel.union_in_place(interval);
+            },
+            1 => {
+                r.minus_in_place(interval);
+                sel.minus_in_place(interval);
+            },
+            _ => if step % 10 == 0 {
+                let outer = Interval::closed(l / 2, h.saturating_mul(2));
+                r.intersect_in_place(outer);
+                sel.intersect_in_place(outer);
+            },
+        }
+        if step == 200 {
+            r = r.complement();
+            sel = sel.complement();
+        }
+        assert_eq!(intervals(&r), intervals(&sel), "step {}", step);
+    }
+    assert_eq!(r.cardinality(), sel.iter().count() as u128);
+}
+
+/// Extending with many scattered points inserts each into its own chunk.
+#[test]
+fn extend_scattered_points() {
+    let mut rng = Lcg::new(5);
+    let points: Vec<u64> = (0..20_000)
+        .map(|_| {
+            let (high, low) = (rng.next(1 << 16), rng.next(1 << 16));
+            u64::from(high) << 24 | u64::from(low)
+        })
+        .collect();
+    let mut r = RoaringSelection::<u64>::new();
+    r.extend(points.iter().cloned().map(Interval::point));
+
+    let mut sorted = points.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert!(r.iter().eq(sorted.iter().cloned()));
+    assert_eq!(r, points.iter().cloned().collect());
+}
+
+/// Intervals spanning most of the `u64` domain are stored without expanding
+/// every chunk they cross.
+#[test]
+fn wide_u64_intervals() {
+    let half = 1u64 << 63;
+    let mut r = RoaringSelection::from(Interval::closed(5, half));
+    r.union_in_place(Interval::closed(half + 10, u64::MAX - 3));
+    r.minus_in_place(Interval::closed(1 << 40, (1 << 40) + 100_000));
+    r.intersect_in_place(Interval::closed(3, u64::MAX - 5));
+
+    let sel: Selection<u64> = r.interval_iter().collect();
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(5, (1 << 40) - 1),
+        Interval::closed((1 << 40) + 100_001, half),
+        Interval::closed(half + 10, u64::MAX - 5),
+    ]);
+    assert!(r.contains(&(half - 1)));
+    assert!(!r.contains(&(half + 5)));
+    assert_eq!(r.cardinality(),
+        u128::from((half - 4) + (u64::MAX - half - 14) - 100_001));
+
+    let other = RoaringSelection::from(
+        Interval::closed(half - 3, half + 70_000));
+    assert_eq!(r.intersect(&other).interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(half - 3, half),
+        Interval::closed(half + 10, half + 70_000),
+    ]);
+    assert_eq!(other.minus(&r).interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(half + 1, half + 9),
+    ]);
+    assert_eq!(RoaringSelection::from(sel), r);
+}
+
@@ -0,0 +1,337 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Selection`].
+//!
+//! [`Selection`]: ../../selection/struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::interval::SplitSide;
+use crate::raw_interval::RawInterval;
+use crate::selection::InvariantError;
+use crate::selection::Selection;
+use crate::tine_tree::TineTree;
+
+
+fn selection() -> Selection<i32> {
+    [
+        Interval::closed(-10, -5),
+        Interval::point(0),
+        Interval::closed(2, 4),
+        Interval::closed(8, 20),
+        Interval::closed(25, 25),
+    ].iter().cloned().collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Neighborhood query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn neighborhood_queries_match_scan() {
+    let sel = selection();
+    let points: Vec<i32> = sel.iter().collect();
+    let intervals: Vec<Interval<i32>> = sel.interval_iter().collect();
+
+    for p in -15..30 {
+        assert_eq!(sel.next_point_at_or_after(&p),
+            points.iter().cloned().find(|q| *q >= p));
+        assert_eq!(sel.prev_point_at_or_before(&p),
+            points.iter().cloned().rev().find(|q| *q <= p));
+        assert_eq!(sel.interval_containing(&p),
+            intervals.iter().cloned().find(|i| i.contains(&p)));
+
+        let nearest = sel.nearest_interval(&p).unwrap();
+        let distance = |i: &Interval<i32>| {
+            let (l, r) = (i.infimum().unwrap(), i.supremum().unwrap());
+            (l - p).max(p - r).max(0)
+        };
+        assert_eq!(distance(&nearest),
+            intervals.iter().map(distance).min().unwrap());
+
+        let gap = sel.gap_after(&p).unwrap();
+        let start = (p..).find(|q| !sel.contains(q)).unwrap();
+        assert!(gap.contains(&start));
+        let (l, r) = (gap.infimum().unwrap(), gap.supremum().unwrap());
+        assert!((l.max(-15)..=r.min(30)).all(|q| !sel.contains(&q)));
+        assert!(l == i32::MIN || sel.contains(&(l - 1)));
+        assert!(r == i32::MAX || sel.contains(&(r + 1)));
+    }
+}
+
+#[test]
+fn neighborhood_queries_at_extremes() {
+    let sel: Selection<u8> = [
+        Interval::closed(0, 3),
+        Interval::closed(250, 255),
+    ].iter().cloned().collect();
+
+    assert_eq!(sel.gap_after(&0), Some(Interval::closed(4, 249)));
+    assert_eq!(sel.gap_after(&250), None);
+    assert_eq!(sel.nearest_interval(&126), Some(Interval::closed(0, 3)));
+    assert_eq!(sel.nearest_interval(&127), Some(Interval::closed(250, 255)));
+    assert_eq!(sel.next_point_at_or_after(&4), Some(250));
+    assert_eq!(sel.prev_point_at_or_before(&249), Some(3));
+
+    let wide: Selection<i32> = [
+        Interval::point(i32::MIN),
+        Interval::point(i32::MAX),
+    ].iter().cloned().collect();
+    assert_eq!(wide.nearest_interval(&0), Some(Interval::point(i32::MAX)));
+    assert_eq!(wide.nearest_interval(&-1), Some(Interval::point(i32::MIN)));
+
+    let empty: Selection<u8> = Selection::new();
+    assert_eq!(empty.nearest_interval(&5), None);
+    assert_eq!(empty.gap_after(&5), Some(Interval::closed(0, 255)));
+    assert_eq!(Selection::<u8>::full().gap_after(&5), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Range view tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn range_matches_intersect() {
+    let sel = selection();
+    for l in -12..28 {
+        for r in l - 1..28 {
+            let window = Interval::closed(l, r);
+            let view = sel.range(window);
+            let points: Vec<i32> = sel.iter()
+                .filter(|p| window.contains(p))
+                .collect();
+
+            assert_eq!(view.iter().collect::<Vec<_>>(), points);
+            assert_eq!(view.iter().rev().collect::<Vec<_>>(),
+                points.iter().cloned().rev().collect::<Vec<_>>());
+            assert_eq!(view.infimum(), points.first().cloned());
+            assert_eq!(view.supremum(), points.last().cloned());
+            assert_eq!(view.count(), points.len() as u128);
+            assert_eq!(view.is_empty(), points.is_empty());
+            assert_eq!(view.to_selection().iter().collect::<Vec<_>>(), points);
+            for p in l - 1..=r + 1 {
+                assert_eq!(view.contains(&p), points.contains(&p));
+            }
+        }
+    }
+}
+
+#[test]
+fn range_count_of_wide_view() {
+    let mut sel: Selection<u64> = Selection::full();
+    sel.minus_in_place(Interval::closed(10, 19));
+    assert_eq!(sel.range(Interval::full()).count(), (1 << 64) - 10);
+    assert_eq!(sel.range(Interval::closed(5, 1 << 40)).count(),
+        (1 << 40) - 4 - 10);
+}
+
+#[test]
+fn range_double_ended() {
+    let sel = selection();
+    let view = sel.range(Interval::closed(-7, 10));
+    assert_eq!(view.interval_iter().rev().collect::<Vec<_>>(), [
+        Interval::closed(8, 10),
+        Interval::closed(2, 4),
+        Interval::point(0),
+        Interval::closed(-7, -5),
+    ]);
+
+    // Alternating ends meet in the middle without repeating points.
+    let mut iter = view.iter();
+    let mut points = Vec::new();
+    while let Some(p) = iter.next() {
+        points.push(p);
+        if let Some(q) = iter.next_back() { points.push(q); }
+    }
+    points.sort();
+    assert_eq!(points, sel.range(Interval::closed(-7, 10)).iter()
+        .collect::<Vec<_>>());
+    assert_eq!(points.len(), 3 + 1 + 3 + 3);
+}
+
+#[test]
+fn interval_iter_rev_across_excluded_point() {
+    // Intervals separated by a single point share an excluded point tine.
+    let sel: Selection<i32> = [Interval::closed(0, 2), Interval::closed(4, 6)]
+        .iter().cloned().collect();
+    assert_eq!(sel.interval_iter().rev().collect::<Vec<_>>(), [
+        Interval::closed(4, 6),
+        Interval::closed(0, 2),
+    ]);
+    assert_eq!(sel.range(Interval::closed(1, 5)).interval_iter()
+        .collect::<Vec<_>>(), [Interval::closed(1, 2), Interval::closed(4, 5)]);
+    assert_eq!(sel.range(Interval::closed(3, 3)).interval_count(), 0);
+}
+
+#[test]
+fn into_interval_iter_rev_across_excluded_point() {
+    let mut sel: Selection<i32> = Interval::closed(0, 9).into();
+    sel.minus_in_place(Interval::point(5));
+    assert_eq!(sel.into_interval_iter().rev().collect::<Vec<_>>(), [
+        Interval::closed(6, 9),
+        Interval::closed(0, 4),
+    ]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Partitioning tests
+////////////////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////////////////
+// Denormalized form tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn complement_denormalized() {
+    let sel = Selection::from(Interval::closed(0, 5));
+    let expected: Selection<i32> = [
+        Interval::unbounded_up_to(0),
+        Interval::unbounded_up_from(5),
+    ].iter().cloned().collect();
+
+    assert_eq!(sel.complement(), expected);
+}
+
+#[test]
+fn intersect_denormalized() {
+    let a = Selection::from(Interval::point(1));
+    let b = Selection::from(Interval::point(2));
+    assert!(a.intersect(&b).is_empty());
+
+    let mut c = a.clone();
+    c.intersect_in_place(Interval::point(2));
+    assert!(c.is_empty());
+}
+
+#[test]
+fn minus_denormalized() {
+    let sel = Selection::from(Interval::closed(0, 5));
+    let expected: Selection<i32> = [
+        Interval::closed(0, 2),
+        Interval::closed(4, 5),
+    ].iter().cloned().collect();
+    assert_eq!(sel.minus(&Selection::from(Interval::point(3))), expected);
+
+    let mut c = sel.clone();
+    c.minus_in_place(Interval::point(3));
+    assert_eq!(c, expected);
+}
+
+
+#[test]
+fn split_at_matches_points() {
+    let sel = selection();
+    let points: Vec<i32> = sel.iter().collect();
+    for p in -12..28 {
+        let (before, after) = sel.split_at(&p, SplitSide::Left);
+        assert_eq!(before.iter().collect::<Vec<_>>(), points.iter()
+            .cloned().filter(|q| *q <= p).collect::<Vec<_>>());
+        assert_eq!(after.iter().collect::<Vec<_>>(), points.iter()
+            .cloned().filter(|q| *q > p).collect::<Vec<_>>());
+
+        let (before, after) = sel.split_at(&p, SplitSide::Right);
+        assert_eq!(before.iter().collect::<Vec<_>>(), points.iter()
+            .cloned().filter(|q| *q < p).collect::<Vec<_>>());
+        assert_eq!(after.iter().collect::<Vec<_>>(), points.iter()
+            .cloned().filter(|q| *q >= p).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn chunks_by_points_rejoin() {
+    let sel = selection();
+    let points: Vec<i32> = sel.iter().collect();
+    for size in 1..=points.len() + 1 {
+        let chunks: Vec<_> = sel.chunks_by_points(size).collect();
+        assert_eq!(chunks.len(), points.len().div_ceil(size));
+        for (i, chunk) in chunks.iter().enumerate() {
+            let start = i * size;
+            let end = (start + size).min(points.len());
+            assert_eq!(chunk.iter().collect::<Vec<_>>(), &points[start..end]);
+        }
+    }
+    assert_eq!(Selection::<i32>::new().chunks_by_points(2).next(), None);
+}
+
+#[test]
+fn partition_into_near_equal() {
+    let sel = selection();
+    let points: Vec<i32> = sel.iter().collect();
+    for k in 1..=points.len() + 3 {
+        let pieces = sel.partition_into(k);
+        assert_eq!(pieces.len(), k);
+        let counts: Vec<usize> = pieces.iter()
+            .map(|p| p.iter().count())
+            .collect();
+        let (min, max) = (counts.iter().min(), counts.iter().max());
+        assert!(max.unwrap() - min.unwrap() <= 1);
+        assert!(counts.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(pieces.iter().flat_map(|p| p.iter()).collect::<Vec<_>>(),
+            points);
+    }
+}
+
+#[test]
+fn partition_into_wide_selection() {
+    let mut sel: Selection<u64> = Selection::full();
+    sel.minus_in_place(Interval::closed(1 << 62, (1 << 63) - 1));
+    let pieces = sel.partition_into(4);
+    assert_eq!(pieces[1].interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(3 << 60, (1 << 62) - 1),
+        Interval::closed(1 << 63, (1 << 63) + (1 << 61) - 1),
+    ]);
+    assert_eq!(pieces[3].interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(u64::MAX - (3 << 60) + 1, u64::MAX)]);
+}
+
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Invariant tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn check_invariants_after_operations() {
+    let mut sel = selection();
+    assert_eq!(sel.check_invariants(), Ok(()));
+    sel.minus_in_place(Interval::closed(-7, 2));
+    assert_eq!(sel.check_invariants(), Ok(()));
+    sel.intersect_in_place(Interval::closed(3, 24));
+    assert_eq!(sel.check_invariants(), Ok(()));
+    assert_eq!(sel.complement().check_invariants(), Ok(()));
+    assert_eq!(Selection::<i32>::full().check_invariants(), Ok(()));
+    assert_eq!(Selection::<i32>::new().check_invariants(), Ok(()));
+}
+
+#[test]
+fn check_invariants_violations() {
+    use Bound::*;
+    use RawInterval::*;
+    let check = |tree: TineTree<i32>| {
+        Selection::from_tine_tree(tree).check_invariants()
+    };
+
+    assert_eq!(check(TineTree::from(Closed(3, 5))),
+        Err(InvariantError::UnnormalizedBound(Include(3))));
+    assert_eq!(check(TineTree::from(Open(3, 4))),
+        Err(InvariantError::EmptyInterval(Exclude(3), Exclude(4))));
+    assert_eq!(check(vec![Closed(1, 3), Closed(4, 6)].into_iter().collect()),
+        Err(InvariantError::UnmergedIntervals(Include(3), Include(4))));
+
+    let error = InvariantError::EmptyInterval(Exclude(3), Exclude(4));
+    assert_eq!(error.to_string(),
+        "interval contains no points between Exclude(3) and Exclude(4)");
+}
@@ -0,0 +1,106 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`SetExpr`].
+//!
+//! [`SetExpr`]: ../../set_expr/struct.SetExpr.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::set_expr::SetExpr;
+use crate::test::Lcg;
+
+
+/// Returns a pseudo-random `Selection` for the given seed.
+fn selection(seed: u32) -> Selection<i8> {
+    let mut rng = Lcg::new(seed);
+    let mut next = |m: u32| rng.next(m);
+    (0..next(6))
+        .map(|_| {
+            let a = (next(256) as i16 - 128) as i8;
+            let b = (next(256) as i16 - 128) as i8;
+            Interval::closed(a.min(b), a.max(b))
+        })
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Evaluation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matches_materialized_operations() {
+    for seed in 0..200 {
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| selection(seed * 4 + i));
+        let window = Interval::closed(-100, 100);
+
+        // (a ∪ b) ∩ ¬(c ∖ d) ∩ window
+        let expr = SetExpr::from(&a)
+            .union(&b)
+            .intersect(SetExpr::from(&c).minus(&d).complement())
+            .intersect(window);
+        let mut expected = a.union(&b).intersect(&c.minus(&d).complement());
+        expected.intersect_in_place(window);
+
+        let actual: Selection<i8> = expr.interval_iter().collect();
+        assert_eq!(expr.interval_iter().collect::<Vec<_>>(),
+            expected.interval_iter().collect::<Vec<_>>(), "seed {}", seed);
+        assert_eq!(actual, expected);
+        assert_eq!(expr.count(), expected.iter().count() as u128);
+        assert_eq!(expr.is_empty(), expected.is_empty());
+        for p in i8::MIN..=i8::MAX {
+            assert_eq!(expr.contains(&p), expected.contains(&p));
+        }
+    }
+}
+
+#[test]
+fn extremes() {
+    let empty: Selection<i8> = Selection::new();
+    let full = SetExpr::from(&empty).complement();
+    assert_eq!(full.interval_iter().collect::<Vec<_>>(), [Interval::full()]);
+    assert_eq!(full.count(), 256);
+    assert!(SetExpr::from(&empty).is_empty());
+
+    let edges = SetExpr::from(Interval::point(i8::MIN))
+        .union(Interval::point(i8::MAX));
+    assert_eq!(edges.interval_iter().collect::<Vec<_>>(),
+        [Interval::point(i8::MIN), Interval::point(i8::MAX)]);
+    assert_eq!((&edges.complement()).into_iter().collect::<Vec<_>>(),
+        [Interval::closed(i8::MIN + 1, i8::MAX - 1)]);
+}
+
+#[test]
+fn count_of_wide_expression() {
+    let empty: Selection<u64> = Selection::new();
+    assert_eq!(SetExpr::from(&empty).complement().count(), 1 << 64);
+
+    let wide = SetExpr::from(Interval::closed(i64::MIN, -1))
+        .union(Interval::closed(1, i64::MAX));
+    assert_eq!(wide.count(), (1 << 64) - 1);
+}
+
+#[test]
+fn cloned_iterators_advance_independently() {
+    let a: Selection<i32> = (0..100)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+    let expr = SetExpr::from(&a).minus(Interval::closed(200, 799));
+
+    let mut iter = expr.interval_iter();
+    assert_eq!(iter.nth(19), Some(Interval::closed(190, 194)));
+    let rest = iter.clone();
+    assert_eq!(iter.next(), Some(Interval::closed(800, 804)));
+    assert_eq!(rest.count(), 20);
+    assert_eq!(iter.last(), Some(Interval::closed(990, 994)));
+}
@@ -0,0 +1,154 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`SmallSelection`].
+//!
+//! [`SmallSelection`]: ../../small_selection/struct.SmallSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_set::IntervalSet;
+use crate::selection::Selection;
+use crate::small_selection::SmallSelection;
+use crate::test::intervals;
+use crate::test::selection;
+
+
+fn small(intervals: &[Interval<i32>]) -> SmallSelection<i32, 3> {
+    intervals.iter().cloned().collect()
+}
+
+fn a() -> [Interval<i32>; 4] {
+    [
+        Interval::closed(-10, -5),
+        Interval::closed(0, 4),
+        Interval::closed(8, 20),
+        Interval::closed(30, 40),
+    ]
+}
+
+fn b() -> [Interval<i32>; 3] {
+    [Interval::closed(-7, 1), Interval::closed(3, 3), Interval::closed(15, 35)]
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Storage tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn inline_until_full() {
+    let mut s: SmallSelection<i32, 3> = SmallSelection::new();
+    assert!(s.is_inline());
+    for (i, interval) in a().iter().enumerate() {
+        s.union_in_place(*interval);
+        assert_eq!(s.is_inline(), i < 3);
+    }
+    assert_eq!(intervals(&s), intervals(&selection(&a())));
+
+    // Merging intervals does not consume inline storage.
+    let merged = small(&[
+        Interval::closed(0, 4),
+        Interval::closed(5, 9),
+        Interval::closed(2, 12),
+        Interval::point(13),
+    ]);
+    assert!(merged.is_inline());
+    assert_eq!(intervals(&merged), [(Some(0), Some(13))]);
+}
+
+#[test]
+fn results_return_inline() {
+    let s = small(&a());
+    assert!(!s.is_inline());
+
+    let fewer = s.intersect(&small(&[Interval::closed(0, 25)]));
+    assert!(fewer.is_inline());
+    assert_eq!(intervals(&fewer), [(Some(0), Some(4)), (Some(8), Some(20))]);
+}
+
+#[test]
+fn selection_round_trip() {
+    for intervals_in in [&a()[..2], &a()[..]] {
+        let sel = selection(intervals_in);
+        let s = SmallSelection::<i32, 3>::from(sel.clone());
+        assert_eq!(s.is_inline(), intervals_in.len() <= 3);
+        assert_eq!(intervals(&s), intervals(&sel));
+        assert_eq!(intervals(&Selection::from(s)), intervals(&sel));
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn queries() {
+    let (inline, spilled) = (small(&a()[..3]), small(&a()));
+    for p in -12..45 {
+        assert_eq!(inline.contains(&p),
+            a()[..3].iter().any(|i| i.contains(&p)));
+        assert_eq!(spilled.contains(&p), a().iter().any(|i| i.contains(&p)));
+    }
+    assert_eq!(inline.infimum(), Some(-10));
+    assert_eq!(inline.supremum(), Some(20));
+    assert_eq!(spilled.supremum(), Some(40));
+    assert_eq!(inline.iter().count(), 6 + 5 + 13);
+
+    assert!(SmallSelection::<i32>::new().is_empty());
+    assert!(SmallSelection::<i32>::full().contains(&i32::MIN));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Set operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn set_operations_match_selection() {
+    let (xa, xb) = (small(&a()), small(&b()));
+    let (sa, sb) = (selection(&a()), selection(&b()));
+
+    assert_eq!(intervals(&xa.union(&xb)), intervals(&sa.union(&sb)));
+    assert_eq!(intervals(&xa.complement()), intervals(&sa.complement()));
+    assert_eq!(intervals(&xa.minus(&xb)),
+        intervals(&IntervalSet::minus(&sa, &sb)));
+    assert_eq!(intervals(&xb.minus(&xa)),
+        intervals(&IntervalSet::minus(&sb, &sa)));
+    assert_eq!(intervals(&xa.intersect(&xb)),
+        intervals(&IntervalSet::intersect(&sa, &sb)));
+}
+
+#[test]
+fn spilled_in_place_operations() {
+    let mut x = small(&a());
+    let mut s = selection(&a());
+    assert!(!x.is_inline());
+
+    x.minus_in_place(Interval::closed(2, 9));
+    s.minus_in_place(Interval::closed(2, 9));
+    assert!(!x.is_inline());
+    assert_eq!(intervals(&x), intervals(&s));
+
+    x.intersect_in_place(Interval::closed(-4, 35));
+    s.intersect_in_place(Interval::closed(-4, 35));
+    assert!(x.is_inline());
+    assert_eq!(intervals(&x), intervals(&s));
+}
+
+#[test]
+fn equality_ignores_storage() {
+    let spilled = small(&a()).minus(&small(&[Interval::closed(25, 50)]));
+    let inline = small(&a()[..3]);
+    assert!(inline.is_inline());
+    assert_eq!(spilled, inline);
+}
@@ -0,0 +1,118 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the [`stream`] adaptors.
+//!
+//! [`stream`]: ../../stream/index.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::stream::intersect;
+use crate::stream::minus;
+use crate::stream::normalize_stream;
+use crate::stream::symmetric_difference;
+use crate::stream::union;
+use crate::test::Lcg;
+
+
+/// Returns pseudo-random intervals for the given seed, sorted by lower bound.
+/// The intervals may overlap, be adjacent, or be empty.
+fn stream(seed: u32) -> Vec<Interval<i8>> {
+    let mut rng = Lcg::new(seed);
+    let mut next = |m: u32| rng.next(m);
+    let mut intervals: Vec<Interval<i8>> = (0..next(8))
+        .map(|_| {
+            let a = (next(256) as i16 - 128) as i8;
+            let b = (next(256) as i16 - 128) as i8;
+            match next(6) {
+                0 => Interval::empty(),
+                1 => Interval::point(a),
+                2 => Interval::open(a.min(b), a.max(b)),
+                _ => Interval::closed(a.min(b), a.max(b)),
+            }
+        })
+        .collect();
+    intervals.sort_by_key(|i| i.infimum());
+    intervals
+}
+
+/// Returns the intervals of the `Selection` of the given intervals.
+fn normalized(intervals: &[Interval<i8>]) -> Vec<Interval<i8>> {
+    intervals.iter().cloned().collect::<Selection<_>>()
+        .interval_iter()
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Stream tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn normalize_stream_matches_selection() {
+    for seed in 0..300 {
+        let intervals = stream(seed);
+        assert_eq!(normalize_stream(intervals.clone()).collect::<Vec<_>>(),
+            normalized(&intervals), "seed {}", seed);
+    }
+}
+
+#[test]
+fn set_operations_match_selection() {
+    for seed in 0..300 {
+        let (a, b) = (stream(seed * 2), stream(seed * 2 + 1));
+        let sel_a: Selection<i8> = a.iter().cloned().collect();
+        let sel_b: Selection<i8> = b.iter().cloned().collect();
+        let intervals = |sel: Selection<i8>| {
+            sel.interval_iter().collect::<Vec<_>>()
+        };
+
+        assert_eq!(union(a.clone(), b.clone()).collect::<Vec<_>>(),
+            intervals(sel_a.union(&sel_b)), "seed {}", seed);
+        assert_eq!(intersect(a.clone(), b.clone()).collect::<Vec<_>>(),
+            intervals(sel_a.intersect(&sel_b)), "seed {}", seed);
+        assert_eq!(minus(a.clone(), b.clone()).collect::<Vec<_>>(),
+            intervals(sel_a.minus(&sel_b)), "seed {}", seed);
+        assert_eq!(symmetric_difference(a, b).collect::<Vec<_>>(),
+            intervals(sel_a.minus(&sel_b).union(&sel_b.minus(&sel_a))),
+            "seed {}", seed);
+    }
+}
+
+#[test]
+fn set_operations_at_extremes() {
+    let full = vec![Interval::<i8>::full()];
+    let edges = vec![Interval::point(i8::MIN), Interval::point(i8::MAX)];
+
+    assert_eq!(union(full.clone(), edges.clone()).collect::<Vec<_>>(),
+        full);
+    assert_eq!(minus(full.clone(), edges.clone()).collect::<Vec<_>>(),
+        [Interval::closed(i8::MIN + 1, i8::MAX - 1)]);
+    assert_eq!(symmetric_difference(edges.clone(), full.clone())
+        .collect::<Vec<_>>(),
+        [Interval::closed(i8::MIN + 1, i8::MAX - 1)]);
+    assert_eq!(intersect(edges.clone(), Vec::new()).count(), 0);
+    assert_eq!(intersect(full, edges.clone()).collect::<Vec<_>>(), edges);
+}
+
+#[test]
+fn intersect_stops_at_exhausted_stream() {
+    // The second stream is never exhausted, so this only terminates if the
+    // intersection stops once the first stream is.
+    let a = vec![Interval::closed(0u32, 5)];
+    let b = (0..).map(|i| Interval::point(i * 2));
+    assert_eq!(intersect(a, b).collect::<Vec<_>>(), [
+        Interval::point(0),
+        Interval::point(2),
+        Interval::point(4),
+    ]);
+}
@@ -0,0 +1,97 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`sweep`].
+//!
+//! [`sweep`]: ../../sweep/fn.sweep.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::sweep::exactly_one;
+use crate::sweep::majority;
+use crate::sweep::sweep;
+use crate::sweep::threshold;
+
+
+/// Returns the bounds of each interval of the selection.
+fn intervals(sel: &Selection<i32>) -> Vec<(Option<i32>, Option<i32>)> {
+    sel.interval_iter().map(|i| (i.infimum(), i.supremum())).collect()
+}
+
+fn selections() -> Vec<Selection<i32>> {
+    vec![
+        [Interval::closed(0, 10), Interval::closed(20, 30)]
+            .iter().cloned().collect(),
+        Interval::closed(5, 25).into(),
+        [Interval::closed(8, 12), Interval::closed(28, 40)]
+            .iter().cloned().collect(),
+    ]
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Predicate tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn built_in_predicates() {
+    let sels = selections();
+
+    assert_eq!(intervals(&sweep(&sels, threshold(1))), [(Some(0), Some(40))]);
+    assert_eq!(intervals(&sweep(&sels, threshold(2))), [
+        (Some(5), Some(12)),
+        (Some(20), Some(25)),
+        (Some(28), Some(30)),
+    ]);
+    assert_eq!(intervals(&sweep(&sels, threshold(3))), [(Some(8), Some(10))]);
+    assert_eq!(intervals(&sweep(&sels, majority())),
+        intervals(&sweep(&sels, threshold(2))));
+    assert_eq!(intervals(&sweep(&sels, exactly_one())), [
+        (Some(0), Some(4)),
+        (Some(13), Some(19)),
+        (Some(26), Some(27)),
+        (Some(31), Some(40)),
+    ]);
+}
+
+#[test]
+fn custom_predicates() {
+    let sels = selections();
+
+    // Points in the first selection but not the last.
+    let only_first = sweep(&sels, |c| c.contains(0) && !c.contains(2));
+    assert_eq!(intervals(&only_first), [
+        (Some(0), Some(7)),
+        (Some(20), Some(27)),
+    ]);
+
+    // The empty cover is tested, so points outside every selection can be
+    // selected.
+    let none = sweep(&sels, |c| c.count() == 0);
+    assert_eq!(intervals(&none), [
+        (Some(i32::MIN), Some(-1)),
+        (Some(41), Some(i32::MAX)),
+    ]);
+}
+
+#[test]
+fn matches_pairwise_operations() {
+    let sels = selections();
+    let union = sels.iter().fold(Selection::new(), |acc, s| acc.union(s));
+    assert_eq!(intervals(&sweep(&sels, threshold(1))), intervals(&union));
+
+    let all = sweep(&sels, |c| c.count() == c.total());
+    assert_eq!(intervals(&all), intervals(&sweep(&sels, threshold(3))));
+
+    assert!(sweep(Vec::<&Selection<i32>>::new(), threshold(1)).is_empty());
+    assert!(sweep(&sels[..0], threshold(0)).is_full());
+}
@@ -34,7 +34,7 @@ fn disjoint_aggregation() {
 	t.intersect_in_place(&UpFrom(50));
 	t.intersect_in_place(&Empty);
 
-    assert_eq!(t.iter_intervals().collect::<Vec<_>>(), []);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), []);
 }
 
 #[test]
@@ -51,7 +51,7 @@ fn nested_aggregation() {
     t.intersect_in_place(&UpFrom(20));
     t.intersect_in_place(&Point(25));
 
-    assert_eq!(t.iter_intervals().collect::<Vec<_>>(), [Point(25)]);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), [Point(25)]);
 }
 
 
@@ -553,3 +553,25 @@ fn full() {
     assert_eq_i!(a.intersect(&TineTree::from(From(0))),           [From(0)]);
     assert_eq_i!(a.intersect(&TineTree::from(Full)),              [Full]);
 }
+
+#[test]
+fn skipped_interval() {
+    // A non-overlapping interval must not consume an interval of the other
+    // tree which overlaps a later interval.
+    let mut a: TineTree<i32> = Closed(0, 1).into();
+    a.union_in_place(&Closed(5, 6));
+
+    assert_eq_i!(a.intersect(&TineTree::from(Closed(5, 6))), [Closed(5, 6)]);
+    assert_eq_i!(TineTree::from(Closed(5, 6)).intersect(&a), [Closed(5, 6)]);
+}
+
+#[test]
+fn shared_bound() {
+    let mut a: TineTree<i32> = Closed(0, 5).into();
+    a.intersect_in_place(&Closed(5, 8));
+    assert_eq_i!(a, [Point(5)]);
+
+    let mut b: TineTree<i32> = Open(0, 5).into();
+    b.intersect_in_place(&Closed(0, 5));
+    assert_eq_i!(b, [Open(0, 5)]);
+}
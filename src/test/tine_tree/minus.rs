@@ -515,3 +515,18 @@ fn full() {
     assert_eq_i!(a.minus(&TineTree::from(From(0))),           [UpTo(0)]);
     assert_eq_i!(a.minus(&TineTree::from(Full)),              []);
 }
+
+#[test]
+fn excluded_point_boundary() {
+    // The tree contains a Point(Exclude(0)) tine, which must remain excluded
+    // when either neighboring interval is removed.
+    let mut a: TineTree<i32> = UpTo(0).into();
+    a.union_in_place(&Open(0, 5));
+    let mut b = a.clone();
+
+    a.minus_in_place(&UpTo(0));
+    assert_eq_i!(a, [Open(0, 5)]);
+
+    b.minus_in_place(&Open(0, 5));
+    assert_eq_i!(b, [UpTo(0)]);
+}
@@ -17,4 +17,5 @@
 // Module declarations.
 mod intersect;
 mod minus;
+mod neighborhood;
 mod union;
@@ -0,0 +1,66 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::tine_tree::TineTree;
+
+// Local enum shortcuts.
+use crate::raw_interval::RawInterval::*;
+
+
+fn tree() -> TineTree<i32> {
+    let mut t: TineTree<i32> = TineTree::new();
+    t.union_in_place(&UpTo(0));
+    t.union_in_place(&Point(1));
+    t.union_in_place(&Open(2, 4));
+    t.union_in_place(&Open(4, 6));
+    t.union_in_place(&Closed(8, 9));
+    t
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Neighborhood tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn interval_containing() {
+    let t = tree();
+    assert_eq!(t.interval_containing(&-100), Some(UpTo(0)));
+    assert_eq!(t.interval_containing(&0), None);
+    assert_eq!(t.interval_containing(&1), Some(Point(1)));
+    assert_eq!(t.interval_containing(&2), None);
+    assert_eq!(t.interval_containing(&3), Some(Open(2, 4)));
+    assert_eq!(t.interval_containing(&4), None);
+    assert_eq!(t.interval_containing(&5), Some(Open(4, 6)));
+    assert_eq!(t.interval_containing(&8), Some(Closed(8, 9)));
+    assert_eq!(t.interval_containing(&9), Some(Closed(8, 9)));
+    assert_eq!(t.interval_containing(&10), None);
+}
+
+#[test]
+fn interval_at_or_after() {
+    let t = tree();
+    assert_eq!(t.interval_at_or_after(&0), Some(Point(1)));
+    assert_eq!(t.interval_at_or_after(&2), Some(Open(2, 4)));
+    assert_eq!(t.interval_at_or_after(&4), Some(Open(4, 6)));
+    assert_eq!(t.interval_at_or_after(&6), Some(Closed(8, 9)));
+    assert_eq!(t.interval_at_or_after(&10), None);
+}
+
+#[test]
+fn interval_at_or_before() {
+    let t = tree();
+    assert_eq!(t.interval_at_or_before(&0), Some(UpTo(0)));
+    assert_eq!(t.interval_at_or_before(&2), Some(Point(1)));
+    assert_eq!(t.interval_at_or_before(&4), Some(Open(2, 4)));
+    assert_eq!(t.interval_at_or_before(&7), Some(Open(4, 6)));
+    assert_eq!(t.interval_at_or_before(&100), Some(Closed(8, 9)));
+    assert_eq!(TineTree::<i32>::new().interval_at_or_before(&0), None);
+}
@@ -173,93 +173,36 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
         }
     }
 
-    /// Unifies two equal `Tines` by excluding any non-coincident points.
-    /// Returns `None` if none of the points in the boundry region are included.
-    pub(in crate) fn intersect(self, other: &Self) -> Option<Self> {
+    /// Returns whether the points immediately before, at, and immediately
+    /// after the `Tine` are included by it.
+    pub(in crate) fn coverage(&self) -> (bool, bool, bool) {
         use Bound::*;
         use Tine::*;
-        debug_assert!(self.as_ref() == other.as_ref(),
-            "cannot intersect unequal tines");
-
-        match (self, other) {
-            (Lower(Include(l)), &Lower(Exclude(_))) => Some(Lower(Exclude(l))),
-            (Lower(Exclude(l)), &Lower(Include(_))) => Some(Lower(Exclude(l))),
-            (Lower(lb),         &Lower(_))          => Some(Lower(lb)),
-
-            (Lower(Include(l)), &Point(Include(_))) => Some(Point(Include(l))),
-            (Lower(Exclude(l)), &Point(Exclude(_))) => Some(Lower(Exclude(l))),
-            (Lower(_),          &Point(_))          => None,
-
-            (Lower(Include(l)), &Upper(Include(_))) => Some(Point(Include(l))),
-            (Lower(_),          &Upper(_))          => None,
-
-            (Point(Include(l)), &Lower(Include(_))) => Some(Point(Include(l))),
-            (Point(Include(l)), &Point(Include(_))) => Some(Point(Include(l))),
-            (Point(Include(l)), &Upper(Include(_))) => Some(Point(Include(l))),
-            (Point(Include(_)), _)                  => None,
-
-            (Point(Exclude(l)), &Lower(_))          => Some(Lower(Exclude(l))),
-            (Point(Exclude(_)), &Point(Include(_))) => None,
-            (Point(Exclude(l)), &Point(Exclude(_))) => Some(Point(Exclude(l))),
-            (Point(Exclude(l)), &Upper(_))          => Some(Upper(Exclude(l))),
-
-            (Upper(Include(l)), &Lower(Include(_))) => Some(Point(Include(l))),
-            (Upper(_),          &Lower(_))          => None,
-
-            (Upper(Include(l)), &Point(Include(_))) => Some(Point(Include(l))),
-            (Upper(Exclude(l)), &Point(Exclude(_))) => Some(Upper(Exclude(l))),
-            (Upper(_),          &Point(_))          => None,
-
-            (Upper(Include(l)), &Upper(Exclude(_))) => Some(Upper(Exclude(l))),
-            (Upper(Exclude(l)), &Upper(Include(_))) => Some(Upper(Exclude(l))),
-            (Upper(lb),         &Upper(_))          => Some(Upper(lb)),
-
-            _ => unreachable!("invalid tine intersect"),
+        match *self {
+            Lower(Include(_)) => (false, true,  true),
+            Lower(_)          => (false, false, true),
+            Point(Include(_)) => (false, true,  false),
+            Point(_)          => (true,  false, true),
+            Upper(Include(_)) => (true,  true,  false),
+            Upper(_)          => (true,  false, false),
         }
     }
 
-    /// Unifies two equal `Tines` by excluding any coincident points. Returns
-    /// `None` if none of the points in the boundry region are included.
-    pub(in crate) fn minus(self, other: &Self) -> Option<Self> {
+    /// Returns the `Tine` at the given point with the given coverage, or
+    /// `None` if the coverage is uniform and no `Tine` is needed.
+    pub(in crate) fn from_coverage(point: T, coverage: (bool, bool, bool))
+        -> Option<Self>
+    {
         use Bound::*;
         use Tine::*;
-        debug_assert!(self.as_ref() == other.as_ref(),
-            "cannot intersect unequal tines");
-
-        match (self, other) {
-            (Lower(Include(l)), &Lower(Exclude(_))) => Some(Point(Include(l))),
-            (Lower(_),          &Lower(_))          => None,
-
-            (Lower(Include(l)), &Point(Include(_))) => Some(Lower(Exclude(l))),
-            (Lower(_),          &Point(_))          => None,
-
-            (Lower(Include(l)), &Upper(Include(_))) => Some(Lower(Exclude(l))),
-            (Lower(Include(l)), &Upper(Exclude(_))) => Some(Lower(Include(l))),
-            (Lower(Exclude(l)), &Upper(Include(_))) => Some(Lower(Exclude(l))),
-            (Lower(Exclude(l)), &Upper(Exclude(_))) => Some(Lower(Exclude(l))),
-
-            (Point(Include(_)), &Lower(Include(_))) => None,
-            (Point(Include(_)), &Point(Include(_))) => None,
-            (Point(Include(_)), &Upper(Include(_))) => None,
-            (Point(Include(l)), _)                  => Some(Point(Include(l))),
-
-            (Point(Exclude(l)), &Lower(_))          => Some(Lower(Exclude(l))),
-            (Point(Exclude(l)), &Point(Include(_))) => Some(Point(Exclude(l))),
-            (Point(Exclude(_)), &Point(Exclude(_))) => None,
-            (Point(Exclude(l)), &Upper(_))          => Some(Upper(Exclude(l))),
-
-            (Upper(Include(l)), &Lower(Include(_))) => Some(Upper(Exclude(l))),
-            (Upper(Include(l)), &Lower(Exclude(_))) => Some(Upper(Include(l))),
-            (Upper(Exclude(l)), &Lower(Include(_))) => Some(Upper(Exclude(l))),
-            (Upper(Exclude(l)), &Lower(Exclude(_))) => Some(Upper(Exclude(l))),
-
-            (Upper(Include(l)), &Point(Include(_))) => Some(Upper(Exclude(l))),
-            (Upper(_),          &Point(_))          => None,
-
-            (Upper(Include(l)), &Upper(Exclude(_))) => Some(Point(Include(l))),
-            (Upper(_),          &Upper(_))          => None,
-
-            _ => unreachable!("invalid tine intersect"),
+        match coverage {
+            (false, true,  true)  => Some(Lower(Include(point))),
+            (false, false, true)  => Some(Lower(Exclude(point))),
+            (false, true,  false) => Some(Point(Include(point))),
+            (true,  false, true)  => Some(Point(Exclude(point))),
+            (true,  true,  false) => Some(Upper(Include(point))),
+            (true,  false, false) => Some(Upper(Exclude(point))),
+            _                     => None,
         }
     }
 
@@ -14,6 +14,7 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::normalize::Normalize;
 use crate::raw_interval::RawInterval;
 use crate::tine::Tine;
 use crate::utility::Few;
@@ -21,8 +22,8 @@ use crate::utility::Few;
 // Standard library imports.
 use std::collections::BTreeSet;
 use std::collections::btree_set;
-use std::collections;
 use std::iter::FromIterator;
+use std::ops;
 
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -102,6 +103,95 @@ impl<T> TineTree<T> where T: Ord + Clone {
         false
     }
 
+    ////////////////////////////////////////////////////////////////////////////
+    // Neighborhood queries
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `RawInterval` containing the given point, or `None` if the
+    /// point is not in the `TineTree`.
+    pub(in crate) fn interval_containing(&self, point: &T)
+        -> Option<RawInterval<T>>
+    {
+        use Bound::*;
+        use Tine::*;
+        let probe = Point(Include(point.clone()));
+        let tine = self.0.range(..=&probe).next_back()?;
+
+        if tine.as_ref() == Some(point) {
+            // The point is on a tine, so only included bounds contain it.
+            match tine {
+                Lower(Include(_)) => Some(self.interval_from(tine)),
+                Point(Include(p)) => Some(RawInterval::Point(p.clone())),
+                Upper(Include(_)) => Some(self.interval_to(tine)),
+                _                 => None,
+            }
+        } else if tine.is_lower_bound() {
+            // The point is between the tine and the next.
+            Some(self.interval_from(tine))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first `RawInterval` containing or following the given
+    /// point, or `None` if there is no such interval.
+    pub(in crate) fn interval_at_or_after(&self, point: &T)
+        -> Option<RawInterval<T>>
+    {
+        use Bound::*;
+        if let Some(interval) = self.interval_containing(point) {
+            return Some(interval);
+        }
+        let probe = Tine::Point(Include(point.clone()));
+        self.0
+            .range(&probe..)
+            .find(|t| t.is_lower_bound() || t.is_point_include())
+            .map(|t| self.interval_from(t))
+    }
+
+    /// Returns the last `RawInterval` containing or preceding the given
+    /// point, or `None` if there is no such interval.
+    pub(in crate) fn interval_at_or_before(&self, point: &T)
+        -> Option<RawInterval<T>>
+    {
+        use Bound::*;
+        if let Some(interval) = self.interval_containing(point) {
+            return Some(interval);
+        }
+        let probe = Tine::Point(Include(point.clone()));
+        self.0
+            .range(..=&probe)
+            .rev()
+            .find(|t| t.is_upper_bound() || t.is_point_include())
+            .map(|t| self.interval_to(t))
+    }
+
+    /// Returns the `RawInterval` whose lower bound is the given `Tine`.
+    fn interval_from(&self, lower: &Tine<T>) -> RawInterval<T> {
+        use Bound::*;
+        if let Tine::Point(Include(p)) = lower {
+            return RawInterval::Point(p.clone());
+        }
+        let upper = self.0
+            .range((ops::Bound::Excluded(lower), ops::Bound::Unbounded))
+            .next()
+            .expect("interval is not partial");
+        RawInterval::new(lower.clone().into_inner(), upper.clone().into_inner())
+    }
+
+    /// Returns the `RawInterval` whose upper bound is the given `Tine`.
+    fn interval_to(&self, upper: &Tine<T>) -> RawInterval<T> {
+        use Bound::*;
+        if let Tine::Point(Include(p)) = upper {
+            return RawInterval::Point(p.clone());
+        }
+        let lower = self.0
+            .range(..upper)
+            .next_back()
+            .expect("interval is not partial");
+        RawInterval::new(lower.clone().into_inner(), upper.clone().into_inner())
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Set Operations
     ////////////////////////////////////////////////////////////////////////////
@@ -165,24 +255,25 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// `TineTree`s.
     pub(in crate) fn intersect(&self, other: &Self) -> Self {
         let mut intersection = Self::new();
-        let mut self_intervals = self.interval_iter();
-        let mut other_intervals = other.interval_iter();
-
-        while let Some(self_interval) = self_intervals.next() {
-            'segment: loop {
-                if let Some(other_interval) = other_intervals.next() {
-                    let i = self_interval.intersect(&other_interval);
-                    if !i.is_empty() {
-                        intersection.union_in_place(&i);
-                    } else {
-                        // Nothing else overlaps in this segment.
-                        break 'segment;
-                    }
+        let mut self_intervals = self.interval_iter().peekable();
+        let mut other_intervals = other.interval_iter().peekable();
 
-                } else {
-                    // Nothing else overlaps anywhere.
-                    return intersection;
-                }
+        while let (Some(self_interval), Some(other_interval))
+            = (self_intervals.peek(), other_intervals.peek())
+        {
+            let i = self_interval.intersect(other_interval);
+            if !i.is_empty() {
+                intersection.union_in_place(&i);
+            }
+
+            // The interval which ends first cannot overlap any later
+            // intervals of the other tree.
+            let self_upper = self_interval.upper_bound().map(Tine::Upper);
+            let other_upper = other_interval.upper_bound().map(Tine::Upper);
+            if self_upper <= other_upper {
+                let _ = self_intervals.next();
+            } else {
+                let _ = other_intervals.next();
             }
         }
         intersection
@@ -301,99 +392,37 @@ impl<T> TineTree<T> where T: Ord + Clone {
     }
 
     fn intersect_proper_interval(&mut self, l: Tine<T>, u: Tine<T>) {
-        let mut ts = self.interior_split_for_proper_interval(&l, &u);
-
-        // Merge tines if overlap or use given ones. We should only have `None`
-        // in the case of a intersection annhiliation.
-        let merged_l = if ts[2].is_some() {
-            ts[2].take().and_then(|lower| lower.intersect(&l))
-        } else {
-            Some(l)
-        };
-
-        let merged_u = if ts[3].is_some() {
-            ts[3].take().and_then(|upper| upper.intersect(&u))
-        } else {
-            Some(u)
-        };
-        
-        // Ensure inner tines have the correct bounds.
-        debug_assert!(merged_l
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(true));
-        debug_assert!(merged_u
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(true));
-
-        
-        // We need to detect whether the point is inside or outside an interval.
-        // To do this, we look at the tines inside and outside the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(false);
-        let closed_after = ts[5]
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(false);
-
-        let in_l = ts[1]
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(false);
-        let in_r = ts[4]
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(false);
-
+        let ts = self.interior_split_for_proper_interval(&l, &u);
+
+        // The tines outside of l and u have been dropped, so any bound which
+        // isn't on a tine lies in the gap after ts[0] or before ts[5].
+        let inside_l = ts[0].as_ref().is_some_and(Tine::is_lower_bound);
+        let inside_u = ts[5].as_ref().is_some_and(Tine::is_upper_bound);
+        let [_, _, existing_l, existing_u, _, _] = ts;
+        self.insert_intersect_bound(existing_l, inside_l, l);
+        self.insert_intersect_bound(existing_u, inside_u, u);
+    }
 
-        // Insert tines into the tree, ignoring them if the are not wrapped by a
-        // surrounding interval, or not wrapping a surrounding interval.
-        match (open_before, merged_l, in_l, in_r, merged_u, closed_after) {
-            (_,     Some(l), true,  true,  Some(u), _   )  |
-            (_,     Some(l), false, false, Some(u), _   )  => {
-                // (   ) (   )
-                //   (     )
-                //     O R
-                // (     )
-                //   ( )
-                //     O R
-                // (     )
-                // (  )
-                //     O R
-                // (     )
-                //    (  )
-                self.0.insert(l);
-                self.0.insert(u);
-            },
-            (true, Some(l),  true,  false, _,       false) => {
-                // (   )
-                //   (   )
-                //     O R
-                // (   ) ( )
-                //   (   )
-                self.0.insert(l);
-            },
-            (false, _,       false, true,  Some(u), true)  => {
-                //   (   )
-                // (   )
-                //     O R
-                // ( ) (   )
-                //   (   )
-                self.0.insert(u);
-            },
-            (false, _,       false, false, _,       false) => {
-                // )     (
-                // (     )
-                //     O R
-                //   ( )
-                // (     )
-                /* Do nothing. */
-            },
-            _ => unreachable!("invalid bounds for intersection interval"),
+    /// Inserts the `Tine` for the points covered by both the given bound and
+    /// the existing `Tine` at its point, or the surrounding gap if there is no
+    /// existing `Tine`.
+    fn insert_intersect_bound(
+        &mut self,
+        existing: Option<Tine<T>>,
+        inside: bool,
+        bound: Tine<T>)
+    {
+        if bound.as_ref().is_none() {
+            // An infinite bound covers everything, so only an existing
+            // infinite bound remains.
+            if let Some(tine) = existing {
+                self.0.insert(tine);
+            }
+            return;
         }
+        let existing = existing.as_ref()
+            .map_or((inside, inside, inside), Tine::coverage);
+        self.insert_coverage(existing, bound, |e, c| e && c);
     }
 
     /// Unions the given interval with the contents of the tree.
@@ -614,177 +643,49 @@ impl<T> TineTree<T> where T: Ord + Clone {
     }
 
     fn minus_point_interval(&mut self, p: Tine<T>) {
-        let mut ts = self.exterior_split_for_point_interval(&p);
-
-        let p = if ts[1].is_some() {
-            if let Some(merged) = ts[1]
-                .take()
-                .and_then(|pt| pt.minus(&p)) 
-            {
-                merged
-            } else {
-                // If the point annhilates, then we've already joined two
-                // intervals by removing the Point(Exclude(_)) from the tree in
-                // minus_split_tree_point. So nothing else needs to happen.
-                return;
-            }
-        } else {
-            p
-        };
-        
-        // We need to detect whether the point is inside or outside an interval.
-        // To do this, we look at the tines before and after the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(false);
-        let closed_after = ts[2]
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(false);
+        let ts = self.exterior_split_for_point_interval(&p);
 
-        // Insert tine into the tree, ignoring it if it is wrapped by a
-        // surrounding interval.
-        // NOTE: We cannot have a Point(Exclude) here, because those will never
-        // result from an interval-tine conversion.
-        match (open_before, closed_after) {
-            (true,  true)  => {
-                // (   )
-                //   |
-                self.0.insert(p.invert());
-            },
-            (true,  false) => {
-                // ( )   ( )
-                //   |
-                debug_assert!(p.is_upper_bound());
-                self.0.insert(p);
-            },
-            (false, true)  => {
-                // ( )   ( )
-                //       |
-                debug_assert!(p.is_lower_bound());
-                self.0.insert(p);
-            },
-            (false, false) => {
-                // ( )   ( )
-                //     |
-                // Do nothing.
-            },
-        }
+        // The point is in a gap between tines if it isn't on one.
+        let inside = ts[0].as_ref().is_some_and(Tine::is_lower_bound);
+        let existing = ts[1].as_ref().map_or((inside, inside, inside),
+            Tine::coverage);
+        self.insert_coverage(existing, p, |e, c| e && !c);
     }
 
     fn minus_proper_interval(&mut self, l: Tine<T>, u: Tine<T>) {
-        let mut ts = self.exterior_split_for_proper_interval(&l, &u);
-
-        // Merge tines if overlap
-        let merged_l = if ts[1].is_some() {
-            ts[1].take().and_then(|lower| lower.minus(&l))
-        } else {
-            Some(l)
-        };
-
-        let merged_u = if ts[2].is_some() {
-            ts[2].take().and_then(|upper| upper.minus(&u))
-        } else {
-            Some(u)
-        };
-
-        // We need to detect whether the interval is inside or outside an 
-        // existing interval. To do this, we look at the tines before and after
-        // the interval.
-        let open_before = ts[0]
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(false);
-        let closed_after = ts[3]
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(false);
-        
-        println!("Minus {} {} {} {}", open_before, merged_l.is_some(), merged_u.is_some(), closed_after);
-        // Insert tines into the tree, ignoring them if the are not wrapped by a
-        // surounding interval.
-        use Bound::*;
-        use Tine::*;
-        match (open_before, merged_l, merged_u, closed_after) {
-            (true,  Some(l), Some(u), true)  => {
-                // ( ) ( )
-                //  (   )
-                //     O R
-                // ( ) ( )
-                //   ( )
-                self.0.insert(if l.is_upper_bound() {l} else {l.invert()});
-                self.0.insert(if u.is_lower_bound() {u} else {u.invert()});
-            },
-            (true,  Some(l), upper,   false)  => {
-                // ( )
-                //  ( )
-                //     O R
-                // ( )
-                //   ( )
-                //     O R
-                // (   )
-                //   ( )
-                //     O R
-                // (   ]
-                //   ( )
-                //     O R
-                self.0.insert(if l.is_upper_bound() {l} else {l.invert()});
-                if let Some(Point(Include(p))) = upper {
-                    self.0.insert(Point(Include(p)));
-                }
-            },
-            (false, lower,   Some(u), true)   => {
-                //  ( )
-                // ( )
-                //     O R
-                //   ( )
-                // ( )
-                //     O R
-                // (   )
-                // ( )
-                //     O R
-                // [   )
-                // ( )
-                self.0.insert(if u.is_lower_bound() {u} else {u.invert()});
-                if let Some(Point(Include(p))) = lower {
-                    self.0.insert(Point(Include(p)));
-                }
-            },
-            (false, Some(l), Some(u), false)  => {
-                //  ( )
-                // (   )
-                //     O R
-                // [ ]
-                // ( )
-                if l.is_point_include() { self.0.insert(l); }
-                if u.is_point_include() { self.0.insert(u); }
-            },
-
-            (false, Some(l), None,    false)  => {
-                // [ )
-                // ( )
-                //     O R
-                //   |
-                // ( ]
-                if l.is_point_include() { self.0.insert(l); }
-            },
-
-            (false, None,    Some(u), false)  => {
-                // ( ]
-                // ( )
-                //     O R
-                // |
-                // [ )
-                if u.is_point_include() { self.0.insert(u); }
-            },
+        let ts = self.exterior_split_for_proper_interval(&l, &u);
+
+        // The tines between l and u have been dropped, so any bound which
+        // isn't on a tine lies in the gap before ts[3] or after ts[0].
+        let inside_l = ts[0].as_ref().is_some_and(Tine::is_lower_bound);
+        let inside_u = ts[3].as_ref().is_some_and(Tine::is_upper_bound);
+        let existing_l = ts[1].as_ref().map_or((inside_l, inside_l, inside_l),
+            Tine::coverage);
+        let existing_u = ts[2].as_ref().map_or((inside_u, inside_u, inside_u),
+            Tine::coverage);
+        self.insert_coverage(existing_l, l, |e, c| e && !c);
+        self.insert_coverage(existing_u, u, |e, c| e && !c);
+    }
 
-            (false, None,    None,    false)  => {
-                // ( )
-                // ( )
-                // Do nothing.
-            },
-            _ => unreachable!("invalid bounds for minus interval"),
+    /// Inserts the `Tine` at the given bound's point whose coverage combines
+    /// the existing coverage with the coverage of the bound.
+    fn insert_coverage<F>(
+        &mut self,
+        existing: (bool, bool, bool),
+        bound: Tine<T>,
+        combine: F)
+        where F: Fn(bool, bool) -> bool
+    {
+        let covered = bound.coverage();
+        let coverage = (
+            combine(existing.0, covered.0),
+            combine(existing.1, covered.1),
+            combine(existing.2, covered.2));
+        let tine = bound.as_ref()
+            .cloned()
+            .and_then(|p| Tine::from_coverage(p, coverage));
+        if let Some(tine) = tine {
+            self.0.insert(tine);
         }
     }
 
@@ -918,14 +819,213 @@ impl<T> TineTree<T> where T: Ord + Clone {
     // Iterator conversions
     ////////////////////////////////////////////////////////////////////////////
 
+    /// Returns an iterator over each of the `Tine`s in the tree.
+    pub(in crate) fn tine_iter(&self) -> btree_set::Iter<'_, Tine<T>> {
+        self.0.iter()
+    }
+
     /// Returns an iterator over each of the `RawInterval`s in the tree.
     pub(in crate) fn interval_iter(&self) -> Iter<'_, T> {
         Iter {
-            tine_iter: self.0.iter(),
+            tine_iter: self.0.range::<Tine<T>, _>(..),
             saved_lower: None,
             saved_upper: None,
         }
     }
+
+    /// Returns an iterator over each of the `RawInterval`s in the tree which
+    /// intersect the closed range from `lower` to `upper`. The first and last
+    /// intervals are not clipped to the range.
+    pub(in crate) fn interval_range_iter(&self, lower: &T, upper: &T)
+        -> Iter<'_, T>
+    {
+        use Bound::*;
+        let lower = Tine::Point(Include(lower.clone()));
+        let upper = Tine::Point(Include(upper.clone()));
+        if lower > upper {
+            // An empty range of tines.
+            return Iter {
+                tine_iter: self.0.range(lower.clone()..lower),
+                saved_lower: None,
+                saved_upper: None,
+            };
+        }
+
+        // Extend the range to include the bounds of any interval which
+        // contains the endpoints. An excluded point beyond the endpoints also
+        // bounds the interval on its far side, so only its near side is kept.
+        let mut saved_lower = None;
+        let mut saved_upper = None;
+        let start = match self.0.range(..&lower).next_back() {
+            Some(Tine::Point(Exclude(p))) => {
+                saved_lower = Some(Tine::Lower(Exclude(p.clone())));
+                ops::Bound::Excluded(Tine::Point(Exclude(p.clone())))
+            },
+            Some(before) if before.is_lower_bound()
+                => ops::Bound::Included(before.clone()),
+            _   => ops::Bound::Included(lower),
+        };
+        let end = match self.0
+            .range((ops::Bound::Excluded(&upper), ops::Bound::Unbounded))
+            .next()
+        {
+            Some(Tine::Point(Exclude(p))) => {
+                saved_upper = Some(Tine::Upper(Exclude(p.clone())));
+                ops::Bound::Excluded(Tine::Point(Exclude(p.clone())))
+            },
+            Some(after) if after.is_upper_bound()
+                => ops::Bound::Included(after.clone()),
+            _   => ops::Bound::Included(upper),
+        };
+
+        Iter {
+            tine_iter: self.0.range((start, end)),
+            saved_lower,
+            saved_upper,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Denormalized form
+////////////////////////////////////////////////////////////////////////////////
+// For types which can be normalized, a `Selection` keeps its `TineTree` in
+// denormalized form: every finite tine excludes its point, and every interval
+// contains at least one point. The form is determined by which points are
+// covered, so a tine can be restored from the coverage of its point and the
+// points beside it.
+impl<T> TineTree<T> where T: Ord + Clone, RawInterval<T>: Normalize {
+    /// Restores the denormalized form of the tines at the bounds of the given
+    /// interval, after the interval was intersected with or removed from a
+    /// denormalized `TineTree`.
+    pub(in crate) fn denormalize_bounds(&mut self, interval: &RawInterval<T>) {
+        let bounds = [interval.lower_bound(), interval.upper_bound()];
+        for bound in bounds.iter().flatten() {
+            if let Some(point) = bound.as_ref() {
+                self.denormalize_at(point);
+            }
+        }
+    }
+
+    /// Restores the denormalized form of every tine in the `TineTree`.
+    pub(in crate) fn denormalize(&mut self) {
+        let points: Vec<T> = self.0.iter()
+            .filter_map(|tine| tine.as_ref().cloned())
+            .collect();
+        for point in &points {
+            self.denormalize_at(point);
+        }
+    }
+
+    /// Replaces the tine at the given point with its denormalized form, along
+    /// with any tines beside it which are not in denormalized form.
+    fn denormalize_at(&mut self, point: &T) {
+        use Bound::*;
+        use Tine::*;
+
+        // Grow the region until the tines beside it are denormalized, so that
+        // each change in coverage at its edges is marked exactly once.
+        let mut first = point.clone();
+        while let Some(before) = neighbors(&first).0 {
+            if self.is_denormalized_at(&before) { break; }
+            first = before;
+        }
+        let mut last = point.clone();
+        while let Some(after) = neighbors(&last).1 {
+            if self.is_denormalized_at(&after) { break; }
+            last = after;
+        }
+
+        // Find the coverage of the region and the points beside it before
+        // replacing any tines.
+        let mut points = vec![first.clone()];
+        while points[points.len() - 1] != last {
+            let next = neighbors(&points[points.len() - 1]).1
+                .expect("point before end of region");
+            points.push(next);
+        }
+        let covered_before = neighbors(&first).0
+            .is_some_and(|p| self.covers(&p));
+        let covered_after = neighbors(&last).1
+            .is_some_and(|p| self.covers(&p));
+        let covered: Vec<bool> = points.iter()
+            .map(|p| self.covers(p))
+            .collect();
+
+        if neighbors(&first).0.is_none() {
+            self.0.take(&Lower(Infinite));
+            if covered[0] { self.0.insert(Lower(Infinite)); }
+        }
+        if neighbors(&last).1.is_none() {
+            self.0.take(&Upper(Infinite));
+            if covered[covered.len() - 1] { self.0.insert(Upper(Infinite)); }
+        }
+        for (i, p) in points.into_iter().enumerate() {
+            self.0.take(&Point(Include(p.clone())));
+            let before = if i == 0 { covered_before } else { covered[i - 1] };
+            let after = covered.get(i + 1).copied().unwrap_or(covered_after);
+            if let Some(tine) = denormalized_tine(p, (before, covered[i], after)) {
+                self.0.insert(tine);
+            }
+        }
+    }
+
+    /// Returns `true` if the tine at the given point, if any, is in
+    /// denormalized form.
+    fn is_denormalized_at(&self, point: &T) -> bool {
+        use Bound::*;
+        use Tine::*;
+        let (before, after) = neighbors(point);
+        let covered = self.covers(point);
+        let coverage = (
+            before.as_ref().is_some_and(|p| self.covers(p)),
+            covered,
+            after.as_ref().is_some_and(|p| self.covers(p)));
+
+        // The infinite bounds cover the least and greatest points.
+        if before.is_none() && self.0.contains(&Lower(Infinite)) != covered {
+            return false;
+        }
+        if after.is_none() && self.0.contains(&Upper(Infinite)) != covered {
+            return false;
+        }
+        let existing = self.0.get(&Point(Include(point.clone())));
+        existing == denormalized_tine(point.clone(), coverage).as_ref()
+    }
+
+    /// Returns `true` if the given point is covered by the `TineTree`.
+    fn covers(&self, point: &T) -> bool {
+        use Bound::*;
+        let probe = Tine::Point(Include(point.clone()));
+        match self.0.range(..=&probe).next_back() {
+            Some(tine) if tine.as_ref() == Some(point) => tine.coverage().1,
+            Some(tine)                                 => tine.coverage().2,
+            None                                       => false,
+        }
+    }
+}
+
+/// Returns the denormalized `Tine` at the given point with the given coverage,
+/// or `None` if no `Tine` is needed. Covered points lie inside an interval, and
+/// uncovered points are excluded by the bounds of the intervals beside them.
+fn denormalized_tine<T>(point: T, coverage: (bool, bool, bool))
+    -> Option<Tine<T>>
+    where T: Ord + Clone
+{
+    if coverage.1 { None } else { Tine::from_coverage(point, coverage) }
+}
+
+/// Returns the points immediately before and after the given point, as given
+/// by the denormalized form of the point.
+fn neighbors<T>(point: &T) -> (Option<T>, Option<T>)
+    where T: Ord + Clone, RawInterval<T>: Normalize
+{
+    match RawInterval::Point(point.clone()).denormalized() {
+        RawInterval::Open(l, r) => (Some(l), Some(r)),
+        RawInterval::UpTo(r)    => (None, Some(r)),
+        RawInterval::UpFrom(l)  => (Some(l), None),
+        _                       => (None, None),
+    }
 }
 
 impl<T> Default for TineTree<T> where T: Ord + Clone {
@@ -1056,7 +1156,7 @@ impl<T> DoubleEndedIterator for IntoIter<T>
                         .expect("interval is not partial");
 
                     if lower.is_point_exclude() {
-                        self.saved_lower = Some(lower.clone());
+                        self.saved_upper = Some(lower.clone());
                     }
 
                     // ... and the next tine after must be a lower bound.
@@ -1074,9 +1174,9 @@ impl<T> DoubleEndedIterator for IntoIter<T>
 // Iter
 ////////////////////////////////////////////////////////////////////////////////
 /// An `Iterator` that constructs `RawInterval`s from a sequence of `Tine`s.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(in crate) struct Iter<'t, T> {
-    tine_iter: collections::btree_set::Iter<'t, Tine<T>>,
+    tine_iter: btree_set::Range<'t, Tine<T>>,
     saved_lower: Option<Tine<T>>,
     saved_upper: Option<Tine<T>>,
 }
@@ -1089,34 +1189,32 @@ impl<'t, T> Iterator for Iter<'t, T>
     fn next(&mut self) -> Option<Self::Item> {
         use Bound::*;
         use Tine::*;
-        self.saved_lower
+        let lower = self.saved_lower
             .take()
-            .or_else(|| self.tine_iter.next().cloned())
-            .map(|lower| {
-                if let Point(Include(p)) = lower {
-                    // Next tine is a single point.
-                    RawInterval::Point(p)
-                } else {
-                    // Next tine must be a lower bound of an interval.
-                    debug_assert!(lower.is_lower_bound());
+            .or_else(|| self.tine_iter.next().cloned())?;
 
-                    let upper = self.tine_iter.next().cloned()
-                        .or_else(|| self.saved_upper.take())
-                        .expect("interval is not partial");
+        if let Point(Include(p)) = lower {
+            // Next tine is a single point.
+            return Some(RawInterval::Point(p));
+        }
 
-                    if upper.is_point_exclude() {
-                        self.saved_lower = Some(upper.clone());
-                    }
+        // Next tine must be a lower bound of an interval.
+        debug_assert!(lower.is_lower_bound());
 
-                    // ... and the next tine after must be an upper bound.
-                    debug_assert!(upper.is_upper_bound());
+        let upper = self.tine_iter.next().cloned()
+            .or_else(|| self.saved_upper.take())
+            .expect("interval is not partial");
 
-                    let lower = lower.into_inner();
-                    let upper = upper.into_inner();
-                    RawInterval::new(lower, upper)
-                }
-            })
+        if upper.is_point_exclude() {
+            self.saved_lower = Some(upper.clone());
+        }
+
+        // ... and the next tine after must be an upper bound.
+        debug_assert!(upper.is_upper_bound());
 
+        let lower = lower.into_inner();
+        let upper = upper.into_inner();
+        Some(RawInterval::new(lower, upper))
     }
 }
 
@@ -1126,33 +1224,32 @@ impl<'t, T> DoubleEndedIterator for Iter<'t, T>
     fn next_back(&mut self) -> Option<Self::Item> {
         use Bound::*;
         use Tine::*;
-        self.saved_upper
+        let upper = self.saved_upper
             .take()
-            .or_else(|| self.tine_iter.next_back().cloned())
-            .map(|upper| {
-                if let Point(Include(p)) = upper {
-                    // Next tine is a single point.
-                    RawInterval::Point(p)
-                } else {
-                    // Next tine must be an upper bound of an interval.
-                    debug_assert!(upper.is_upper_bound());
+            .or_else(|| self.tine_iter.next_back().cloned())?;
 
-                    let lower = self.tine_iter.next_back().cloned()
-                        .or_else(|| self.saved_lower.take())
-                        .expect("interval is not partial");
+        if let Point(Include(p)) = upper {
+            // Next tine is a single point.
+            return Some(RawInterval::Point(p));
+        }
 
-                    if lower.is_point_exclude() {
-                        self.saved_lower = Some(lower.clone());
-                    }
+        // Next tine must be an upper bound of an interval.
+        debug_assert!(upper.is_upper_bound());
 
-                    // ... and the next tine after must be a lower bound.
-                    debug_assert!(lower.is_lower_bound());
+        let lower = self.tine_iter.next_back().cloned()
+            .or_else(|| self.saved_lower.take())
+            .expect("interval is not partial");
 
-                    let upper = upper.into_inner();
-                    let lower = lower.into_inner();
-                    RawInterval::new(lower, upper)
-                }
-            })
+        if lower.is_point_exclude() {
+            self.saved_upper = Some(lower.clone());
+        }
+
+        // ... and the next tine after must be a lower bound.
+        debug_assert!(lower.is_lower_bound());
+
+        let upper = upper.into_inner();
+        let lower = lower.into_inner();
+        Some(RawInterval::new(lower, upper))
     }
 }
 